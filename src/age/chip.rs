@@ -0,0 +1,374 @@
+use std::marker::PhantomData;
+
+use halo2_proofs::{
+    circuit::{AssignedCell, Chip, Layouter, Region, Value},
+    pasta::group::ff::PrimeField,
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, Instance, VirtualCells},
+    poly::Rotation,
+};
+
+use crate::{days_in_month, AgeConfig, AgeInstructions, NUMBER_LENGTH, YEAR_SHIFT};
+
+/// The number of two-digit values (`00` to `99`) covered by the leap year table
+const LEAP_TABLE_SIZE: u64 = 100;
+
+/// The chip that implements the birth date and age check instructions
+pub struct AgeChip<F: PrimeField> {
+    config: AgeConfig,
+    _marker: PhantomData<F>,
+}
+
+impl<F: PrimeField> AgeChip<F> {
+    /// Creates a new instance of the age chip
+    pub fn construct(config: AgeConfig) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Configures the age chip
+    ///
+    /// The digits of the birth date and of the age difference are loaded by another chip (e.g. the digit sum chip),
+    /// which range checks them, and are copied into the chip.
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        advice: [Column<Advice>; 3],
+        instance: Column<Instance>,
+    ) -> <Self as Chip<F>>::Config {
+        meta.enable_equality(instance);
+        for column in &advice {
+            meta.enable_equality(*column);
+        }
+        let s_date = meta.complex_selector();
+        let s_age = meta.selector();
+        let leap_table = [meta.lookup_table_column(), meta.lookup_table_column()];
+        let calendar_table = [
+            meta.lookup_table_column(),
+            meta.lookup_table_column(),
+            meta.lookup_table_column(),
+        ];
+
+        meta.create_gate("birth date", |meta| {
+            // This gate recomposes the birth date from its digits, and checks that the `year_low_inv` cell makes
+            // `is_zero` a boolean which is set if and only if the two last digits of the year are zero
+            // Here is the arrangement of the cells of the gate
+            //
+            // | a0           | a1   | a2         | s_date |
+            // |--------------|------|------------|--------|
+            // | in0          | in1  | in2        | s_date |
+            // | in3          | in4  | in5        |        |
+            // | in6          | in7  | birth_date |        |
+            // | year_low_inv | leap |            |        |
+            //
+            let s_date = meta.query_selector(s_date);
+            let digits = Self::digit_expressions(meta, advice);
+            let birth_date = meta.query_advice(advice[2], Rotation(2));
+            let (_, year_low, is_zero, _, _, _) = Self::date_expressions(meta, advice);
+
+            vec![
+                s_date.clone() * (Self::recompose(digits) - birth_date),
+                s_date * year_low * is_zero,
+            ]
+        });
+
+        meta.lookup(|meta| {
+            // A year is a leap year if its two last digits are divisible by 4, or if they are
+            // zero and its two first digits are divisible by 4. The key is shifted by one so
+            // that the disabled selector `(0, 0)` entry does not collide with a valid year.
+            let s_date = meta.query_selector(s_date);
+            let (century, year_low, is_zero, _, _, leap) = Self::date_expressions(meta, advice);
+            let leap_key = year_low + is_zero * century + Expression::Constant(F::ONE);
+            vec![
+                (s_date.clone() * leap_key, leap_table[0]),
+                (s_date * leap, leap_table[1]),
+            ]
+        });
+
+        meta.lookup(|meta| {
+            // The leap flag is shifted by one so that the disabled selector `(0, 0, 0)` entry
+            // does not collide with the `00/00` date.
+            let s_date = meta.query_selector(s_date);
+            let (_, _, _, month, day, leap) = Self::date_expressions(meta, advice);
+            vec![
+                (s_date.clone() * month, calendar_table[0]),
+                (s_date.clone() * day, calendar_table[1]),
+                (
+                    s_date * (leap + Expression::Constant(F::ONE)),
+                    calendar_table[2],
+                ),
+            ]
+        });
+
+        meta.create_gate("age", |meta| {
+            // This gate checks that the difference `today - birth_date - min_age * 10000`
+            // is the number recomposed from the digits (which is therefore non negative)
+            // Here is the arrangement of the cells of the gate
+            //
+            // | a0    | a1      | a2         | s_age |
+            // |-------|---------|------------|-------|
+            // | in0   | in1     | in2        | s_age |
+            // | in3   | in4     | in5        |       |
+            // | in6   | in7     | birth_date |       |
+            // | today | min_age |            |       |
+            //
+            let digits = Self::digit_expressions(meta, advice);
+            let birth_date = meta.query_advice(advice[2], Rotation(2));
+            let today = meta.query_advice(advice[0], Rotation(3));
+            let min_age = meta.query_advice(advice[1], Rotation(3));
+            let s_age = meta.query_selector(s_age);
+
+            vec![
+                s_age
+                    * (today
+                        - birth_date
+                        - min_age * Expression::Constant(F::from(YEAR_SHIFT))
+                        - Self::recompose(digits)),
+            ]
+        });
+
+        AgeConfig {
+            advice,
+            instance,
+            s_date,
+            s_age,
+            leap_table,
+            calendar_table,
+        }
+    }
+
+    /// Queries the digits of a number copied on the three first rows of a region, from the first row.
+    fn digit_expressions(
+        meta: &mut VirtualCells<'_, F>,
+        advice: [Column<Advice>; 3],
+    ) -> [Expression<F>; NUMBER_LENGTH] {
+        std::array::from_fn(|i| meta.query_advice(advice[i % 3], Rotation((i / 3) as i32)))
+    }
+
+    /// Recomposes a number from its digits in decimal representation
+    fn recompose(digits: [Expression<F>; NUMBER_LENGTH]) -> Expression<F> {
+        digits
+            .into_iter()
+            .reduce(|number, digit| number * Expression::Constant(F::from(10)) + digit)
+            .unwrap() // safe unwrap as a number has at least one digit
+    }
+
+    /// Queries the parts of a `YYYYMMDD` birth date from its digits.
+    ///
+    /// Returns `(century, year_low, is_zero(year_low), month, day, leap)`.
+    #[allow(clippy::type_complexity)]
+    fn date_expressions(
+        meta: &mut VirtualCells<'_, F>,
+        advice: [Column<Advice>; 3],
+    ) -> (
+        Expression<F>,
+        Expression<F>,
+        Expression<F>,
+        Expression<F>,
+        Expression<F>,
+        Expression<F>,
+    ) {
+        let [century, year_low, month, day] = {
+            let digits = Self::digit_expressions(meta, advice);
+            std::array::from_fn(|i| {
+                digits[2 * i].clone() * Expression::Constant(F::from(10))
+                    + digits[2 * i + 1].clone()
+            })
+        };
+        let year_low_inv = meta.query_advice(advice[0], Rotation(3));
+        let leap = meta.query_advice(advice[1], Rotation(3));
+        let is_zero = Expression::Constant(F::ONE) - year_low.clone() * year_low_inv;
+
+        (century, year_low, is_zero, month, day, leap)
+    }
+
+    /// Copies the digits of a number on the three first rows of a region, and assigns their recomposition in the
+    /// last cell
+    fn assign_digits(
+        &self,
+        region: &mut Region<'_, F>,
+        digits: &[AssignedCell<F, F>; NUMBER_LENGTH],
+    ) -> Result<Value<F>, Error> {
+        let config = self.config();
+
+        let mut number = Value::known(F::ZERO);
+        for (i, digit) in digits.iter().enumerate() {
+            digit.copy_advice(|| format!("digit {i}"), region, config.advice[i % 3], i / 3)?;
+            number = number * Value::known(F::from(10)) + digit.value();
+        }
+
+        Ok(number)
+    }
+
+    /// Computes the leap year flag of a year given its two first and its two last digits
+    fn leap_flag(century: F, year_low: F) -> F {
+        let key = if year_low == F::ZERO {
+            century
+        } else {
+            year_low
+        };
+        let is_leap = (0..LEAP_TABLE_SIZE)
+            .find(|x| F::from(*x) == key)
+            .is_some_and(|x| x.is_multiple_of(4));
+
+        F::from(is_leap as u64)
+    }
+}
+
+impl<F: PrimeField> Chip<F> for AgeChip<F> {
+    type Config = AgeConfig;
+    type Loaded = ();
+
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+/// A date represented in the chip
+#[derive(Clone, Debug)]
+pub struct AgeNumber<F: PrimeField>(AssignedCell<F, F>);
+
+impl<F: PrimeField> AgeInstructions<F> for AgeChip<F> {
+    type Num = AgeNumber<F>;
+
+    fn load_tables(&self, mut layouter: impl Layouter<F>) -> Result<(), Error> {
+        let config = self.config();
+
+        layouter.assign_table(
+            || "leap year table",
+            |mut table| {
+                // Entry used when the selector is disabled
+                table.assign_cell(|| "key", config.leap_table[0], 0, || Value::known(F::ZERO))?;
+                table.assign_cell(|| "leap", config.leap_table[1], 0, || Value::known(F::ZERO))?;
+                for x in 0..LEAP_TABLE_SIZE {
+                    let row = x as usize + 1;
+                    let leap = (x.is_multiple_of(4)) as u64;
+                    table.assign_cell(
+                        || format!("key {x}"),
+                        config.leap_table[0],
+                        row,
+                        || Value::known(F::from(x + 1)),
+                    )?;
+                    table.assign_cell(
+                        || format!("leap {x}"),
+                        config.leap_table[1],
+                        row,
+                        || Value::known(F::from(leap)),
+                    )?;
+                }
+                Ok(())
+            },
+        )?;
+
+        layouter.assign_table(
+            || "calendar table",
+            |mut table| {
+                // Entry used when the selector is disabled
+                for column in config.calendar_table {
+                    table.assign_cell(|| "disabled", column, 0, || Value::known(F::ZERO))?;
+                }
+                let mut row = 1;
+                for leap in [false, true] {
+                    for month in 1..=12 {
+                        for day in 1..=days_in_month(month, leap) {
+                            let entry = [month, day, leap as u64 + 1];
+                            for (column, value) in config.calendar_table.into_iter().zip(entry) {
+                                table.assign_cell(
+                                    || format!("date {month}/{day} (leap={leap})"),
+                                    column,
+                                    row,
+                                    || Value::known(F::from(value)),
+                                )?;
+                            }
+                            row += 1;
+                        }
+                    }
+                }
+                Ok(())
+            },
+        )
+    }
+
+    fn load_birth_date(
+        &self,
+        mut layouter: impl Layouter<F>,
+        digits: &[AssignedCell<F, F>; NUMBER_LENGTH],
+    ) -> Result<Self::Num, Error> {
+        let config = self.config();
+
+        layouter
+            .assign_region(
+                || "birth date",
+                |mut region| {
+                    config.s_date.enable(&mut region, 0)?;
+                    let birth_date = self.assign_digits(&mut region, digits)?;
+
+                    // The century is made of the two first digits and the year of the four first digits
+                    let pair = |i: usize| {
+                        digits[i].value().copied() * Value::known(F::from(10))
+                            + digits[i + 1].value()
+                    };
+                    let century = pair(0);
+                    let year_low = pair(2);
+                    let year_low_inv = year_low.map(|v| v.invert().unwrap_or(F::ZERO));
+                    let leap = century
+                        .zip(year_low)
+                        .map(|(century, year_low)| Self::leap_flag(century, year_low));
+
+                    region.assign_advice(
+                        || "year low inverse",
+                        config.advice[0],
+                        3,
+                        || year_low_inv,
+                    )?;
+                    region.assign_advice(|| "leap year", config.advice[1], 3, || leap)?;
+
+                    region.assign_advice(|| "birth date", config.advice[2], 2, || birth_date)
+                },
+            )
+            .map(AgeNumber)
+    }
+
+    fn constrain_age(
+        &self,
+        mut layouter: impl Layouter<F>,
+        birth_date: Self::Num,
+        difference: &[AssignedCell<F, F>; NUMBER_LENGTH],
+        today_row: usize,
+        min_age_row: usize,
+    ) -> Result<(), Error> {
+        let config = self.config();
+
+        layouter.assign_region(
+            || "age",
+            |mut region| {
+                config.s_age.enable(&mut region, 0)?;
+                self.assign_digits(&mut region, difference)?;
+
+                birth_date
+                    .0
+                    .copy_advice(|| "birth date", &mut region, config.advice[2], 2)?;
+                region.assign_advice_from_instance(
+                    || "today",
+                    config.instance,
+                    today_row,
+                    config.advice[0],
+                    3,
+                )?;
+                region.assign_advice_from_instance(
+                    || "min age",
+                    config.instance,
+                    min_age_row,
+                    config.advice[1],
+                    3,
+                )?;
+
+                Ok(())
+            },
+        )
+    }
+}
@@ -0,0 +1,23 @@
+use halo2_proofs::plonk::{Advice, Column, Instance, Selector, TableColumn};
+
+/// The configuration for the age circuit
+#[derive(Debug, Clone)]
+pub struct AgeConfig {
+    /// Advice columns of the chip
+    pub advice: [Column<Advice>; 3],
+
+    /// Instance column of the chip
+    pub instance: Column<Instance>,
+
+    /// Birth date selector of the chip, which also enables the calendar date lookups
+    pub s_date: Selector,
+
+    /// Age comparison selector of the chip
+    pub s_age: Selector,
+
+    /// Table columns for the leap year lookup: `(x + 1, x % 4 == 0)` for `x` in `[0, 99]`
+    pub leap_table: [TableColumn; 2],
+
+    /// Table columns for the calendar lookup: `(month, day, leap + 1)` for all valid dates
+    pub calendar_table: [TableColumn; 3],
+}
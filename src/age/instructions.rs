@@ -0,0 +1,37 @@
+use halo2_proofs::{
+    arithmetic::Field,
+    circuit::{AssignedCell, Chip, Layouter},
+    plonk::Error,
+};
+
+use crate::NUMBER_LENGTH;
+
+/// Traits for the chip that checks a birth date and an age
+pub trait AgeInstructions<F: Field>: Chip<F> {
+    /// The number type associated to the age instruction
+    type Num;
+
+    /// Loads the leap year and calendar lookup tables into the circuit.
+    fn load_tables(&self, layouter: impl Layouter<F>) -> Result<(), Error>;
+
+    /// Loads the digits of a birth date in `YYYYMMDD` decimal format, which are already assigned and range checked
+    /// in the circuit, and checks that it is a valid calendar date.
+    fn load_birth_date(
+        &self,
+        layouter: impl Layouter<F>,
+        digits: &[AssignedCell<F, F>; NUMBER_LENGTH],
+    ) -> Result<Self::Num, Error>;
+
+    /// Constrains the birth date to be at least `min_age` years before `today`, both read from the public inputs.
+    ///
+    /// The `difference` is made of the digits of `today - birth_date - min_age * 10000`, which are already assigned
+    /// and range checked in the circuit.
+    fn constrain_age(
+        &self,
+        layouter: impl Layouter<F>,
+        birth_date: Self::Num,
+        difference: &[AssignedCell<F, F>; NUMBER_LENGTH],
+        today_row: usize,
+        min_age_row: usize,
+    ) -> Result<(), Error>;
+}
@@ -0,0 +1,62 @@
+//! The age chip that provably checks a birth date in `YYYYMMDD` decimal representation
+//! The module is splitted into three files:
+//! - `chip.rs` contains the implementation of the chip that checks the birth date and the age
+//! - `config.rs` contains the configuration for the age circuit
+//! - `instructions.rs` contains the instructions interface for the age chip
+
+mod chip;
+mod config;
+mod instructions;
+
+pub use chip::*;
+pub use config::*;
+pub use instructions::*;
+
+/// The number of years to add to a `YYYYMMDD` date (i.e. a shift of 4 decimal digits)
+pub const YEAR_SHIFT: u64 = 10_000;
+
+/// Checks if a year is a leap year in the Gregorian calendar
+pub fn is_leap_year(year: u64) -> bool {
+    (year.is_multiple_of(4) && !year.is_multiple_of(100)) || year.is_multiple_of(400)
+}
+
+/// Computes the number of days of a month (`0` if the month is not valid)
+pub fn days_in_month(month: u64, leap_year: bool) -> u64 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if leap_year => 29,
+        2 => 28,
+        _ => 0,
+    }
+}
+
+/// Checks if a number in `YYYYMMDD` decimal representation is a valid calendar date
+pub fn is_valid_date(date: u64) -> bool {
+    let (year, month, day) = (date / 10_000, (date / 100) % 100, date % 100);
+
+    date < 100_000_000 && day >= 1 && day <= days_in_month(month, is_leap_year(year))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn valid_dates_are_accepted() {
+        assert!(is_valid_date(20000229));
+        assert!(is_valid_date(20240229));
+        assert!(is_valid_date(19991231));
+        assert!(is_valid_date(20260101));
+    }
+
+    #[test]
+    fn invalid_dates_are_rejected() {
+        assert!(!is_valid_date(19000229));
+        assert!(!is_valid_date(20230229));
+        assert!(!is_valid_date(20231301));
+        assert!(!is_valid_date(20230431));
+        assert!(!is_valid_date(20230100));
+        assert!(!is_valid_date(100000101));
+    }
+}
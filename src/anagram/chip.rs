@@ -0,0 +1,270 @@
+use std::marker::PhantomData;
+
+use halo2_proofs::{
+    arithmetic::Field,
+    circuit::{AssignedCell, Chip, Layouter, Region, Value},
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, Fixed, Instance, TableColumn},
+    poly::Rotation,
+};
+
+use crate::{digitsum::DIGIT_RANGE_UPPER_BOUND, AnagramConfig, AnagramInstructions, NUMBER_LENGTH};
+
+/// The value of the leading zeros of a number in the digit products, which is not the value of any digit
+fn leading_zero<F: Field>() -> F {
+    (0..DIGIT_RANGE_UPPER_BOUND).fold(F::ZERO, |value, _| value + F::ONE)
+}
+
+/// The chip that implements the anagram check instructions
+pub struct AnagramChip<F: Field> {
+    config: AnagramConfig,
+    _marker: PhantomData<F>,
+}
+
+impl<F: Field> AnagramChip<F> {
+    /// Creates a new instance of the anagram chip
+    pub fn construct(config: AnagramConfig) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Configures the anagram chip
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        advice: [Column<Advice>; 6],
+        instance: Column<Instance>,
+        constant: Column<Fixed>,
+        digit_table: TableColumn,
+    ) -> <Self as Chip<F>>::Config {
+        meta.enable_equality(instance);
+        meta.enable_constant(constant);
+        for column in &advice {
+            meta.enable_equality(*column);
+        }
+        let s_product = meta.selector();
+        let s_lookup = meta.complex_selector();
+        let s_leading = meta.complex_selector();
+        let one = || Expression::Constant(F::ONE);
+
+        meta.lookup(|meta| {
+            let s_lookup = meta.query_selector(s_lookup);
+            let digit = meta.query_advice(advice[0], Rotation::cur());
+            vec![(s_lookup * digit, digit_table)]
+        });
+
+        meta.lookup(|meta| {
+            // The digit which starts the number, i.e. the first digit after the leading zeros, is not zero
+            let s_leading = meta.query_selector(s_leading);
+            let digit = meta.query_advice(advice[0], Rotation::cur());
+            let previous_started = meta.query_advice(advice[4], Rotation::cur());
+            let started = meta.query_advice(advice[5], Rotation::cur());
+            vec![(
+                s_leading * (started - previous_started) * (digit - one()),
+                digit_table,
+            )]
+        });
+
+        meta.create_gate("digit_product", |meta| {
+            // This gate implements the product of (x - digit) for the digits of the provided number, where the
+            // leading zeros are replaced by 10 so that only numbers of the same length can be anagrams
+            // Here is the arrangement of the cells of the gate
+            //
+            // | a0  | a1    | a2    | a3 | a4       | a5       | s_product |
+            // |-----|-------|-------|----|----------|----------|-----------|
+            // | in0 | 1     | prod0 | x  | 0        | started0 | s_product |
+            // | in1 | prod0 | prod1 | x  | started0 | started1 | s_product |
+            // | ... | ...   | ...   | x  | ...      | ...      | s_product |
+            // | in7 | prod6 | prod7 | x  | started6 | started7 | s_product |
+            //
+            let digit = meta.query_advice(advice[0], Rotation::cur());
+            let previous = meta.query_advice(advice[1], Rotation::cur());
+            let product = meta.query_advice(advice[2], Rotation::cur());
+            let challenge = meta.query_advice(advice[3], Rotation::cur());
+            let previous_started = meta.query_advice(advice[4], Rotation::cur());
+            let started = meta.query_advice(advice[5], Rotation::cur());
+            let s_product = meta.query_selector(s_product);
+            let value = digit.clone()
+                + Expression::Constant(leading_zero::<F>()) * (one() - started.clone());
+
+            vec![
+                s_product.clone() * (previous * (challenge - value) - product),
+                s_product.clone() * started.clone() * (one() - started.clone()),
+                s_product.clone() * previous_started * (one() - started.clone()),
+                s_product * (one() - started) * digit,
+            ]
+        });
+
+        AnagramConfig {
+            advice,
+            instance,
+            s_product,
+            s_lookup,
+            s_leading,
+            digit_table,
+        }
+    }
+
+    /// Assigns the running product of `(x - digit)` once the digits are assigned in the first advice column
+    ///
+    /// The number is started from its first non zero digit, and the leading zeros before it count as `10`.
+    fn assign_product(
+        &self,
+        region: &mut Region<'_, F>,
+        digits: Vec<AssignedCell<F, F>>,
+        challenge_row: usize,
+    ) -> Result<AssignedCell<F, F>, Error> {
+        let config = self.config();
+
+        let mut previous_value =
+            region.assign_advice_from_constant(|| "one", config.advice[1], 0, F::ONE)?;
+        let mut previous_started =
+            region.assign_advice_from_constant(|| "not started", config.advice[4], 0, F::ZERO)?;
+        for (i, digit) in digits.into_iter().enumerate() {
+            config.s_product.enable(region, i)?;
+            config.s_leading.enable(region, i)?;
+
+            let challenge = region.assign_advice_from_instance(
+                || "challenge",
+                config.instance,
+                challenge_row,
+                config.advice[3],
+                i,
+            )?;
+            if i > 0 {
+                previous_value.copy_advice(
+                    || format!("digit product [{}]", i - 1),
+                    region,
+                    config.advice[1],
+                    i,
+                )?;
+                previous_started.copy_advice(
+                    || format!("started [{}]", i - 1),
+                    region,
+                    config.advice[4],
+                    i,
+                )?;
+            }
+            let started = previous_started.value().copied().zip(digit.value()).map(
+                |(previous_started, digit)| {
+                    if previous_started == F::ONE || *digit != F::ZERO {
+                        F::ONE
+                    } else {
+                        F::ZERO
+                    }
+                },
+            );
+            let value = digit.value().copied()
+                + started.map(|started| leading_zero::<F>() * (F::ONE - started));
+            previous_started = region.assign_advice(
+                || format!("started [{i}]"),
+                config.advice[5],
+                i,
+                || started,
+            )?;
+            previous_value = region.assign_advice(
+                || format!("digit product [{i}]"),
+                config.advice[2],
+                i,
+                || previous_value.value().copied() * (challenge.value().copied() - value),
+            )?;
+        }
+
+        Ok(previous_value)
+    }
+}
+
+impl<F: Field> Chip<F> for AnagramChip<F> {
+    type Config = AnagramConfig;
+    type Loaded = ();
+
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+/// A digit product represented in the chip
+#[derive(Clone, Debug)]
+pub struct AnagramNumber<F: Field>(AssignedCell<F, F>);
+
+impl<F: Field> AnagramInstructions<F> for AnagramChip<F> {
+    type Num = AnagramNumber<F>;
+
+    fn load_private(
+        &self,
+        mut layouter: impl Layouter<F>,
+        values: [Value<F>; NUMBER_LENGTH],
+        challenge_row: usize,
+    ) -> Result<Self::Num, Error> {
+        let config = self.config();
+
+        layouter
+            .assign_region(
+                || "private digits product",
+                |mut region| {
+                    let digits = values
+                        .into_iter()
+                        .enumerate()
+                        .map(|(i, value)| {
+                            config.s_lookup.enable(&mut region, i)?;
+                            region.assign_advice(
+                                || format!("witness {i}"),
+                                config.advice[0],
+                                i,
+                                || value,
+                            )
+                        })
+                        .collect::<Result<Vec<_>, Error>>()?;
+
+                    self.assign_product(&mut region, digits, challenge_row)
+                },
+            )
+            .map(AnagramNumber)
+    }
+
+    fn load_public(
+        &self,
+        mut layouter: impl Layouter<F>,
+        row: usize,
+        challenge_row: usize,
+    ) -> Result<Self::Num, Error> {
+        let config = self.config();
+
+        layouter
+            .assign_region(
+                || "public digits product",
+                |mut region| {
+                    let digits = (0..NUMBER_LENGTH)
+                        .map(|i| {
+                            region.assign_advice_from_instance(
+                                || format!("public input {i}"),
+                                config.instance,
+                                row + i,
+                                config.advice[0],
+                                i,
+                            )
+                        })
+                        .collect::<Result<Vec<_>, Error>>()?;
+
+                    self.assign_product(&mut region, digits, challenge_row)
+                },
+            )
+            .map(AnagramNumber)
+    }
+
+    fn constrain_equal(
+        &self,
+        mut layouter: impl Layouter<F>,
+        lhs: Self::Num,
+        rhs: Self::Num,
+    ) -> Result<(), Error> {
+        layouter.assign_region(
+            || "equal digit products",
+            |mut region| region.constrain_equal(lhs.0.cell(), rhs.0.cell()),
+        )
+    }
+}
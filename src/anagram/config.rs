@@ -0,0 +1,23 @@
+use halo2_proofs::plonk::{Advice, Column, Instance, Selector, TableColumn};
+
+/// The configuration for the anagram circuit
+#[derive(Debug, Clone)]
+pub struct AnagramConfig {
+    /// Advice columns of the chip
+    pub advice: [Column<Advice>; 6],
+
+    /// Instance column of the chip
+    pub instance: Column<Instance>,
+
+    /// Product selector of the chip
+    pub s_product: Selector,
+
+    /// Lookup selector for the digit range check
+    pub s_lookup: Selector,
+
+    /// Lookup selector for the check that the first digit after the leading zeros is not zero
+    pub s_leading: Selector,
+
+    /// Table column for the digit range check lookup (shared with the digit sum chip)
+    pub digit_table: TableColumn,
+}
@@ -0,0 +1,42 @@
+use halo2_proofs::{
+    arithmetic::Field,
+    circuit::{Chip, Layouter, Value},
+    plonk::Error,
+};
+
+use crate::NUMBER_LENGTH;
+
+/// Traits for the chip that checks that two numbers are anagrams
+///
+/// Two numbers are made of the same multiset of digits if and only if the polynomials
+/// `(X - d0) * ... * (X - d7)` built from their digits are equal, which is checked by
+/// evaluating them at a random challenge `x`. The leading zeros of the numbers count as the digit `10`, so that two
+/// numbers of different lengths (e.g. `1020` and `201`) are never anagrams.
+pub trait AnagramInstructions<F: Field>: Chip<F> {
+    /// The number type associated to the anagram instruction
+    type Num;
+
+    /// Loads a private input in a decimal format and computes the product of `(x - digit)` for the challenge read in the public inputs.
+    fn load_private(
+        &self,
+        layouter: impl Layouter<F>,
+        values: [Value<F>; NUMBER_LENGTH],
+        challenge_row: usize,
+    ) -> Result<Self::Num, Error>;
+
+    /// Loads a public input in a decimal format (starting at `row`) and computes the product of `(x - digit)` for the challenge read in the public inputs.
+    fn load_public(
+        &self,
+        layouter: impl Layouter<F>,
+        row: usize,
+        challenge_row: usize,
+    ) -> Result<Self::Num, Error>;
+
+    /// Constrains two products to be equal.
+    fn constrain_equal(
+        &self,
+        layouter: impl Layouter<F>,
+        lhs: Self::Num,
+        rhs: Self::Num,
+    ) -> Result<(), Error>;
+}
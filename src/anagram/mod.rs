@@ -0,0 +1,13 @@
+//! The anagram chip that provably checks that two decimal numbers are made of the same multiset of digits
+//! The module is splitted into three files:
+//! - `chip.rs` contains the implementation of the chip that computes the digit products
+//! - `config.rs` contains the configuration for the anagram circuit
+//! - `instructions.rs` contains the instructions interface for the anagram chip
+
+mod chip;
+mod config;
+mod instructions;
+
+pub use chip::*;
+pub use config::*;
+pub use instructions::*;
@@ -0,0 +1,179 @@
+use std::marker::PhantomData;
+
+use halo2_proofs::{
+    circuit::{AssignedCell, Chip, Layouter, Value},
+    pasta::group::ff::PrimeField,
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, Fixed, TableColumn},
+    poly::Rotation,
+};
+
+use crate::{ArmstrongConfig, ArmstrongInstructions, NUMBER_LENGTH};
+
+/// The number of rows of the digit power lookup table (one per decimal digit)
+const POWER_TABLE_ROWS: u64 = 10;
+
+/// The chip that implements the Armstrong number instructions
+pub struct ArmstrongChip<F: PrimeField> {
+    config: ArmstrongConfig,
+    _marker: PhantomData<F>,
+}
+
+impl<F: PrimeField> ArmstrongChip<F> {
+    /// Creates a new instance of the Armstrong chip
+    pub fn construct(config: ArmstrongConfig) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Configures the Armstrong chip
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        advice: [Column<Advice>; 3],
+        constant: Column<Fixed>,
+        digit_table: TableColumn,
+    ) -> <Self as Chip<F>>::Config {
+        meta.enable_constant(constant);
+        for column in &advice {
+            meta.enable_equality(*column);
+        }
+        let s_power = meta.complex_selector();
+        let s_significant = meta.complex_selector();
+        let power_table = [meta.lookup_table_column(), meta.lookup_table_column()];
+
+        // This lookup implements the sum of the digit powers of the provided number
+        // Here is the arrangement of the cells of the lookup
+        //
+        // | a0  | a1   | a2   | s_power |
+        // |-----|------|------|---------|
+        // | in0 | 0    | sum0 | s_power |
+        // | in1 | sum0 | sum1 | s_power |
+        // | ... | ...  | ...  | s_power |
+        // | in7 | sum6 | sum7 | s_power |
+        //
+        // The `(digit, sum - sum_prev)` pair must be a `(digit, digit^n)` entry of the power table.
+        // When the selector is disabled, the `(0, 0)` entry of the table matches.
+        meta.lookup(|meta| {
+            let s_power = meta.query_selector(s_power);
+            let digit = meta.query_advice(advice[0], Rotation::cur());
+            let sum_prev = meta.query_advice(advice[1], Rotation::cur());
+            let sum = meta.query_advice(advice[2], Rotation::cur());
+            vec![
+                (s_power.clone() * digit, power_table[0]),
+                (s_power * (sum - sum_prev), power_table[1]),
+            ]
+        });
+
+        // When s_significant is enabled, the lookup constrains the (already range checked) digit to {1..10}.
+        meta.lookup(|meta| {
+            let s_significant = meta.query_selector(s_significant);
+            let digit = meta.query_advice(advice[0], Rotation::cur());
+            vec![(
+                s_significant * (digit - Expression::Constant(F::ONE)),
+                digit_table,
+            )]
+        });
+
+        ArmstrongConfig {
+            advice,
+            s_power,
+            s_significant,
+            power_table,
+            digit_table,
+        }
+    }
+}
+
+impl<F: PrimeField> Chip<F> for ArmstrongChip<F> {
+    type Config = ArmstrongConfig;
+    type Loaded = ();
+
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+impl<F: PrimeField> ArmstrongInstructions<F> for ArmstrongChip<F> {
+    fn load_table(&self, mut layouter: impl Layouter<F>, digit_count: usize) -> Result<(), Error> {
+        let config = self.config();
+
+        layouter.assign_table(
+            || "digit power table",
+            |mut table| {
+                for digit in 0..POWER_TABLE_ROWS {
+                    let power = digit.pow(digit_count as u32);
+                    for (column, value) in config.power_table.into_iter().zip([digit, power]) {
+                        table.assign_cell(
+                            || format!("digit {digit} power"),
+                            column,
+                            digit as usize,
+                            || Value::known(F::from(value)),
+                        )?;
+                    }
+                }
+                Ok(())
+            },
+        )
+    }
+
+    fn constrain_armstrong(
+        &self,
+        mut layouter: impl Layouter<F>,
+        digits: &[AssignedCell<F, F>; NUMBER_LENGTH],
+        number: &AssignedCell<F, F>,
+        digit_count: usize,
+    ) -> Result<(), Error> {
+        let config = self.config();
+        let first = NUMBER_LENGTH - digit_count;
+
+        layouter.assign_region(
+            || "digit power sum",
+            |mut region| {
+                // The digits before the significant ones are zero
+                for digit in &digits[..first] {
+                    region.constrain_constant(digit.cell(), F::ZERO)?;
+                }
+
+                let mut previous_value =
+                    region.assign_advice_from_constant(|| "zero", config.advice[1], 0, F::ZERO)?;
+                for (i, digit) in digits[first..].iter().enumerate() {
+                    config.s_power.enable(&mut region, i)?;
+
+                    let digit = digit.copy_advice(
+                        || format!("digit {i}"),
+                        &mut region,
+                        config.advice[0],
+                        i,
+                    )?;
+                    if i > 0 {
+                        previous_value.copy_advice(
+                            || format!("digit power sum [{}]", i - 1),
+                            &mut region,
+                            config.advice[1],
+                            i,
+                        )?;
+                    }
+                    let power = digit.value().map(|digit| digit.pow([digit_count as u64]));
+                    previous_value = region.assign_advice(
+                        || format!("digit power sum [{i}]"),
+                        config.advice[2],
+                        i,
+                        || previous_value.value().copied() + power,
+                    )?;
+                }
+
+                // The most significant digit is not a leading zero
+                if digit_count > 1 {
+                    config.s_significant.enable(&mut region, 0)?;
+                }
+
+                region.constrain_equal(previous_value.cell(), number.cell())
+            },
+        )
+    }
+}
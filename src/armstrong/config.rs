@@ -0,0 +1,20 @@
+use halo2_proofs::plonk::{Advice, Column, Selector, TableColumn};
+
+/// The configuration for the Armstrong circuit
+#[derive(Debug, Clone)]
+pub struct ArmstrongConfig {
+    /// Advice columns of the chip
+    pub advice: [Column<Advice>; 3],
+
+    /// Lookup selector for the digit power check
+    pub s_power: Selector,
+
+    /// Lookup selector for the non zero most significant digit check
+    pub s_significant: Selector,
+
+    /// Table columns for the digit power lookup, keyed by digit
+    pub power_table: [TableColumn; 2],
+
+    /// Table column for the digit range check lookup (shared with the digit sum chip)
+    pub digit_table: TableColumn,
+}
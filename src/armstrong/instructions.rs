@@ -0,0 +1,25 @@
+use halo2_proofs::{
+    arithmetic::Field,
+    circuit::{AssignedCell, Chip, Layouter},
+    plonk::Error,
+};
+
+use crate::NUMBER_LENGTH;
+
+/// Traits for the chip that checks Armstrong numbers
+pub trait ArmstrongInstructions<F: Field>: Chip<F> {
+    /// Loads the digit power lookup table for a digit count into the circuit.
+    fn load_table(&self, layouter: impl Layouter<F>, digit_count: usize) -> Result<(), Error>;
+
+    /// Constrains the number to be the sum of its last `digit_count` digits raised to the power `digit_count`.
+    ///
+    /// The other digits are constrained to be zero, and the most significant of the `digit_count` digits to be non zero
+    /// (unless the digit count is `1`).
+    fn constrain_armstrong(
+        &self,
+        layouter: impl Layouter<F>,
+        digits: &[AssignedCell<F, F>; NUMBER_LENGTH],
+        number: &AssignedCell<F, F>,
+        digit_count: usize,
+    ) -> Result<(), Error>;
+}
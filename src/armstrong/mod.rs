@@ -0,0 +1,13 @@
+//! The Armstrong chip that provably checks that a fixed length number is the sum of its digits raised to the power of its digit count
+//! The module is splitted into three files:
+//! - `chip.rs` contains the implementation of the chip that computes the sum of the digit powers
+//! - `config.rs` contains the configuration for the Armstrong circuit
+//! - `instructions.rs` contains the instructions interface for the Armstrong chip
+
+mod chip;
+mod config;
+mod instructions;
+
+pub use chip::*;
+pub use config::*;
+pub use instructions::*;
@@ -1,48 +1,247 @@
 //! The final circuit that uses one or more chips to implement the desired proof system.
 
+mod age;
+mod anagram;
+mod armstrong;
+mod dfa;
+mod digitalroot;
+mod digitproduct;
+mod equalsum;
+mod harshad;
+mod kakuro;
+mod merkle;
+mod minmax;
+mod modulo;
+mod nullifier;
+mod occurrence;
+mod palindrome;
+mod statement;
+mod sudoku;
+
+pub use age::*;
+pub use anagram::*;
+pub use armstrong::*;
+pub use dfa::*;
+pub use digitalroot::*;
+pub use digitproduct::*;
+pub use equalsum::*;
+pub use harshad::*;
+pub use kakuro::*;
+pub use merkle::*;
+pub use minmax::*;
+pub use modulo::*;
+pub use nullifier::*;
+pub use occurrence::*;
+pub use palindrome::*;
+pub use statement::*;
+pub use sudoku::*;
+
+use anyhow::anyhow;
 use halo2_proofs::{
     circuit::{Layouter, SimpleFloorPlanner, Value},
-    pasta::{group::ff::PrimeField, EqAffine, Fp},
-    plonk::{
-        create_proof, keygen_pk, keygen_vk, verify_proof, Circuit, ConstraintSystem, Error,
-        ProvingKey, SingleVerifier, VerifyingKey,
-    },
-    poly::commitment::Params,
-    transcript::{Blake2bRead, Blake2bWrite, Challenge255},
+    pasta::{group::ff::PrimeField, Fp},
+    plonk::{Circuit, ConstraintSystem, Error},
 };
-use rand_core::OsRng;
 
 use crate::{
-    Bytes, CircuitKeyGenerator, CircuitProver, CircuitVerifier, DigitSumChip, DigitSumConfig,
-    DigitSumInstructions, DigitSumSecretWitness, StdResult, NUMBER_LENGTH,
+    digitsum::DIGIT_RANGE_UPPER_BOUND, field_decimal_capacity, CircuitSizeParameter, DecimalNumber,
+    DigitSumChip, DigitSumConfig, DigitSumInstructions, DigitSumSecretWitness, StdResult,
+    MAX_AGGREGATE_COUNT, NUMBER_LENGTH,
 };
 
 /// The size parameter of the circuit: the circuit must fit into 2^k rows.
 const DIGIT_SUM_CIRCUIT_SIZE_PARAMETER: u32 = 5;
 
-/// The circuit implementation for digit sum
-pub struct DigitSumCircuit<F: PrimeField> {
-    /// The number with which to compute the digit sum in decimal representation
-    pub number: [Value<F>; NUMBER_LENGTH],
+/// The number of rows reserved by the proof system (blinding factors) at the end of the circuit (upper bound)
+const RESERVED_ROWS: usize = 10;
+
+/// Computes the smallest size parameter of a circuit which uses a number of rows
+fn size_parameter_for_rows(rows: usize) -> u32 {
+    (rows + RESERVED_ROWS).next_power_of_two().trailing_zeros()
+}
+
+/// The shape of the digit sum circuit, i.e. everything that determines its layout and its keys but its witness
+///
+/// The keys are generated and the proofs are verified from the shape only, so that the verifier never constructs
+/// a witness, and the prover and the verifier can not use diverging layouts.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DigitSumCircuitShape {
+    /// The count of numbers of which the digit sums are aggregated
+    pub count: usize,
+
+    /// The number of digits of the numbers
+    pub number_length: usize,
+
+    /// The disclosure mask of the digits of the numbers
+    pub disclosure: Vec<bool>,
+
+    /// Whether the most significant digit of the numbers is constrained to be non-zero
+    pub non_zero_leading_digit: bool,
 
     /// The size parameter of the circuit: the circuit must fit into 2^k rows.
     pub k: u32,
 }
 
+impl Default for DigitSumCircuitShape {
+    fn default() -> Self {
+        Self::new(1, NUMBER_LENGTH).unwrap() // safe unwrap
+    }
+}
+
+impl DigitSumCircuitShape {
+    /// Creates the shape of a digit sum circuit for a count of numbers with a number of digits
+    ///
+    /// The count of numbers is at most `MAX_AGGREGATE_COUNT`, and the numbers have at most the count of digits of
+    /// the numbers below the field modulus.
+    pub fn new(count: usize, number_length: usize) -> StdResult<Self> {
+        if !(1..=MAX_AGGREGATE_COUNT).contains(&count) {
+            return Err(anyhow!(
+                "Invalid count of numbers {count}, expected a value between 1 and {MAX_AGGREGATE_COUNT}"
+            ));
+        }
+        let max_number_length = field_decimal_capacity::<Fp>();
+        if !(1..=max_number_length).contains(&number_length) {
+            return Err(anyhow!(
+                "Invalid count of digits {number_length}, expected a value between 1 and {max_number_length}"
+            ));
+        }
+        // Each number uses one row per digit and one row for its leading digit, and the aggregation uses one row
+        // per additional number
+        let rows = count * (number_length + 1) + count.saturating_sub(1);
+
+        Ok(Self {
+            count,
+            number_length,
+            disclosure: vec![false; number_length],
+            non_zero_leading_digit: false,
+            k: size_parameter_for_rows(rows).max(DIGIT_SUM_CIRCUIT_SIZE_PARAMETER),
+        })
+    }
+
+    /// The base of the representation of the numbers, i.e. the count of values of a digit
+    pub fn base(&self) -> usize {
+        DIGIT_RANGE_UPPER_BOUND
+    }
+
+    /// Discloses the digits at some positions of the numbers (starting at `0` for the most significant digit)
+    pub fn with_disclosure(mut self, positions: &[usize]) -> StdResult<Self> {
+        for position in positions {
+            match self.disclosure.get_mut(*position) {
+                Some(true) => return Err(anyhow!("Position {position} is disclosed twice")),
+                Some(disclosed) => *disclosed = true,
+                None => {
+                    return Err(anyhow!(
+                        "Invalid position {position}, expected less than {}",
+                        self.number_length
+                    ))
+                }
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// Constrains the most significant digit of the numbers to be non-zero
+    pub fn with_non_zero_leading_digit(mut self) -> Self {
+        self.non_zero_leading_digit = true;
+        self
+    }
+}
+
+/// The circuit implementation for digit sum
+///
+/// The digit sums of several numbers are computed in one region each, and aggregated into a total which is
+/// the first public input of the circuit. The digits selected by the disclosure mask are the next public inputs,
+/// number by number.
+/// The numbers have `NUMBER_LENGTH` digits by default, and may have more digits (e.g. numbers which do not fit in a
+/// `u64`): the digit sum does not recompose the numbers, so their length is not bounded by the field modulus.
+/// The most significant digit of the numbers may be constrained to be non-zero, so that the numbers have exactly
+/// their count of digits.
+pub struct DigitSumCircuit<F: PrimeField> {
+    /// The numbers with which to compute the digit sum in decimal representation
+    pub numbers: Vec<Vec<Value<F>>>,
+
+    /// The shape of the circuit
+    pub shape: DigitSumCircuitShape,
+}
+
 impl<F: PrimeField> Default for DigitSumCircuit<F> {
     fn default() -> Self {
-        Self::new(0).unwrap()
+        Self::from_shape(DigitSumCircuitShape::default())
     }
 }
 
 impl<F: PrimeField> DigitSumCircuit<F> {
     /// Creates a new digit sum circuit
-    pub fn new(number: u64) -> StdResult<Self> {
-        let k = DIGIT_SUM_CIRCUIT_SIZE_PARAMETER;
-        let secret_witness_number = DigitSumSecretWitness::<F>::new(number);
-        let number: [Value<F>; NUMBER_LENGTH] = secret_witness_number.try_into().unwrap();
+    pub fn new(number: impl Into<DecimalNumber>) -> StdResult<Self> {
+        Self::new_aggregate(&[number.into()])
+    }
+
+    /// Creates a new digit sum circuit for a number with an exact count of digits
+    ///
+    /// The number is padded with leading zeros to the count of digits, which must not be less than the count of
+    /// digits of the number (including its leading zeros).
+    pub fn new_with_length(
+        number: impl Into<DecimalNumber>,
+        number_length: usize,
+    ) -> StdResult<Self> {
+        Ok(Self {
+            numbers: vec![DigitSumSecretWitness::<F>::new(number).padded_digits(number_length)?],
+            ..Self::new_without_witness(1, number_length)?
+        })
+    }
 
-        Ok(Self { number, k })
+    /// Creates a new digit sum circuit which aggregates the digit sums of several numbers
+    ///
+    /// The numbers are padded with leading zeros to the length of the longest one, and to at least `NUMBER_LENGTH` digits.
+    pub fn new_aggregate<N: Clone + Into<DecimalNumber>>(numbers: &[N]) -> StdResult<Self> {
+        if numbers.is_empty() {
+            return Err(anyhow!("Expected at least one number"));
+        }
+        let numbers: Vec<DecimalNumber> = numbers.iter().cloned().map(Into::into).collect();
+        let number_length = numbers
+            .iter()
+            .map(|number| number.digits().len())
+            .fold(NUMBER_LENGTH, usize::max);
+
+        Ok(Self {
+            numbers: numbers
+                .iter()
+                .map(|number| {
+                    DigitSumSecretWitness::<F>::new(number.clone()).padded_digits(number_length)
+                })
+                .collect::<StdResult<_>>()?,
+            ..Self::new_without_witness(numbers.len(), number_length)?
+        })
+    }
+
+    /// Creates a new digit sum circuit without witness for a count of numbers with a number of digits
+    pub fn new_without_witness(count: usize, number_length: usize) -> StdResult<Self> {
+        Ok(Self::from_shape(DigitSumCircuitShape::new(
+            count,
+            number_length,
+        )?))
+    }
+
+    /// Creates a new digit sum circuit without witness from its shape
+    pub fn from_shape(shape: DigitSumCircuitShape) -> Self {
+        Self {
+            numbers: vec![vec![Value::unknown(); shape.number_length]; shape.count],
+            shape,
+        }
+    }
+
+    /// Discloses the digits at some positions of the numbers (starting at `0` for the most significant digit)
+    pub fn with_disclosure(mut self, positions: &[usize]) -> StdResult<Self> {
+        self.shape = self.shape.with_disclosure(positions)?;
+
+        Ok(self)
+    }
+
+    /// Constrains the most significant digit of the numbers to be non-zero
+    pub fn with_non_zero_leading_digit(mut self) -> Self {
+        self.shape = self.shape.with_non_zero_leading_digit();
+        self
     }
 }
 
@@ -51,7 +250,7 @@ impl<F: PrimeField> Circuit<F> for DigitSumCircuit<F> {
     type FloorPlanner = SimpleFloorPlanner;
 
     fn without_witnesses(&self) -> Self {
-        Self::default()
+        Self::from_shape(self.shape.clone())
     }
 
     fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
@@ -69,53 +268,38 @@ impl<F: PrimeField> Circuit<F> for DigitSumCircuit<F> {
 
         chip.load_table(layouter.namespace(|| "digit range table"))?;
 
-        let sum = chip.load_private(layouter.namespace(|| "private number"), self.number)?;
+        let mut sums = Vec::with_capacity(self.numbers.len());
+        let mut row = 1;
+        for (i, number) in self.numbers.iter().enumerate() {
+            let (digits, sum) =
+                chip.load_digits(layouter.namespace(|| format!("private number {i}")), number)?;
+            if self.shape.non_zero_leading_digit {
+                chip.constrain_non_zero(
+                    layouter.namespace(|| format!("leading digit of number {i}")),
+                    &digits[0],
+                )?;
+            }
+            for (position, digit) in digits.into_iter().enumerate() {
+                if self.shape.disclosure[position] {
+                    chip.expose_public(
+                        layouter.namespace(|| format!("disclose digit {position} of number {i}")),
+                        digit,
+                        row,
+                    )?;
+                    row += 1;
+                }
+            }
+            sums.push(sum);
+        }
+        let sum = chip.aggregate(layouter.namespace(|| "aggregate digit sums"), &sums)?;
 
         chip.expose_public(layouter.namespace(|| "expose digit sum"), sum, 0)
     }
 }
 
-impl CircuitKeyGenerator<EqAffine> for DigitSumCircuit<Fp> {
-    fn generate_setup_params(&self) -> StdResult<Params<EqAffine>> {
-        Ok(Params::<EqAffine>::new(self.k))
-    }
-
-    fn generate_keys(&self) -> StdResult<(ProvingKey<EqAffine>, VerifyingKey<EqAffine>)> {
-        let params = self.generate_setup_params()?;
-        let vk = keygen_vk(&params, self)?;
-        let pk = keygen_pk(&params, vk.clone(), self)?;
-
-        Ok((pk, vk))
-    }
-}
-
-impl CircuitProver<EqAffine, Fp> for DigitSumCircuit<Fp> {
-    fn prove(self, public_inputs: &[Fp]) -> StdResult<Bytes> {
-        let params = self.generate_setup_params()?;
-        let (pk, _) = self.generate_keys()?;
-        let mut transcript = Blake2bWrite::<_, _, Challenge255<_>>::init(vec![]);
-        create_proof(
-            &params,
-            &pk,
-            &[self],
-            &[&[public_inputs]],
-            OsRng,
-            &mut transcript,
-        )?;
-
-        Ok(transcript.finalize())
-    }
-}
-
-impl CircuitVerifier<EqAffine, Fp> for DigitSumCircuit<Fp> {
-    fn verify(self, public_inputs: &[Fp], proof: &Bytes) -> StdResult<()> {
-        let params = self.generate_setup_params()?;
-        let (_, vk) = self.generate_keys()?;
-        let strategy = SingleVerifier::new(&params);
-        let mut transcript = Blake2bRead::<_, _, Challenge255<_>>::init(proof.as_slice());
-        verify_proof(&params, &vk, strategy, &[&[public_inputs]], &mut transcript)?;
-
-        Ok(())
+impl<F: PrimeField> CircuitSizeParameter for DigitSumCircuit<F> {
+    fn size_parameter(&self) -> u32 {
+        self.shape.k
     }
 }
 
@@ -123,18 +307,88 @@ impl CircuitVerifier<EqAffine, Fp> for DigitSumCircuit<Fp> {
 mod tests {
     use halo2_proofs::{dev::MockProver, pasta::Fp};
 
-    use crate::DigitSumPublicInput;
+    use crate::{DigitSumPublicInput, DigitSumSecretWitness};
 
     use super::*;
 
+    /// A circuit composing two digit sum chips which share the digit range table: the digits loaded by the first
+    /// chip are copied into the second one, and the sum and a prefix sum of the copied digits are exposed
+    #[derive(Default)]
+    struct ComposedDigitSumCircuit {
+        number: [Value<Fp>; NUMBER_LENGTH],
+        prefix_length: usize,
+    }
+
+    impl Circuit<Fp> for ComposedDigitSumCircuit {
+        type Config = (DigitSumConfig, DigitSumConfig);
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self {
+                number: [Value::unknown(); NUMBER_LENGTH],
+                prefix_length: self.prefix_length,
+            }
+        }
+
+        fn configure(meta: &mut ConstraintSystem<Fp>) -> Self::Config {
+            let advice = [(); 3].map(|_| meta.advice_column());
+            let instance = meta.instance_column();
+            let loader_config = DigitSumChip::configure(meta, advice, instance);
+            let advice = [(); 3].map(|_| meta.advice_column());
+            let summer_config = DigitSumChip::configure_with_table(
+                meta,
+                advice,
+                instance,
+                loader_config.digit_table,
+            );
+
+            (loader_config, summer_config)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<Fp>,
+        ) -> Result<(), Error> {
+            let loader_chip = DigitSumChip::<Fp>::construct(config.0);
+            let summer_chip = DigitSumChip::<Fp>::construct(config.1);
+            loader_chip.load_table(layouter.namespace(|| "shared digit range table"))?;
+
+            let loaded = loader_chip
+                .load_digits_with_prefix_sums(layouter.namespace(|| "load digits"), &self.number)?;
+            let digits = loaded
+                .digits
+                .iter()
+                .map(|digit| digit.cell().clone())
+                .collect::<Vec<_>>();
+            let summed =
+                summer_chip.sum_assigned_digits(layouter.namespace(|| "sum digits"), &digits)?;
+            summer_chip.constrain_equal(
+                layouter.namespace(|| "equal sums"),
+                loaded.sum(),
+                summed.sum(),
+            )?;
+            summer_chip.expose_public(
+                layouter.namespace(|| "expose sum"),
+                summed.sum().clone(),
+                0,
+            )?;
+            summer_chip.expose_public(
+                layouter.namespace(|| "expose prefix sum"),
+                summed.prefix_sums[self.prefix_length - 1].clone(),
+                1,
+            )
+        }
+    }
+
     #[test]
     fn digit_sum_circuit_proof_succeeds_with_valid_public_input() {
-        let secret_witness_number = 12340000;
+        let secret_witness_number = 12340000_u64;
         let public_input_digitsum = 10;
 
         let circuit = DigitSumCircuit::<Fp>::new(secret_witness_number).unwrap();
         let prover = MockProver::run(
-            circuit.k,
+            circuit.shape.k,
             &circuit,
             vec![vec![DigitSumPublicInput::new(public_input_digitsum).into()]],
         )
@@ -145,25 +399,190 @@ mod tests {
 
     #[test]
     fn digit_sum_circuit_proof_fails_with_invalid_public_input() {
-        let secret_witness_number = 10000000;
+        let secret_witness_number = 10000000_u64;
         let public_input_digitsum = 2;
 
         let circuit = DigitSumCircuit::<Fp>::new(secret_witness_number).unwrap();
         let prover = MockProver::run(
-            circuit.k,
+            circuit.shape.k,
+            &circuit,
+            vec![vec![DigitSumPublicInput::new(public_input_digitsum).into()]],
+        )
+        .unwrap();
+
+        prover.verify().expect_err("the proof should be invalid");
+    }
+
+    #[test]
+    fn digit_sum_circuit_proof_succeeds_with_aggregated_digit_sums() {
+        let secret_witness_numbers = [123_u64, 4567, 89, 0, 99999999];
+        let public_input_digitsum = 6 + 22 + 17 + 72;
+
+        let circuit = DigitSumCircuit::<Fp>::new_aggregate(&secret_witness_numbers).unwrap();
+        let prover = MockProver::run(
+            circuit.shape.k,
             &circuit,
             vec![vec![DigitSumPublicInput::new(public_input_digitsum).into()]],
         )
         .unwrap();
 
+        prover.verify().expect("the proof should be valid");
+    }
+
+    #[test]
+    fn digit_sum_circuit_proof_fails_with_invalid_aggregated_digit_sum() {
+        let secret_witness_numbers = [123_u64, 4567, 89];
+        let public_input_digitsum = 6 + 22;
+
+        let circuit = DigitSumCircuit::<Fp>::new_aggregate(&secret_witness_numbers).unwrap();
+        let prover = MockProver::run(
+            circuit.shape.k,
+            &circuit,
+            vec![vec![DigitSumPublicInput::new(public_input_digitsum).into()]],
+        )
+        .unwrap();
+
+        prover.verify().expect_err("the proof should be invalid");
+    }
+
+    #[test]
+    fn digit_sum_circuit_proof_succeeds_with_number_beyond_u64() {
+        let secret_witness_number = "1234567890".repeat(4).parse::<DecimalNumber>().unwrap();
+
+        for (public_input_digitsum, valid) in [(180, true), (179, false)] {
+            let circuit = DigitSumCircuit::<Fp>::new(secret_witness_number.clone()).unwrap();
+            assert_eq!(40, circuit.shape.number_length);
+            let prover = MockProver::run(
+                circuit.shape.k,
+                &circuit,
+                vec![vec![DigitSumPublicInput::new(public_input_digitsum).into()]],
+            )
+            .unwrap();
+
+            assert_eq!(valid, prover.verify().is_ok());
+        }
+    }
+
+    #[test]
+    fn digit_sum_circuit_proof_succeeds_with_exact_count_of_digits() {
+        for number in ["12340000", "123400"] {
+            let circuit = DigitSumCircuit::<Fp>::new_with_length(
+                number.parse::<DecimalNumber>().unwrap(),
+                number.len(),
+            )
+            .unwrap()
+            .with_non_zero_leading_digit();
+            let prover = MockProver::run(
+                circuit.shape.k,
+                &circuit,
+                vec![vec![DigitSumPublicInput::new(10).into()]],
+            )
+            .unwrap();
+
+            prover.verify().expect("the proof should be valid");
+        }
+    }
+
+    #[test]
+    fn digit_sum_circuit_proof_fails_with_leading_zero() {
+        for (number, number_length) in [("01234000", 8), ("1234000", 8)] {
+            let circuit = DigitSumCircuit::<Fp>::new_with_length(
+                number.parse::<DecimalNumber>().unwrap(),
+                number_length,
+            )
+            .unwrap()
+            .with_non_zero_leading_digit();
+            let prover = MockProver::run(
+                circuit.shape.k,
+                &circuit,
+                vec![vec![DigitSumPublicInput::new(10).into()]],
+            )
+            .unwrap();
+
+            prover.verify().expect_err("the proof should be invalid");
+        }
+    }
+
+    #[test]
+    fn digit_sum_circuit_rejects_number_longer_than_its_count_of_digits() {
+        let number = "00123".parse::<DecimalNumber>().unwrap();
+
+        assert!(DigitSumCircuit::<Fp>::new_with_length(number.clone(), 4).is_err());
+        assert!(DigitSumCircuit::<Fp>::new_with_length(number, 5).is_ok());
+    }
+
+    #[test]
+    fn digit_sum_circuit_size_grows_with_aggregated_numbers() {
+        assert_eq!(
+            DIGIT_SUM_CIRCUIT_SIZE_PARAMETER,
+            DigitSumCircuitShape::new(1, NUMBER_LENGTH).unwrap().k
+        );
+        assert_eq!(7, DigitSumCircuitShape::new(10, NUMBER_LENGTH).unwrap().k);
+        assert!(DigitSumCircuit::<Fp>::new_aggregate::<u64>(&[]).is_err());
+    }
+
+    #[test]
+    fn digit_sum_circuit_shape_rejects_invalid_count_of_numbers_or_digits() {
+        let capacity = field_decimal_capacity::<Fp>();
+
+        assert!(DigitSumCircuitShape::new(MAX_AGGREGATE_COUNT, capacity).is_ok());
+        for (count, number_length) in [
+            (0, NUMBER_LENGTH),
+            (MAX_AGGREGATE_COUNT + 1, NUMBER_LENGTH),
+            (usize::MAX, NUMBER_LENGTH),
+            (1, 0),
+            (1, capacity + 1),
+            (1, usize::MAX),
+        ] {
+            assert!(DigitSumCircuitShape::new(count, number_length).is_err());
+        }
+        assert!(DigitSumCircuit::<Fp>::new(
+            "1".repeat(capacity + 1).parse::<DecimalNumber>().unwrap()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn digit_sum_circuit_proof_succeeds_with_disclosed_digits() {
+        let public_input = DigitSumPublicInput::new(10)
+            .with_disclosed_digits(vec!["3=4".parse().unwrap(), "0=1".parse().unwrap()]);
+
+        let circuit = DigitSumCircuit::<Fp>::new(12340000_u64)
+            .unwrap()
+            .with_disclosure(&public_input.disclosed_positions())
+            .unwrap();
+        let prover = MockProver::run(circuit.shape.k, &circuit, vec![public_input.into()]).unwrap();
+
+        prover.verify().expect("the proof should be valid");
+    }
+
+    #[test]
+    fn digit_sum_circuit_proof_fails_with_invalid_disclosed_digits() {
+        let public_input = DigitSumPublicInput::new(10)
+            .with_disclosed_digits(vec!["3=4".parse().unwrap(), "0=2".parse().unwrap()]);
+
+        let circuit = DigitSumCircuit::<Fp>::new(12340000_u64)
+            .unwrap()
+            .with_disclosure(&public_input.disclosed_positions())
+            .unwrap();
+        let prover = MockProver::run(circuit.shape.k, &circuit, vec![public_input.into()]).unwrap();
+
         prover.verify().expect_err("the proof should be invalid");
     }
 
+    #[test]
+    fn digit_sum_circuit_rejects_invalid_disclosure() {
+        let circuit = || DigitSumCircuit::<Fp>::new(12340000_u64).unwrap();
+
+        assert!(circuit().with_disclosure(&[NUMBER_LENGTH]).is_err());
+        assert!(circuit().with_disclosure(&[1, 1]).is_err());
+    }
+
     #[test]
     fn out_of_range_digit_is_rejected() {
         let digit_sum_claimed = 10;
         let circuit = DigitSumCircuit::<Fp> {
-            number: [
+            numbers: vec![vec![
                 Value::known(Fp::from(10)),
                 Value::known(Fp::from(0)),
                 Value::known(Fp::from(0)),
@@ -172,11 +591,11 @@ mod tests {
                 Value::known(Fp::from(0)),
                 Value::known(Fp::from(0)),
                 Value::known(Fp::from(0)),
-            ],
-            k: DIGIT_SUM_CIRCUIT_SIZE_PARAMETER,
+            ]],
+            shape: DigitSumCircuitShape::default(),
         };
         let prover = MockProver::run(
-            circuit.k,
+            circuit.shape.k,
             &circuit,
             vec![vec![DigitSumPublicInput::new(digit_sum_claimed).into()]],
         )
@@ -187,6 +606,52 @@ mod tests {
             .expect_err("out-of-range digit values should be rejected by the range check lookup");
     }
 
+    #[test]
+    fn digit_sum_circuit_without_witnesses_has_unknown_values_and_same_shape() {
+        let circuit = DigitSumCircuit::<Fp>::new_aggregate(&[12340000_u64, 5678])
+            .unwrap()
+            .with_disclosure(&[1])
+            .unwrap()
+            .with_non_zero_leading_digit();
+        let circuit_without_witnesses = circuit.without_witnesses();
+
+        assert_eq!(circuit.shape, circuit_without_witnesses.shape);
+        assert_eq!(
+            circuit.numbers.len(),
+            circuit_without_witnesses.numbers.len()
+        );
+        for number in circuit_without_witnesses
+            .numbers
+            .iter()
+            .chain(&DigitSumCircuit::<Fp>::default().numbers)
+        {
+            for digit in number {
+                digit.assert_if_known(|_| false);
+            }
+        }
+    }
+
+    #[test]
+    fn composed_digit_sum_chips_share_table_and_assigned_digits() {
+        let number = DigitSumSecretWitness::<Fp>::new(31257425_u64)
+            .try_into()
+            .unwrap();
+        for (public_input, is_valid) in [([29, 6], true), ([29, 7], false), ([28, 6], false)] {
+            let circuit = ComposedDigitSumCircuit {
+                number,
+                prefix_length: 3,
+            };
+            let prover = MockProver::run(
+                DIGIT_SUM_CIRCUIT_SIZE_PARAMETER,
+                &circuit,
+                vec![public_input.map(Fp::from).to_vec()],
+            )
+            .unwrap();
+
+            assert_eq!(is_valid, prover.verify().is_ok());
+        }
+    }
+
     /// A malicious prover can exploit modular arithmetic to forge a digit sum.
     /// Here the "digits" are [-1, 0, 0, 0, 0, 0, 0, 1], which sum to 0 in the
     /// finite field because (p - 1) + 1 = 0 mod p. The range check lookup
@@ -195,7 +660,7 @@ mod tests {
     fn field_wrapping_arithmetic_is_rejected() {
         let digit_sum_claimed = 0;
         let circuit = DigitSumCircuit::<Fp> {
-            number: [
+            numbers: vec![vec![
                 Value::known(-Fp::from(1)),
                 Value::known(Fp::from(0)),
                 Value::known(Fp::from(0)),
@@ -204,11 +669,11 @@ mod tests {
                 Value::known(Fp::from(0)),
                 Value::known(Fp::from(0)),
                 Value::known(Fp::from(1)),
-            ],
-            k: DIGIT_SUM_CIRCUIT_SIZE_PARAMETER,
+            ]],
+            shape: DigitSumCircuitShape::default(),
         };
         let prover = MockProver::run(
-            circuit.k,
+            circuit.shape.k,
             &circuit,
             vec![vec![DigitSumPublicInput::new(digit_sum_claimed).into()]],
         )
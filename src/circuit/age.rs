@@ -0,0 +1,212 @@
+use anyhow::anyhow;
+use halo2_proofs::{
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    pasta::group::ff::PrimeField,
+    plonk::{Circuit, ConstraintSystem, Error},
+};
+
+use crate::{
+    is_valid_date, AgeChip, AgeConfig, AgeInstructions, CircuitSizeParameter, DigitSumChip,
+    DigitSumConfig, DigitSumInstructions, DigitSumSecretWitness, StdResult, NUMBER_LENGTH,
+    YEAR_SHIFT,
+};
+
+/// The size parameter of the circuit: the circuit must fit into 2^k rows.
+const AGE_CIRCUIT_SIZE_PARAMETER: u32 = 10;
+
+/// The circuit implementation for age verification from a birth date in `YYYYMMDD` decimal representation
+///
+/// The public inputs are the date of today (in `YYYYMMDD` decimal representation) and the minimum age in years.
+pub struct AgeCircuit<F: PrimeField> {
+    /// The birth date in `YYYYMMDD` decimal representation
+    pub birth_date: [Value<F>; NUMBER_LENGTH],
+
+    /// The decimal representation of `today - birth_date - min_age * 10000`
+    pub difference: [Value<F>; NUMBER_LENGTH],
+
+    /// The size parameter of the circuit: the circuit must fit into 2^k rows.
+    pub k: u32,
+}
+
+impl<F: PrimeField> Default for AgeCircuit<F> {
+    fn default() -> Self {
+        Self {
+            birth_date: [Value::unknown(); NUMBER_LENGTH],
+            difference: [Value::unknown(); NUMBER_LENGTH],
+            k: AGE_CIRCUIT_SIZE_PARAMETER,
+        }
+    }
+}
+
+impl<F: PrimeField> AgeCircuit<F> {
+    /// Creates a new age circuit
+    pub fn new(birth_date: u64, today: u64, min_age: u64) -> StdResult<Self> {
+        let k = AGE_CIRCUIT_SIZE_PARAMETER;
+        if !is_valid_date(birth_date) {
+            return Err(anyhow!(
+                "Birth date {birth_date} is not a valid YYYYMMDD date"
+            ));
+        }
+        let min_birth_date_shift = min_age
+            .checked_mul(YEAR_SHIFT)
+            .and_then(|shift| shift.checked_add(birth_date))
+            .ok_or_else(|| anyhow!("Minimum age {min_age} is too large"))?;
+        let difference = today.checked_sub(min_birth_date_shift).ok_or_else(|| {
+            anyhow!("Birth date {birth_date} is not at least {min_age} years before {today}")
+        })?;
+        let birth_date = DigitSumSecretWitness::<F>::new(birth_date).try_into()?;
+        let difference = DigitSumSecretWitness::<F>::new(difference).try_into()?;
+
+        Ok(Self {
+            birth_date,
+            difference,
+            k,
+        })
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for AgeCircuit<F> {
+    type Config = (DigitSumConfig, AgeConfig);
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let advice = (0..3).map(|_| meta.advice_column()).collect::<Vec<_>>();
+        let advice: [_; 3] = advice.try_into().unwrap();
+        let instance = meta.instance_column();
+        let digit_sum_config = DigitSumChip::configure(meta, advice, instance);
+        let age_config = AgeChip::configure(meta, advice, instance);
+
+        (digit_sum_config, age_config)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let (digit_sum_config, age_config) = config;
+        let digit_sum_chip = DigitSumChip::<F>::construct(digit_sum_config);
+        let chip = AgeChip::construct(age_config);
+
+        digit_sum_chip.load_table(layouter.namespace(|| "digit range table"))?;
+        chip.load_tables(layouter.namespace(|| "calendar tables"))?;
+
+        let (birth_date, _) = digit_sum_chip
+            .load_private_digits(layouter.namespace(|| "birth date digits"), self.birth_date)?;
+        let (difference, _) = digit_sum_chip
+            .load_private_digits(layouter.namespace(|| "difference digits"), self.difference)?;
+
+        let birth_date = chip.load_birth_date(
+            layouter.namespace(|| "private birth date"),
+            &birth_date.map(|digit| digit.cell().clone()),
+        )?;
+
+        chip.constrain_age(
+            layouter.namespace(|| "age"),
+            birth_date,
+            &difference.map(|digit| digit.cell().clone()),
+            0,
+            1,
+        )
+    }
+}
+
+impl<F: PrimeField> CircuitSizeParameter for AgeCircuit<F> {
+    fn size_parameter(&self) -> u32 {
+        self.k
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use halo2_proofs::{dev::MockProver, pasta::Fp};
+
+    use crate::AgePublicInput;
+
+    use super::*;
+
+    fn run_age_circuit(circuit: AgeCircuit<Fp>, today: u64, min_age: u64) -> MockProver<Fp> {
+        MockProver::run(
+            circuit.k,
+            &circuit,
+            vec![AgePublicInput::new(today, min_age).into()],
+        )
+        .unwrap()
+    }
+
+    /// Creates an age circuit without checking the birth date off-circuit
+    fn unchecked_age_circuit(birth_date: u64, difference: u64) -> AgeCircuit<Fp> {
+        AgeCircuit {
+            birth_date: DigitSumSecretWitness::new(birth_date).try_into().unwrap(),
+            difference: DigitSumSecretWitness::new(difference).try_into().unwrap(),
+            k: AGE_CIRCUIT_SIZE_PARAMETER,
+        }
+    }
+
+    #[test]
+    fn age_circuit_proof_succeeds_with_old_enough_birth_date() {
+        let circuit = AgeCircuit::<Fp>::new(19900615, 20261018, 18).unwrap();
+        let prover = run_age_circuit(circuit, 20261018, 18);
+
+        prover.verify().expect("the proof should be valid");
+    }
+
+    #[test]
+    fn age_circuit_proof_succeeds_on_birthday() {
+        let circuit = AgeCircuit::<Fp>::new(20081018, 20261018, 18).unwrap();
+        let prover = run_age_circuit(circuit, 20261018, 18);
+
+        prover.verify().expect("the proof should be valid");
+    }
+
+    #[test]
+    fn age_circuit_proof_succeeds_with_leap_day_birth_date() {
+        for birth_date in [20000229, 20040229] {
+            let circuit = AgeCircuit::<Fp>::new(birth_date, 20261018, 18).unwrap();
+            let prover = run_age_circuit(circuit, 20261018, 18);
+
+            prover.verify().expect("the proof should be valid");
+        }
+    }
+
+    #[test]
+    fn age_circuit_creation_fails_with_too_recent_birth_date() {
+        let circuit = AgeCircuit::<Fp>::new(20081019, 20261018, 18);
+
+        assert!(circuit.is_err(), "the birth date should be too recent");
+    }
+
+    #[test]
+    fn age_circuit_creation_fails_with_too_large_min_age() {
+        for min_age in [u64::MAX / YEAR_SHIFT + 1, u64::MAX] {
+            let circuit = AgeCircuit::<Fp>::new(19900615, 20261018, min_age);
+
+            assert!(circuit.is_err(), "the minimum age should be too large");
+        }
+    }
+
+    #[test]
+    fn age_circuit_proof_fails_with_wrong_difference() {
+        let circuit = unchecked_age_circuit(20081019, 0);
+        let prover = run_age_circuit(circuit, 20261018, 18);
+
+        prover.verify().expect_err("the proof should be invalid");
+    }
+
+    #[test]
+    fn age_circuit_proof_fails_with_invalid_birth_dates() {
+        let today = 20261018;
+        for birth_date in [19000229, 19970229, 19971301, 19970431, 19970100, 19970000] {
+            let circuit = unchecked_age_circuit(birth_date, today - birth_date - 18 * YEAR_SHIFT);
+            let prover = run_age_circuit(circuit, today, 18);
+
+            prover
+                .verify()
+                .expect_err(&format!("the birth date {birth_date} should be invalid"));
+        }
+    }
+}
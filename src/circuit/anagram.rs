@@ -0,0 +1,160 @@
+use halo2_proofs::{
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    pasta::group::ff::PrimeField,
+    plonk::{Circuit, ConstraintSystem, Error},
+};
+
+use crate::{
+    load_digit_table, AnagramChip, AnagramConfig, AnagramInstructions, CircuitSizeParameter,
+    DigitSumSecretWitness, StdResult, NUMBER_LENGTH,
+};
+
+/// The size parameter of the circuit: the circuit must fit into 2^k rows.
+const ANAGRAM_CIRCUIT_SIZE_PARAMETER: u32 = 5;
+
+/// The circuit implementation for anagram numbers
+///
+/// The public inputs are the digits of the public number followed by the challenge of the check.
+pub struct AnagramCircuit<F: PrimeField> {
+    /// The secret number in decimal representation
+    pub number: [Value<F>; NUMBER_LENGTH],
+
+    /// The size parameter of the circuit: the circuit must fit into 2^k rows.
+    pub k: u32,
+}
+
+impl<F: PrimeField> Default for AnagramCircuit<F> {
+    fn default() -> Self {
+        Self {
+            number: [Value::unknown(); NUMBER_LENGTH],
+            k: ANAGRAM_CIRCUIT_SIZE_PARAMETER,
+        }
+    }
+}
+
+impl<F: PrimeField> AnagramCircuit<F> {
+    /// Creates a new anagram circuit
+    pub fn new(number: u64) -> StdResult<Self> {
+        let k = ANAGRAM_CIRCUIT_SIZE_PARAMETER;
+        let number = DigitSumSecretWitness::<F>::new(number).try_into()?;
+
+        Ok(Self { number, k })
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for AnagramCircuit<F> {
+    type Config = AnagramConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let advice = (0..6).map(|_| meta.advice_column()).collect::<Vec<_>>();
+        let instance = meta.instance_column();
+        let constant = meta.fixed_column();
+        let digit_table = meta.lookup_table_column();
+
+        AnagramChip::configure(
+            meta,
+            advice.try_into().unwrap(),
+            instance,
+            constant,
+            digit_table,
+        )
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let digit_table = config.digit_table;
+        let chip = AnagramChip::construct(config);
+
+        load_digit_table(layouter.namespace(|| "digit range table"), digit_table)?;
+
+        let challenge_row = NUMBER_LENGTH;
+        let private_product = chip.load_private(
+            layouter.namespace(|| "private number"),
+            self.number,
+            challenge_row,
+        )?;
+        let public_product =
+            chip.load_public(layouter.namespace(|| "public number"), 0, challenge_row)?;
+
+        chip.constrain_equal(
+            layouter.namespace(|| "anagram"),
+            private_product,
+            public_product,
+        )
+    }
+}
+
+impl<F: PrimeField> CircuitSizeParameter for AnagramCircuit<F> {
+    fn size_parameter(&self) -> u32 {
+        self.k
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use halo2_proofs::{dev::MockProver, pasta::Fp};
+
+    use crate::AnagramPublicInput;
+
+    use super::*;
+
+    fn run_anagram_circuit(circuit: AnagramCircuit<Fp>, public_number: u64) -> MockProver<Fp> {
+        let public_inputs = AnagramPublicInput::new(public_number).try_into().unwrap();
+        MockProver::run(circuit.k, &circuit, vec![public_inputs]).unwrap()
+    }
+
+    #[test]
+    fn anagram_circuit_proof_succeeds_with_permuted_digits() {
+        for (secret_number, public_number) in
+            [(12345678, 87654321), (11223344, 43214321), (1020, 2001)]
+        {
+            let circuit = AnagramCircuit::<Fp>::new(secret_number).unwrap();
+            let prover = run_anagram_circuit(circuit, public_number);
+
+            prover.verify().expect("the proof should be valid");
+        }
+    }
+
+    #[test]
+    fn anagram_circuit_proof_fails_with_different_digits() {
+        // The two last pairs have the same digit sum (and the same product of their non zero digits)
+        for (secret_number, public_number) in [(11223344, 11223345), (19, 28), (229, 166)] {
+            let circuit = AnagramCircuit::<Fp>::new(secret_number).unwrap();
+            let prover = run_anagram_circuit(circuit, public_number);
+
+            prover.verify().expect_err("the proof should be invalid");
+        }
+    }
+
+    #[test]
+    fn anagram_circuit_proof_fails_with_different_lengths() {
+        // The digits are the same once the shorter number is padded with leading zeros
+        for (secret_number, public_number) in [(1020, 201), (201, 1020), (21, 120)] {
+            let circuit = AnagramCircuit::<Fp>::new(secret_number).unwrap();
+            let prover = run_anagram_circuit(circuit, public_number);
+
+            prover.verify().expect_err("the proof should be invalid");
+        }
+    }
+
+    #[test]
+    fn out_of_range_digit_is_rejected() {
+        let mut circuit = AnagramCircuit::<Fp>::new(0).unwrap();
+        circuit.number[0] = Value::known(Fp::from(10));
+        let mut public_inputs: Vec<Fp> = AnagramPublicInput::new(0).try_into().unwrap();
+        public_inputs[0] = Fp::from(10);
+        let prover = MockProver::run(circuit.k, &circuit, vec![public_inputs]).unwrap();
+
+        prover
+            .verify()
+            .expect_err("out-of-range digit values should be rejected by the range check lookup");
+    }
+}
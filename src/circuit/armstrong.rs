@@ -0,0 +1,199 @@
+use anyhow::anyhow;
+use halo2_proofs::{
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    pasta::group::ff::PrimeField,
+    plonk::{Circuit, ConstraintSystem, Error},
+};
+
+use crate::{
+    ArmstrongChip, ArmstrongConfig, ArmstrongInstructions, CircuitSizeParameter, DigitSumChip,
+    DigitSumConfig, DigitSumInstructions, DigitSumSecretWitness, ModuloChip, ModuloConfig,
+    ModuloInstructions, StdResult, NUMBER_LENGTH,
+};
+
+/// The size parameter of the circuit: the circuit must fit into 2^k rows.
+const ARMSTRONG_CIRCUIT_SIZE_PARAMETER: u32 = 6;
+
+/// The circuit implementation for Armstrong numbers (i.e. numbers equal to the sum of their digits raised to the power of the digit count)
+///
+/// The digits assigned by the digit sum chip are recomposed by the modulo chip and summed as powers by the Armstrong chip.
+/// The digit sum is optionally exposed as the single public input of the circuit.
+pub struct ArmstrongCircuit<F: PrimeField> {
+    /// The secret number in decimal representation
+    pub number: [Value<F>; NUMBER_LENGTH],
+
+    /// The number of significant digits of the secret number
+    pub digit_count: usize,
+
+    /// Whether the digit sum is exposed as a public input
+    pub digit_sum: bool,
+
+    /// The size parameter of the circuit: the circuit must fit into 2^k rows.
+    pub k: u32,
+}
+
+impl<F: PrimeField> Default for ArmstrongCircuit<F> {
+    fn default() -> Self {
+        Self::new_without_witness(1, false).unwrap() // safe unwrap
+    }
+}
+
+impl<F: PrimeField> ArmstrongCircuit<F> {
+    /// Creates a new Armstrong circuit
+    pub fn new(number: u64, digit_count: usize, digit_sum: bool) -> StdResult<Self> {
+        let number = DigitSumSecretWitness::<F>::new(number).try_into()?;
+
+        Ok(Self {
+            number,
+            ..Self::new_without_witness(digit_count, digit_sum)?
+        })
+    }
+
+    /// Creates a new Armstrong circuit without witness
+    pub fn new_without_witness(digit_count: usize, digit_sum: bool) -> StdResult<Self> {
+        let k = ARMSTRONG_CIRCUIT_SIZE_PARAMETER;
+        if !(1..=NUMBER_LENGTH).contains(&digit_count) {
+            return Err(anyhow!(
+                "Invalid digit count {digit_count}, expected a value between 1 and {NUMBER_LENGTH}"
+            ));
+        }
+
+        Ok(Self {
+            number: [Value::unknown(); NUMBER_LENGTH],
+            digit_count,
+            digit_sum,
+            k,
+        })
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for ArmstrongCircuit<F> {
+    type Config = (DigitSumConfig, ModuloConfig, ArmstrongConfig);
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::new_without_witness(self.digit_count, self.digit_sum).unwrap() // safe unwrap
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let advice = (0..4).map(|_| meta.advice_column()).collect::<Vec<_>>();
+        let instance = meta.instance_column();
+        let constant = meta.fixed_column();
+        let digit_sum_config =
+            DigitSumChip::configure(meta, advice[..3].try_into().unwrap(), instance);
+        let modulo_config = ModuloChip::configure(
+            meta,
+            advice.clone().try_into().unwrap(),
+            instance,
+            constant,
+            digit_sum_config.digit_table,
+        );
+        let armstrong_config = ArmstrongChip::configure(
+            meta,
+            advice[..3].try_into().unwrap(),
+            constant,
+            digit_sum_config.digit_table,
+        );
+
+        (digit_sum_config, modulo_config, armstrong_config)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let (digit_sum_config, modulo_config, armstrong_config) = config;
+        let digit_sum_chip = DigitSumChip::<F>::construct(digit_sum_config);
+        let modulo_chip = ModuloChip::construct(modulo_config);
+        let chip = ArmstrongChip::construct(armstrong_config);
+
+        digit_sum_chip.load_table(layouter.namespace(|| "digit range table"))?;
+        chip.load_table(layouter.namespace(|| "digit power table"), self.digit_count)?;
+
+        let (digits, sum) = digit_sum_chip
+            .load_private_digits(layouter.namespace(|| "private number"), self.number)?;
+        if self.digit_sum {
+            digit_sum_chip.expose_public(layouter.namespace(|| "expose digit sum"), sum, 0)?;
+        }
+
+        let digits = digits.map(|digit| digit.cell().clone());
+        let number = modulo_chip.recompose(layouter.namespace(|| "recompose number"), &digits)?;
+
+        chip.constrain_armstrong(
+            layouter.namespace(|| "armstrong"),
+            &digits,
+            number.cell(),
+            self.digit_count,
+        )
+    }
+}
+
+impl<F: PrimeField> CircuitSizeParameter for ArmstrongCircuit<F> {
+    fn size_parameter(&self) -> u32 {
+        self.k
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use halo2_proofs::{dev::MockProver, pasta::Fp};
+
+    use super::*;
+
+    fn run_armstrong_circuit(
+        circuit: ArmstrongCircuit<Fp>,
+        public_input: Vec<u64>,
+    ) -> MockProver<Fp> {
+        let public_input = public_input.into_iter().map(Fp::from).collect();
+        MockProver::run(circuit.k, &circuit, vec![public_input]).unwrap()
+    }
+
+    #[test]
+    fn armstrong_circuit_proof_succeeds_with_armstrong_number() {
+        for (secret_witness_number, digit_count) in [
+            (0, 1),
+            (7, 1),
+            (153, 3),
+            (9474, 4),
+            (548834, 6),
+            (24678050, 8),
+        ] {
+            let circuit =
+                ArmstrongCircuit::<Fp>::new(secret_witness_number, digit_count, false).unwrap();
+            let prover = run_armstrong_circuit(circuit, vec![]);
+
+            prover.verify().expect("the proof should be valid");
+        }
+    }
+
+    #[test]
+    fn armstrong_circuit_proof_fails_with_non_armstrong_number() {
+        for (secret_witness_number, digit_count) in
+            [(154, 3), (153, 4), (153, 2), (10, 2), (1, 8), (12345678, 8)]
+        {
+            let circuit =
+                ArmstrongCircuit::<Fp>::new(secret_witness_number, digit_count, false).unwrap();
+            let prover = run_armstrong_circuit(circuit, vec![]);
+
+            prover.verify().expect_err("the proof should be invalid");
+        }
+    }
+
+    #[test]
+    fn armstrong_circuit_reveals_digit_sum() {
+        let circuit = ArmstrongCircuit::<Fp>::new(153, 3, true).unwrap();
+        let prover = run_armstrong_circuit(circuit, vec![9]);
+        prover.verify().expect("the proof should be valid");
+
+        let circuit = ArmstrongCircuit::<Fp>::new(153, 3, true).unwrap();
+        let prover = run_armstrong_circuit(circuit, vec![10]);
+        prover.verify().expect_err("the proof should be invalid");
+    }
+
+    #[test]
+    fn armstrong_circuit_rejects_invalid_digit_count() {
+        assert!(ArmstrongCircuit::<Fp>::new(0, 0, false).is_err());
+        assert!(ArmstrongCircuit::<Fp>::new(0, NUMBER_LENGTH + 1, false).is_err());
+    }
+}
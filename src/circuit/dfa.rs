@@ -0,0 +1,193 @@
+use anyhow::anyhow;
+use halo2_proofs::{
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    pasta::group::ff::PrimeField,
+    plonk::{Circuit, ConstraintSystem, Error},
+};
+
+use crate::{
+    Automaton, AutomatonChip, AutomatonConfig, AutomatonInstructions, CircuitSizeParameter,
+    StdResult,
+};
+
+use super::size_parameter_for_rows;
+
+/// The circuit implementation for the membership of a digit string in the language of an automaton
+///
+/// The circuit has no public inputs: the automaton is embedded in the lookup tables (and therefore in the verifying key),
+/// and the length of the digit string is part of the shape of the circuit.
+pub struct AutomatonCircuit<F: PrimeField> {
+    /// The public automaton
+    pub automaton: Automaton,
+
+    /// The digits of the secret string
+    pub digits: Vec<Value<F>>,
+
+    /// The codes of the states visited by the automaton walk
+    pub states: Vec<Value<F>>,
+
+    /// The size parameter of the circuit: the circuit must fit into 2^k rows.
+    pub k: u32,
+}
+
+impl<F: PrimeField> AutomatonCircuit<F> {
+    /// Creates a new automaton circuit
+    pub fn new(automaton: Automaton, word: &str) -> StdResult<Self> {
+        let digits = word
+            .chars()
+            .map(|c| {
+                c.to_digit(10)
+                    .map(u64::from)
+                    .ok_or_else(|| anyhow!("Invalid digit '{c}' in the digit string"))
+            })
+            .collect::<StdResult<Vec<_>>>()?;
+        let states = automaton.walk(&digits)?;
+        let k = Self::size_parameter_for(&automaton, digits.len());
+
+        Ok(Self {
+            automaton,
+            digits: digits
+                .into_iter()
+                .map(|d| Value::known(F::from(d)))
+                .collect(),
+            states: states
+                .into_iter()
+                .map(|s| Value::known(F::from(s)))
+                .collect(),
+            k,
+        })
+    }
+
+    /// Creates a new automaton circuit without witness for a digit string length
+    pub fn new_without_witness(automaton: Automaton, length: usize) -> Self {
+        let k = Self::size_parameter_for(&automaton, length);
+
+        Self {
+            automaton,
+            digits: vec![Value::unknown(); length],
+            states: vec![Value::unknown(); length + 1],
+            k,
+        }
+    }
+
+    /// Computes the size parameter of the circuit for an automaton and a digit string length
+    fn size_parameter_for(automaton: &Automaton, length: usize) -> u32 {
+        let table_rows = automaton
+            .transition_codes()
+            .len()
+            .max(automaton.accepting_codes().len())
+            + 1;
+
+        size_parameter_for_rows(table_rows.max(length + 1))
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for AutomatonCircuit<F> {
+    type Config = AutomatonConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::new_without_witness(self.automaton.clone(), self.digits.len())
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let advice = (0..3).map(|_| meta.advice_column()).collect::<Vec<_>>();
+        // The circuit has no public inputs, but the proof system expects an instance column
+        let _instance = meta.instance_column();
+        let constant = meta.fixed_column();
+        AutomatonChip::configure(meta, advice.try_into().unwrap(), constant)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let chip = AutomatonChip::construct(config);
+
+        chip.load_table(layouter.namespace(|| "automaton table"), &self.automaton)?;
+
+        chip.load_private(
+            layouter.namespace(|| "private digit string"),
+            &self.automaton,
+            &self.digits,
+            &self.states,
+        )
+    }
+}
+
+impl<F: PrimeField> CircuitSizeParameter for AutomatonCircuit<F> {
+    fn size_parameter(&self) -> u32 {
+        self.k
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use halo2_proofs::{dev::MockProver, pasta::Fp};
+
+    use super::*;
+
+    /// Postal codes made of 5 digits which do not start with `00`
+    const POSTAL_CODE_AUTOMATON: &str = r#"
+        start s0
+        accept s5
+        s0 0 zero
+        s0 1-9 s1
+        zero 1-9 s2
+        s1 0-9 s2
+        s2 0-9 s3
+        s3 0-9 s4
+        s4 0-9 s5
+    "#;
+
+    fn run_automaton_circuit(circuit: AutomatonCircuit<Fp>) -> MockProver<Fp> {
+        MockProver::run(circuit.k, &circuit, vec![vec![]]).unwrap()
+    }
+
+    #[test]
+    fn automaton_circuit_proof_succeeds_with_accepted_string() {
+        let automaton: Automaton = POSTAL_CODE_AUTOMATON.parse().unwrap();
+        for word in ["75001", "01000", "99999"] {
+            let circuit = AutomatonCircuit::<Fp>::new(automaton.clone(), word).unwrap();
+            let prover = run_automaton_circuit(circuit);
+
+            prover.verify().expect("the proof should be valid");
+        }
+    }
+
+    #[test]
+    fn automaton_circuit_creation_fails_with_rejected_string() {
+        let automaton: Automaton = POSTAL_CODE_AUTOMATON.parse().unwrap();
+        for word in ["00100", "7500", "750011", "75a01"] {
+            let circuit = AutomatonCircuit::<Fp>::new(automaton.clone(), word);
+
+            assert!(circuit.is_err(), "the string {word} should be rejected");
+        }
+    }
+
+    #[test]
+    fn automaton_circuit_proof_fails_with_forged_walk() {
+        let automaton: Automaton = POSTAL_CODE_AUTOMATON.parse().unwrap();
+        let mut circuit = AutomatonCircuit::<Fp>::new(automaton.clone(), "75001").unwrap();
+        circuit.digits[0] = Value::known(Fp::from(0));
+        let prover = run_automaton_circuit(circuit);
+
+        prover
+            .verify()
+            .expect_err("a digit without transition should be rejected");
+    }
+
+    #[test]
+    fn automaton_circuit_proof_fails_with_non_accepting_end_state() {
+        let automaton: Automaton = "start a\naccept b\na 0-9 a\nb 0-9 b".parse().unwrap();
+        let mut circuit = AutomatonCircuit::<Fp>::new_without_witness(automaton, 3);
+        circuit.digits = vec![Value::known(Fp::from(1)); 3];
+        circuit.states = vec![Value::known(Fp::from(1)); 4];
+        let prover = run_automaton_circuit(circuit);
+
+        prover
+            .verify()
+            .expect_err("a non accepting end state should be rejected");
+    }
+}
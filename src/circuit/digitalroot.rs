@@ -0,0 +1,225 @@
+use halo2_proofs::{
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    pasta::group::ff::PrimeField,
+    plonk::{Circuit, ConstraintSystem, Error},
+};
+
+use crate::{
+    CircuitSizeParameter, DigitSumChip, DigitSumConfig, DigitSumInstructions,
+    DigitSumSecretWitness, DigitalRootChip, DigitalRootConfig, DigitalRootInstructions, ModuloChip,
+    ModuloConfig, ModuloInstructions, StdResult, DIGITAL_ROOT_ITERATIONS, NUMBER_LENGTH,
+};
+
+/// The size parameter of the circuit: the circuit must fit into 2^k rows.
+const DIGITAL_ROOT_CIRCUIT_SIZE_PARAMETER: u32 = 7;
+
+/// The circuit implementation for digital root and additive persistence
+///
+/// The digit sum chip is applied `DIGITAL_ROOT_ITERATIONS` times: each intermediate sum is decomposed in
+/// range checked digits, whose recomposition by the modulo chip is constrained to be equal to the sum.
+/// The public inputs are the digital root, followed by the additive persistence if it is proven.
+pub struct DigitalRootCircuit<F: PrimeField> {
+    /// The secret number in decimal representation
+    pub number: [Value<F>; NUMBER_LENGTH],
+
+    /// The intermediate digit sums in decimal representation
+    pub intermediate_sums: [[Value<F>; NUMBER_LENGTH]; DIGITAL_ROOT_ITERATIONS - 1],
+
+    /// Whether the additive persistence is exposed as a public input
+    pub persistence: bool,
+
+    /// The size parameter of the circuit: the circuit must fit into 2^k rows.
+    pub k: u32,
+}
+
+impl<F: PrimeField> Default for DigitalRootCircuit<F> {
+    fn default() -> Self {
+        Self::new_without_witness(false)
+    }
+}
+
+impl<F: PrimeField> DigitalRootCircuit<F> {
+    /// Creates a new digital root circuit
+    pub fn new(number: u64, persistence: bool) -> StdResult<Self> {
+        let digits: [u64; NUMBER_LENGTH] = DigitSumSecretWitness::<F>::new(number).try_into()?;
+        let mut circuit = Self::new_without_witness(persistence);
+        circuit.number = DigitSumSecretWitness::<F>::new(number).try_into()?;
+        let mut sum = digits.iter().sum::<u64>();
+        for intermediate_sum in circuit.intermediate_sums.iter_mut() {
+            let digits: [u64; NUMBER_LENGTH] = DigitSumSecretWitness::<F>::new(sum).try_into()?;
+            *intermediate_sum = DigitSumSecretWitness::<F>::new(sum).try_into()?;
+            sum = digits.iter().sum::<u64>();
+        }
+
+        Ok(circuit)
+    }
+
+    /// Creates a new digital root circuit without witness
+    pub fn new_without_witness(persistence: bool) -> Self {
+        Self {
+            number: [Value::unknown(); NUMBER_LENGTH],
+            intermediate_sums: [[Value::unknown(); NUMBER_LENGTH]; DIGITAL_ROOT_ITERATIONS - 1],
+            persistence,
+            k: DIGITAL_ROOT_CIRCUIT_SIZE_PARAMETER,
+        }
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for DigitalRootCircuit<F> {
+    type Config = (DigitSumConfig, ModuloConfig, DigitalRootConfig);
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::new_without_witness(self.persistence)
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let advice = (0..4).map(|_| meta.advice_column()).collect::<Vec<_>>();
+        let instance = meta.instance_column();
+        let constant = meta.fixed_column();
+        let digit_sum_config =
+            DigitSumChip::configure(meta, advice[..3].try_into().unwrap(), instance);
+        let modulo_config = ModuloChip::configure(
+            meta,
+            advice.clone().try_into().unwrap(),
+            instance,
+            constant,
+            digit_sum_config.digit_table,
+        );
+        let digital_root_config =
+            DigitalRootChip::configure(meta, advice.try_into().unwrap(), instance);
+
+        (digit_sum_config, modulo_config, digital_root_config)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let (digit_sum_config, modulo_config, digital_root_config) = config;
+        let digit_sum_chip = DigitSumChip::<F>::construct(digit_sum_config);
+        let modulo_chip = ModuloChip::construct(modulo_config);
+        let chip = DigitalRootChip::construct(digital_root_config);
+
+        digit_sum_chip.load_table(layouter.namespace(|| "digit range table"))?;
+
+        let (digits, mut sum) = digit_sum_chip
+            .load_private_digits(layouter.namespace(|| "private number"), self.number)?;
+        let digits = digits.map(|digit| digit.cell().clone());
+        let number = modulo_chip.recompose(layouter.namespace(|| "recompose number"), &digits)?;
+        let mut multi_digits = vec![chip.is_multi_digit(
+            layouter.namespace(|| "multi digit number"),
+            number.cell(),
+            &digits[NUMBER_LENGTH - 1],
+        )?];
+
+        for (i, intermediate_sum) in self.intermediate_sums.iter().enumerate() {
+            let (digits, next_sum) = digit_sum_chip.load_private_digits(
+                layouter.namespace(|| format!("intermediate sum {i}")),
+                *intermediate_sum,
+            )?;
+            let digits = digits.map(|digit| digit.cell().clone());
+            let recomposed_sum = modulo_chip.recompose(
+                layouter.namespace(|| format!("recompose intermediate sum {i}")),
+                &digits,
+            )?;
+            chip.constrain_equal(
+                layouter.namespace(|| format!("decompose intermediate sum {i}")),
+                recomposed_sum.cell(),
+                sum.cell(),
+            )?;
+            multi_digits.push(chip.is_multi_digit(
+                layouter.namespace(|| format!("multi digit intermediate sum {i}")),
+                recomposed_sum.cell(),
+                &digits[NUMBER_LENGTH - 1],
+            )?);
+            sum = next_sum;
+        }
+
+        digit_sum_chip.expose_public(layouter.namespace(|| "expose digital root"), sum, 0)?;
+
+        if self.persistence {
+            let persistence = chip.persistence(
+                layouter.namespace(|| "persistence"),
+                multi_digits.try_into().unwrap(), // safe unwrap
+            )?;
+            chip.expose_public(layouter.namespace(|| "expose persistence"), persistence, 1)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<F: PrimeField> CircuitSizeParameter for DigitalRootCircuit<F> {
+    fn size_parameter(&self) -> u32 {
+        self.k
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use halo2_proofs::{dev::MockProver, pasta::Fp};
+
+    use super::*;
+
+    fn run_digital_root_circuit(
+        circuit: DigitalRootCircuit<Fp>,
+        public_input: Vec<u64>,
+    ) -> MockProver<Fp> {
+        let public_input = public_input.into_iter().map(Fp::from).collect();
+        MockProver::run(circuit.k, &circuit, vec![public_input]).unwrap()
+    }
+
+    #[test]
+    fn digital_root_circuit_proof_succeeds_with_valid_public_input() {
+        for (secret_witness_number, digital_root, persistence) in [
+            (0, 0, 0),
+            (7, 7, 0),
+            (10, 1, 1),
+            (99, 9, 2),
+            (12345678, 9, 2),
+            (99999999, 9, 2),
+            (19999999, 1, 3),
+        ] {
+            let circuit = DigitalRootCircuit::<Fp>::new(secret_witness_number, true).unwrap();
+            let prover = run_digital_root_circuit(circuit, vec![digital_root, persistence]);
+
+            prover.verify().expect("the proof should be valid");
+        }
+    }
+
+    #[test]
+    fn digital_root_circuit_proof_fails_with_invalid_public_input() {
+        for (secret_witness_number, digital_root, persistence) in [
+            (12345678, 36, 1),
+            (12345678, 9, 1),
+            (19999999, 1, 2),
+            (7, 7, 1),
+        ] {
+            let circuit = DigitalRootCircuit::<Fp>::new(secret_witness_number, true).unwrap();
+            let prover = run_digital_root_circuit(circuit, vec![digital_root, persistence]);
+
+            prover.verify().expect_err("the proof should be invalid");
+        }
+    }
+
+    #[test]
+    fn digital_root_circuit_hides_persistence() {
+        let circuit = DigitalRootCircuit::<Fp>::new(12345678, false).unwrap();
+        let prover = run_digital_root_circuit(circuit, vec![9]);
+
+        prover.verify().expect("the proof should be valid");
+    }
+
+    #[test]
+    fn forged_intermediate_sum_is_rejected() {
+        let mut circuit = DigitalRootCircuit::<Fp>::new(12345678, false).unwrap();
+        circuit.intermediate_sums[0] = DigitSumSecretWitness::new(35_u64).try_into().unwrap();
+        let prover = run_digital_root_circuit(circuit, vec![8]);
+
+        prover
+            .verify()
+            .expect_err("an intermediate sum which is not the digit sum should be rejected");
+    }
+}
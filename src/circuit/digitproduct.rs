@@ -0,0 +1,218 @@
+use anyhow::anyhow;
+use halo2_proofs::{
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    pasta::group::ff::PrimeField,
+    plonk::{Circuit, ConstraintSystem, Error},
+};
+
+use crate::{
+    CircuitSizeParameter, DigitProductChip, DigitProductConfig, DigitSumInstructions,
+    DigitSumSecretWitness, StdResult, NUMBER_LENGTH,
+};
+
+/// The size parameter of the circuit: the circuit must fit into 2^k rows.
+const DIGIT_PRODUCT_CIRCUIT_SIZE_PARAMETER: u32 = 5;
+
+/// The circuit implementation for digit product
+///
+/// The product is computed over the public count of digits of the number, the digits before them being constrained
+/// to be zero, so that the leading zeros of a number with fewer digits than `NUMBER_LENGTH` are not factors.
+pub struct DigitProductCircuit<F: PrimeField> {
+    /// The number with which to compute the digit product in decimal representation
+    pub number: [Value<F>; NUMBER_LENGTH],
+
+    /// The number of digits whose product is computed (the other digits are zero)
+    pub digit_count: usize,
+
+    /// The size parameter of the circuit: the circuit must fit into 2^k rows.
+    pub k: u32,
+}
+
+impl<F: PrimeField> Default for DigitProductCircuit<F> {
+    fn default() -> Self {
+        Self::new_without_witness(NUMBER_LENGTH).unwrap() // safe unwrap
+    }
+}
+
+impl<F: PrimeField> DigitProductCircuit<F> {
+    /// Creates a new digit product circuit
+    pub fn new(number: u64, digit_count: usize) -> StdResult<Self> {
+        if number.checked_ilog10().unwrap_or_default() as usize >= digit_count {
+            return Err(anyhow!(
+                "The witness {number} has more than {digit_count} digits"
+            ));
+        }
+        let secret_witness_number = DigitSumSecretWitness::<F>::new(number);
+        let number: [Value<F>; NUMBER_LENGTH] = secret_witness_number.try_into()?;
+
+        Ok(Self {
+            number,
+            ..Self::new_without_witness(digit_count)?
+        })
+    }
+
+    /// Creates a new digit product circuit without witness
+    pub fn new_without_witness(digit_count: usize) -> StdResult<Self> {
+        let k = DIGIT_PRODUCT_CIRCUIT_SIZE_PARAMETER;
+        if !(1..=NUMBER_LENGTH).contains(&digit_count) {
+            return Err(anyhow!(
+                "Invalid digit count {digit_count}, expected a value between 1 and {NUMBER_LENGTH}"
+            ));
+        }
+
+        Ok(Self {
+            number: [Value::unknown(); NUMBER_LENGTH],
+            digit_count,
+            k,
+        })
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for DigitProductCircuit<F> {
+    type Config = DigitProductConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::new_without_witness(self.digit_count).unwrap() // safe unwrap
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let advice = (0..3).map(|_| meta.advice_column()).collect::<Vec<_>>();
+        let instance = meta.instance_column();
+        let constant = meta.fixed_column();
+        let digit_table = meta.lookup_table_column();
+        DigitProductChip::configure(
+            meta,
+            advice.try_into().unwrap(),
+            instance,
+            constant,
+            digit_table,
+        )
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let chip = DigitProductChip::construct(config);
+
+        chip.load_table(layouter.namespace(|| "digit range table"))?;
+
+        let digits = chip.load_digits_with_leading_zeros(
+            layouter.namespace(|| "private number"),
+            &self.number,
+            NUMBER_LENGTH - self.digit_count,
+        )?;
+
+        chip.expose_public(
+            layouter.namespace(|| "expose digit product"),
+            digits.sum().clone(),
+            0,
+        )
+    }
+}
+
+impl<F: PrimeField> CircuitSizeParameter for DigitProductCircuit<F> {
+    fn size_parameter(&self) -> u32 {
+        self.k
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use halo2_proofs::{dev::MockProver, pasta::Fp};
+
+    use super::*;
+
+    fn run_digit_product_circuit(circuit: DigitProductCircuit<Fp>, product: u64) -> MockProver<Fp> {
+        MockProver::run(circuit.k, &circuit, vec![vec![Fp::from(product)]]).unwrap()
+    }
+
+    #[test]
+    fn digit_product_circuit_proof_succeeds_with_valid_public_input() {
+        for (secret_witness_number, public_input_digit_product) in [
+            (12345678, 40320),
+            (99999999, 43046721),
+            (11111123, 6),
+            (1020, 0),
+            (10000000, 0),
+            (1234, 0),
+            (0, 0),
+            (7, 0),
+        ] {
+            let circuit =
+                DigitProductCircuit::<Fp>::new(secret_witness_number, NUMBER_LENGTH).unwrap();
+            let prover = run_digit_product_circuit(circuit, public_input_digit_product);
+
+            prover.verify().expect("the proof should be valid");
+        }
+    }
+
+    #[test]
+    fn digit_product_circuit_proof_fails_with_invalid_public_input() {
+        for (secret_witness_number, public_input_digit_product) in [
+            (12345678, 40321),
+            (11111123, 0),
+            (1020, 2),
+            (1234, 24),
+            (0, 1),
+        ] {
+            let circuit =
+                DigitProductCircuit::<Fp>::new(secret_witness_number, NUMBER_LENGTH).unwrap();
+            let prover = run_digit_product_circuit(circuit, public_input_digit_product);
+
+            prover.verify().expect_err("the proof should be invalid");
+        }
+    }
+
+    #[test]
+    fn digit_product_circuit_skips_the_leading_zeros_of_a_shorter_number() {
+        for (secret_witness_number, digit_count, public_input_digit_product) in [
+            (1234, 4, 24),
+            (1234, 5, 0),
+            (7, 1, 7),
+            (0, 1, 0),
+            (1020, 4, 0),
+        ] {
+            let circuit =
+                DigitProductCircuit::<Fp>::new(secret_witness_number, digit_count).unwrap();
+            let prover = run_digit_product_circuit(circuit, public_input_digit_product);
+
+            prover.verify().expect("the proof should be valid");
+        }
+
+        let circuit = DigitProductCircuit::<Fp>::new(1234, 4).unwrap();
+        let prover = run_digit_product_circuit(circuit, 0);
+        prover.verify().expect_err("the proof should be invalid");
+    }
+
+    #[test]
+    fn digit_product_circuit_rejects_a_non_zero_leading_digit() {
+        let mut circuit = DigitProductCircuit::<Fp>::new(1234, 4).unwrap();
+        circuit.number[3] = Value::known(Fp::from(1));
+        let prover = run_digit_product_circuit(circuit, 24);
+
+        prover
+            .verify()
+            .expect_err("the digits before the digit count should be constrained to zero");
+    }
+
+    #[test]
+    fn digit_product_circuit_rejects_invalid_digit_count() {
+        assert!(DigitProductCircuit::<Fp>::new(1234, 0).is_err());
+        assert!(DigitProductCircuit::<Fp>::new(1234, 3).is_err());
+        assert!(DigitProductCircuit::<Fp>::new(1234, NUMBER_LENGTH + 1).is_err());
+    }
+
+    #[test]
+    fn out_of_range_digit_is_rejected() {
+        let mut circuit = DigitProductCircuit::<Fp>::new(11111111, NUMBER_LENGTH).unwrap();
+        circuit.number[0] = Value::known(Fp::from(10));
+        let prover = run_digit_product_circuit(circuit, 10);
+
+        prover
+            .verify()
+            .expect_err("out-of-range digit values should be rejected by the range check lookup");
+    }
+}
@@ -0,0 +1,149 @@
+use halo2_proofs::{
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    pasta::group::ff::PrimeField,
+    plonk::{Circuit, ConstraintSystem, Error},
+};
+
+use crate::{
+    CircuitSizeParameter, DigitSumChip, DigitSumConfig, DigitSumInstructions,
+    DigitSumSecretWitness, StdResult, NUMBER_LENGTH,
+};
+
+/// The size parameter of the circuit: the circuit must fit into 2^k rows.
+const EQUAL_DIGIT_SUM_CIRCUIT_SIZE_PARAMETER: u32 = 5;
+
+/// The circuit implementation for two numbers with equal digit sums
+///
+/// The digit sums of the two numbers are computed in two regions of the digit sum chip, and their output cells
+/// are tied together with a copy constraint. The shared digit sum is optionally exposed as the single public input
+/// of the circuit.
+pub struct EqualDigitSumCircuit<F: PrimeField> {
+    /// The first secret number in decimal representation
+    pub number: [Value<F>; NUMBER_LENGTH],
+
+    /// The second secret number in decimal representation
+    pub other_number: [Value<F>; NUMBER_LENGTH],
+
+    /// Whether the shared digit sum is exposed as a public input
+    pub digit_sum: bool,
+
+    /// The size parameter of the circuit: the circuit must fit into 2^k rows.
+    pub k: u32,
+}
+
+impl<F: PrimeField> Default for EqualDigitSumCircuit<F> {
+    fn default() -> Self {
+        Self::new_without_witness(false)
+    }
+}
+
+impl<F: PrimeField> EqualDigitSumCircuit<F> {
+    /// Creates a new equal digit sum circuit
+    pub fn new(number: u64, other_number: u64, digit_sum: bool) -> StdResult<Self> {
+        Ok(Self {
+            number: DigitSumSecretWitness::<F>::new(number).try_into()?,
+            other_number: DigitSumSecretWitness::<F>::new(other_number).try_into()?,
+            ..Self::new_without_witness(digit_sum)
+        })
+    }
+
+    /// Creates a new equal digit sum circuit without witness
+    pub fn new_without_witness(digit_sum: bool) -> Self {
+        Self {
+            number: [Value::unknown(); NUMBER_LENGTH],
+            other_number: [Value::unknown(); NUMBER_LENGTH],
+            digit_sum,
+            k: EQUAL_DIGIT_SUM_CIRCUIT_SIZE_PARAMETER,
+        }
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for EqualDigitSumCircuit<F> {
+    type Config = DigitSumConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::new_without_witness(self.digit_sum)
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let advice = (0..3).map(|_| meta.advice_column()).collect::<Vec<_>>();
+        let instance = meta.instance_column();
+        DigitSumChip::configure(meta, advice.try_into().unwrap(), instance)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let chip = DigitSumChip::construct(config);
+
+        chip.load_table(layouter.namespace(|| "digit range table"))?;
+
+        let sum = chip.load_private(layouter.namespace(|| "private number"), self.number)?;
+        let other_sum = chip.load_private(
+            layouter.namespace(|| "other private number"),
+            self.other_number,
+        )?;
+        chip.constrain_equal(layouter.namespace(|| "equal digit sums"), &sum, &other_sum)?;
+
+        if self.digit_sum {
+            chip.expose_public(layouter.namespace(|| "expose digit sum"), sum, 0)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<F: PrimeField> CircuitSizeParameter for EqualDigitSumCircuit<F> {
+    fn size_parameter(&self) -> u32 {
+        self.k
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use halo2_proofs::{dev::MockProver, pasta::Fp};
+
+    use super::*;
+
+    fn run_equal_digit_sum_circuit(
+        circuit: EqualDigitSumCircuit<Fp>,
+        public_input: Vec<u64>,
+    ) -> MockProver<Fp> {
+        let public_input = public_input.into_iter().map(Fp::from).collect();
+        MockProver::run(circuit.k, &circuit, vec![public_input]).unwrap()
+    }
+
+    #[test]
+    fn equal_digit_sum_circuit_proof_succeeds_with_equal_digit_sums() {
+        for (number, other_number) in [(123, 321), (19, 91), (12345678, 99990), (0, 0)] {
+            let circuit = EqualDigitSumCircuit::<Fp>::new(number, other_number, false).unwrap();
+            let prover = run_equal_digit_sum_circuit(circuit, vec![]);
+
+            prover.verify().expect("the proof should be valid");
+        }
+    }
+
+    #[test]
+    fn equal_digit_sum_circuit_proof_fails_with_different_digit_sums() {
+        for (number, other_number) in [(123, 124), (19, 0), (12345678, 99999)] {
+            let circuit = EqualDigitSumCircuit::<Fp>::new(number, other_number, false).unwrap();
+            let prover = run_equal_digit_sum_circuit(circuit, vec![]);
+
+            prover.verify().expect_err("the proof should be invalid");
+        }
+    }
+
+    #[test]
+    fn equal_digit_sum_circuit_reveals_digit_sum() {
+        let circuit = EqualDigitSumCircuit::<Fp>::new(1729, 991, true).unwrap();
+        let prover = run_equal_digit_sum_circuit(circuit, vec![19]);
+        prover.verify().expect("the proof should be valid");
+
+        let circuit = EqualDigitSumCircuit::<Fp>::new(1729, 991, true).unwrap();
+        let prover = run_equal_digit_sum_circuit(circuit, vec![20]);
+        prover.verify().expect_err("the proof should be invalid");
+    }
+}
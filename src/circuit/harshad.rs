@@ -0,0 +1,177 @@
+use halo2_proofs::{
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    pasta::group::ff::PrimeField,
+    plonk::{Circuit, ConstraintSystem, Error},
+};
+
+use crate::{
+    CircuitSizeParameter, DigitSumChip, DigitSumConfig, DigitSumInstructions,
+    DigitSumSecretWitness, ModuloChip, ModuloConfig, ModuloInstructions, StdResult, NUMBER_LENGTH,
+};
+
+/// The size parameter of the circuit: the circuit must fit into 2^k rows.
+const HARSHAD_CIRCUIT_SIZE_PARAMETER: u32 = 6;
+
+/// The circuit implementation for Harshad numbers (i.e. numbers divisible by their digit sum)
+///
+/// The digit sum computed by the digit sum chip is the modulus of the modulo chip, with a zero residue.
+/// The digit sum is optionally exposed as the single public input of the circuit.
+pub struct HarshadCircuit<F: PrimeField> {
+    /// The secret number in decimal representation
+    pub number: [Value<F>; NUMBER_LENGTH],
+
+    /// The quotient of the secret number by its digit sum in decimal representation
+    pub quotient: [Value<F>; NUMBER_LENGTH],
+
+    /// The difference `digit_sum - 1` in decimal representation
+    pub difference: [Value<F>; NUMBER_LENGTH],
+
+    /// Whether the digit sum is exposed as a public input
+    pub digit_sum: bool,
+
+    /// The size parameter of the circuit: the circuit must fit into 2^k rows.
+    pub k: u32,
+}
+
+impl<F: PrimeField> Default for HarshadCircuit<F> {
+    fn default() -> Self {
+        Self::new_without_witness(false)
+    }
+}
+
+impl<F: PrimeField> HarshadCircuit<F> {
+    /// Creates a new Harshad circuit
+    pub fn new(number: u64, digit_sum: bool) -> StdResult<Self> {
+        let digits: [u64; NUMBER_LENGTH] = DigitSumSecretWitness::<F>::new(number).try_into()?;
+        let sum = digits.iter().sum::<u64>();
+        let quotient = number.checked_div(sum).unwrap_or_default();
+        let difference = sum.saturating_sub(1);
+
+        Ok(Self {
+            number: DigitSumSecretWitness::<F>::new(number).try_into()?,
+            quotient: DigitSumSecretWitness::<F>::new(quotient).try_into()?,
+            difference: DigitSumSecretWitness::<F>::new(difference).try_into()?,
+            ..Self::new_without_witness(digit_sum)
+        })
+    }
+
+    /// Creates a new Harshad circuit without witness
+    pub fn new_without_witness(digit_sum: bool) -> Self {
+        Self {
+            number: [Value::unknown(); NUMBER_LENGTH],
+            quotient: [Value::unknown(); NUMBER_LENGTH],
+            difference: [Value::unknown(); NUMBER_LENGTH],
+            digit_sum,
+            k: HARSHAD_CIRCUIT_SIZE_PARAMETER,
+        }
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for HarshadCircuit<F> {
+    type Config = (DigitSumConfig, ModuloConfig);
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::new_without_witness(self.digit_sum)
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let advice = (0..4).map(|_| meta.advice_column()).collect::<Vec<_>>();
+        let instance = meta.instance_column();
+        let constant = meta.fixed_column();
+        let digit_sum_config =
+            DigitSumChip::configure(meta, advice[..3].try_into().unwrap(), instance);
+        let modulo_config = ModuloChip::configure(
+            meta,
+            advice.try_into().unwrap(),
+            instance,
+            constant,
+            digit_sum_config.digit_table,
+        );
+
+        (digit_sum_config, modulo_config)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let (digit_sum_config, modulo_config) = config;
+        let digit_sum_chip = DigitSumChip::<F>::construct(digit_sum_config);
+        let chip = ModuloChip::construct(modulo_config);
+
+        digit_sum_chip.load_table(layouter.namespace(|| "digit range table"))?;
+
+        let (digits, sum) = digit_sum_chip
+            .load_private_digits(layouter.namespace(|| "private number"), self.number)?;
+        let modulus = sum.cell().clone().into();
+        if self.digit_sum {
+            digit_sum_chip.expose_public(layouter.namespace(|| "expose digit sum"), sum, 0)?;
+        }
+
+        let digits = digits.map(|digit| digit.cell().clone());
+        let number = chip.recompose(layouter.namespace(|| "recompose number"), &digits)?;
+        let quotient = chip.load_private(layouter.namespace(|| "quotient"), self.quotient)?;
+        let difference = chip.load_private(layouter.namespace(|| "difference"), self.difference)?;
+        let residue = chip.load_constant(layouter.namespace(|| "residue"), F::ZERO)?;
+
+        chip.constrain_modulo(
+            layouter.namespace(|| "harshad"),
+            number,
+            quotient,
+            modulus,
+            residue,
+            difference,
+        )
+    }
+}
+
+impl<F: PrimeField> CircuitSizeParameter for HarshadCircuit<F> {
+    fn size_parameter(&self) -> u32 {
+        self.k
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use halo2_proofs::{dev::MockProver, pasta::Fp};
+
+    use super::*;
+
+    fn run_harshad_circuit(circuit: HarshadCircuit<Fp>, public_input: Vec<u64>) -> MockProver<Fp> {
+        let public_input = public_input.into_iter().map(Fp::from).collect();
+        MockProver::run(circuit.k, &circuit, vec![public_input]).unwrap()
+    }
+
+    #[test]
+    fn harshad_circuit_proof_succeeds_with_harshad_number() {
+        for secret_witness_number in [18, 21, 1729, 11111112, 10000000, 7] {
+            let circuit = HarshadCircuit::<Fp>::new(secret_witness_number, false).unwrap();
+            let prover = run_harshad_circuit(circuit, vec![]);
+
+            prover.verify().expect("the proof should be valid");
+        }
+    }
+
+    #[test]
+    fn harshad_circuit_proof_fails_with_non_harshad_number() {
+        for secret_witness_number in [19, 11, 12345678, 0] {
+            let circuit = HarshadCircuit::<Fp>::new(secret_witness_number, false).unwrap();
+            let prover = run_harshad_circuit(circuit, vec![]);
+
+            prover.verify().expect_err("the proof should be invalid");
+        }
+    }
+
+    #[test]
+    fn harshad_circuit_reveals_digit_sum() {
+        let circuit = HarshadCircuit::<Fp>::new(1729, true).unwrap();
+        let prover = run_harshad_circuit(circuit, vec![19]);
+        prover.verify().expect("the proof should be valid");
+
+        let circuit = HarshadCircuit::<Fp>::new(1729, true).unwrap();
+        let prover = run_harshad_circuit(circuit, vec![7]);
+        prover.verify().expect_err("the proof should be invalid");
+    }
+}
@@ -0,0 +1,223 @@
+use anyhow::anyhow;
+use halo2_proofs::{
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    pasta::group::ff::PrimeField,
+    plonk::{Circuit, ConstraintSystem, Error},
+};
+
+use crate::{
+    load_digit_table, CircuitSizeParameter, Kakuro, KakuroChip, KakuroConfig, KakuroInstructions,
+    KakuroRun, StdResult,
+};
+
+use super::size_parameter_for_rows;
+
+/// The circuit implementation for kakuro solutions
+///
+/// The layout of the puzzle is part of the circuit, and the public inputs are the clues of its runs.
+pub struct KakuroCircuit<F: PrimeField> {
+    /// The runs of the puzzle
+    pub runs: Vec<KakuroRun>,
+
+    /// The secret white cells of the solution, row by row
+    pub cells: Vec<Value<F>>,
+
+    /// The size parameter of the circuit: the circuit must fit into 2^k rows.
+    pub k: u32,
+}
+
+impl<F: PrimeField> KakuroCircuit<F> {
+    /// Creates a new kakuro circuit
+    pub fn new(puzzle: &Kakuro, solution: &Kakuro) -> StdResult<Self> {
+        if puzzle.runs() != solution.runs() {
+            return Err(anyhow!(
+                "The solution does not have the layout of the puzzle"
+            ));
+        }
+
+        Ok(Self {
+            cells: solution
+                .cells()
+                .iter()
+                .map(|cell| Value::known(F::from(*cell)))
+                .collect(),
+            ..Self::new_without_witness(puzzle)
+        })
+    }
+
+    /// Creates a new kakuro circuit without witness
+    pub fn new_without_witness(puzzle: &Kakuro) -> Self {
+        let runs = puzzle.runs().to_vec();
+        let cells = puzzle.cells().len();
+        // The digit range table has 10 rows, and each run uses one row per cell for its sum and one row per
+        // pair of cells for its distinct cells
+        let table_rows = 10;
+        let run_rows = runs
+            .iter()
+            .map(|run| run.cells.len() * (run.cells.len() + 1) / 2)
+            .sum::<usize>();
+
+        Self {
+            runs,
+            cells: vec![Value::unknown(); cells],
+            k: size_parameter_for_rows(table_rows.max(cells + run_rows)),
+        }
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for KakuroCircuit<F> {
+    type Config = KakuroConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            runs: self.runs.clone(),
+            cells: vec![Value::unknown(); self.cells.len()],
+            k: self.k,
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let advice = (0..3).map(|_| meta.advice_column()).collect::<Vec<_>>();
+        let instance = meta.instance_column();
+        let constant = meta.fixed_column();
+        let digit_table = meta.lookup_table_column();
+
+        KakuroChip::configure(
+            meta,
+            advice.try_into().unwrap(),
+            instance,
+            constant,
+            digit_table,
+        )
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let digit_table = config.digit_table;
+        let chip = KakuroChip::construct(config);
+
+        load_digit_table(layouter.namespace(|| "digit range table"), digit_table)?;
+
+        let cells = chip.load_cells(layouter.namespace(|| "cells"), &self.cells)?;
+        for (i, run) in self.runs.iter().enumerate() {
+            let run_cells = run
+                .cells
+                .iter()
+                .map(|cell| &cells[*cell])
+                .collect::<Vec<_>>();
+            let sum = chip.sum_run(layouter.namespace(|| format!("run {i} sum")), &run_cells)?;
+            chip.constrain_distinct(
+                layouter.namespace(|| format!("run {i} distinct cells")),
+                &run_cells,
+            )?;
+            chip.expose_public(layouter.namespace(|| format!("run {i} clue")), sum, i)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<F: PrimeField> CircuitSizeParameter for KakuroCircuit<F> {
+    fn size_parameter(&self) -> u32 {
+        self.k
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use halo2_proofs::{dev::MockProver, pasta::Fp};
+
+    use crate::KakuroPublicInput;
+
+    use super::*;
+
+    const PUZZLE: &str = r#"
+        X     4\    19\   X     X
+        \3    .     .     X     X
+        \11   .     .     8\    11\
+        X     \24   .     .     .
+        X     X     \4    .     .
+    "#;
+
+    const SOLUTION: &str = r#"
+        X     4\    19\   X     X
+        \3    1     2     X     X
+        \11   3     8     8\    11\
+        X     \24   9     7     8
+        X     X     \4    1     3
+    "#;
+
+    fn run_kakuro_circuit(circuit: KakuroCircuit<Fp>, puzzle: &Kakuro) -> MockProver<Fp> {
+        let public_input = KakuroPublicInput::new(puzzle.clone()).into();
+        MockProver::run(circuit.k, &circuit, vec![public_input]).unwrap()
+    }
+
+    #[test]
+    fn kakuro_circuit_proof_succeeds_with_solution() {
+        let puzzle: Kakuro = PUZZLE.parse().unwrap();
+        let solution: Kakuro = SOLUTION.parse().unwrap();
+        let circuit = KakuroCircuit::<Fp>::new(&puzzle, &solution).unwrap();
+        let prover = run_kakuro_circuit(circuit, &puzzle);
+
+        prover.verify().expect("the proof should be valid");
+    }
+
+    #[test]
+    fn kakuro_circuit_proof_fails_with_wrong_clue() {
+        let puzzle: Kakuro = PUZZLE.parse().unwrap();
+        let solution: Kakuro = SOLUTION.parse().unwrap();
+        let circuit = KakuroCircuit::<Fp>::new(&puzzle, &solution).unwrap();
+        let other_puzzle: Kakuro = PUZZLE.replace("\\24", "\\23").parse().unwrap();
+        let prover = run_kakuro_circuit(circuit, &other_puzzle);
+
+        prover.verify().expect_err("the proof should be invalid");
+    }
+
+    #[test]
+    fn kakuro_circuit_proof_fails_with_repeated_digits() {
+        let puzzle: Kakuro = PUZZLE.parse().unwrap();
+        // All the clues are satisfied, but some runs have repeated digits
+        let solution: Kakuro = SOLUTION
+            .replace("1     2", "2     1")
+            .replace("3     8", "2     9")
+            .parse()
+            .unwrap();
+        let circuit = KakuroCircuit::<Fp>::new(&puzzle, &solution).unwrap();
+        let prover = run_kakuro_circuit(circuit, &puzzle);
+
+        prover.verify().expect_err("the proof should be invalid");
+    }
+
+    #[test]
+    fn out_of_range_cell_is_rejected() {
+        let puzzle: Kakuro = PUZZLE.parse().unwrap();
+        let solution: Kakuro = SOLUTION
+            .replace("3     8", "4     7")
+            .replace("1     2", "1     3")
+            .parse()
+            .unwrap();
+        let mut circuit = KakuroCircuit::<Fp>::new(&puzzle, &solution).unwrap();
+        // All the clues are satisfied with distinct cells, but the first cell is not a digit from 1 to 9
+        circuit.cells[0] = Value::known(Fp::from(0));
+        let prover = run_kakuro_circuit(circuit, &puzzle);
+
+        prover
+            .verify()
+            .expect_err("out-of-range cells should be rejected by the lookup");
+    }
+
+    #[test]
+    fn kakuro_circuit_rejects_solution_with_another_layout() {
+        let puzzle: Kakuro = PUZZLE.parse().unwrap();
+        let solution: Kakuro = SOLUTION.replace("\\4 ", "X  ").parse().unwrap();
+
+        assert!(
+            KakuroCircuit::<Fp>::new(&puzzle, &solution).is_err(),
+            "the solution should have the layout of the puzzle"
+        );
+    }
+}
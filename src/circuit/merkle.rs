@@ -0,0 +1,183 @@
+use halo2_proofs::{
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    pasta::{group::ff::PrimeField, Fp},
+    plonk::{Circuit, ConstraintSystem, Error},
+};
+
+use crate::{
+    CircuitSizeParameter, DigitSumChip, DigitSumConfig, DigitSumInstructions,
+    DigitSumSecretWitness, MerkleChip, MerkleConfig, MerkleInstructions, MerklePath, ModuloChip,
+    ModuloConfig, ModuloInstructions, StdResult, MERKLE_DEPTH, NUMBER_LENGTH,
+};
+
+/// The size parameter of the circuit: the circuit must fit into 2^k rows.
+const MERKLE_CIRCUIT_SIZE_PARAMETER: u32 = 10;
+
+/// The circuit implementation for digit sum with a Merkle membership proof
+///
+/// The leaf of the Merkle tree is the secret number (recomposed from its digits), which proves that the secret
+/// number belongs to the published allow-list whose Merkle root is known, without revealing which one it is.
+/// The public inputs are the digit sum and the Merkle root.
+pub struct MerkleCircuit<F: PrimeField> {
+    /// The secret number in decimal representation
+    pub number: [Value<F>; NUMBER_LENGTH],
+
+    /// The secret siblings of the authentication path of the number, from the leaf to the root
+    pub siblings: [Value<F>; MERKLE_DEPTH],
+
+    /// The secret position bits of the authentication path of the number, from the leaf to the root
+    pub position_bits: [Value<F>; MERKLE_DEPTH],
+
+    /// The size parameter of the circuit: the circuit must fit into 2^k rows.
+    pub k: u32,
+}
+
+impl<F: PrimeField> Default for MerkleCircuit<F> {
+    fn default() -> Self {
+        Self {
+            number: [Value::unknown(); NUMBER_LENGTH],
+            siblings: [Value::unknown(); MERKLE_DEPTH],
+            position_bits: [Value::unknown(); MERKLE_DEPTH],
+            k: MERKLE_CIRCUIT_SIZE_PARAMETER,
+        }
+    }
+}
+
+impl MerkleCircuit<Fp> {
+    /// Creates a new Merkle membership circuit from a number and its authentication path
+    pub fn new(number: u64, path: &MerklePath) -> StdResult<Self> {
+        Ok(Self {
+            number: DigitSumSecretWitness::<Fp>::new(number).try_into()?,
+            siblings: path.siblings.map(Value::known),
+            position_bits: path
+                .position_bits()
+                .map(|is_right| Value::known(Fp::from(is_right as u64))),
+            ..Self::default()
+        })
+    }
+}
+
+impl Circuit<Fp> for MerkleCircuit<Fp> {
+    type Config = (DigitSumConfig, ModuloConfig, MerkleConfig);
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<Fp>) -> Self::Config {
+        let advice = (0..4).map(|_| meta.advice_column()).collect::<Vec<_>>();
+        let instance = meta.instance_column();
+        let constant = meta.fixed_column();
+        let digit_sum_config =
+            DigitSumChip::configure(meta, advice[..3].try_into().unwrap(), instance);
+        let modulo_config = ModuloChip::configure(
+            meta,
+            advice.clone().try_into().unwrap(),
+            instance,
+            constant,
+            digit_sum_config.digit_table,
+        );
+        let merkle_config =
+            MerkleChip::configure(meta, advice.try_into().unwrap(), instance, constant);
+
+        (digit_sum_config, modulo_config, merkle_config)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<Fp>,
+    ) -> Result<(), Error> {
+        let (digit_sum_config, modulo_config, merkle_config) = config;
+        let digit_sum_chip = DigitSumChip::<Fp>::construct(digit_sum_config);
+        let modulo_chip = ModuloChip::construct(modulo_config);
+        let chip = MerkleChip::construct(merkle_config);
+
+        digit_sum_chip.load_table(layouter.namespace(|| "digit range table"))?;
+
+        let (digits, sum) = digit_sum_chip
+            .load_private_digits(layouter.namespace(|| "private number"), self.number)?;
+        digit_sum_chip.expose_public(layouter.namespace(|| "expose digit sum"), sum, 0)?;
+
+        let digits = digits.map(|digit| digit.cell().clone());
+        let number = modulo_chip.recompose(layouter.namespace(|| "recompose number"), &digits)?;
+        let root = chip.compute_root(
+            layouter.namespace(|| "merkle root"),
+            number.cell(),
+            self.siblings,
+            self.position_bits,
+        )?;
+
+        chip.expose_public(layouter.namespace(|| "expose merkle root"), root, 1)
+    }
+}
+
+impl<F: PrimeField> CircuitSizeParameter for MerkleCircuit<F> {
+    fn size_parameter(&self) -> u32 {
+        self.k
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use halo2_proofs::dev::MockProver;
+
+    use crate::{MerklePublicInput, MerkleTree};
+
+    use super::*;
+
+    fn run_merkle_circuit(
+        circuit: MerkleCircuit<Fp>,
+        public_input: MerklePublicInput,
+    ) -> MockProver<Fp> {
+        MockProver::run(circuit.k, &circuit, vec![public_input.into()]).unwrap()
+    }
+
+    #[test]
+    fn merkle_circuit_proof_succeeds_with_number_in_allow_list() {
+        let tree = MerkleTree::build(&[11111111, 12345678, 42]).unwrap();
+        for (number, digit_sum) in [(12345678, 36), (42, 6)] {
+            let path = tree.path(number).unwrap();
+            let circuit = MerkleCircuit::new(number, &path).unwrap();
+            let public_input = MerklePublicInput::new(digit_sum, tree.root());
+            let prover = run_merkle_circuit(circuit, public_input);
+
+            prover.verify().expect("the proof should be valid");
+        }
+    }
+
+    #[test]
+    fn merkle_circuit_proof_fails_with_number_not_in_allow_list() {
+        let tree = MerkleTree::build(&[11111111, 12345678, 42]).unwrap();
+        let path = tree.path(12345678).unwrap();
+        let circuit = MerkleCircuit::new(12345687, &path).unwrap();
+        let public_input = MerklePublicInput::new(36, tree.root());
+        let prover = run_merkle_circuit(circuit, public_input);
+
+        prover.verify().expect_err("the proof should be invalid");
+    }
+
+    #[test]
+    fn merkle_circuit_proof_fails_with_another_root() {
+        let tree = MerkleTree::build(&[11111111, 12345678, 42]).unwrap();
+        let other_tree = MerkleTree::build(&[11111111, 12345678]).unwrap();
+        let path = tree.path(12345678).unwrap();
+        let circuit = MerkleCircuit::new(12345678, &path).unwrap();
+        let public_input = MerklePublicInput::new(36, other_tree.root());
+        let prover = run_merkle_circuit(circuit, public_input);
+
+        prover.verify().expect_err("the proof should be invalid");
+    }
+
+    #[test]
+    fn merkle_circuit_proof_fails_with_invalid_digit_sum() {
+        let tree = MerkleTree::build(&[11111111, 12345678, 42]).unwrap();
+        let path = tree.path(12345678).unwrap();
+        let circuit = MerkleCircuit::new(12345678, &path).unwrap();
+        let public_input = MerklePublicInput::new(35, tree.root());
+        let prover = run_merkle_circuit(circuit, public_input);
+
+        prover.verify().expect_err("the proof should be invalid");
+    }
+}
@@ -0,0 +1,259 @@
+use anyhow::anyhow;
+use halo2_proofs::{
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    pasta::group::ff::PrimeField,
+    plonk::{Circuit, ConstraintSystem, Error},
+};
+
+use crate::{
+    CircuitSizeParameter, DigitSumChip, DigitSumConfig, DigitSumInstructions,
+    DigitSumSecretWitness, MinMaxChip, MinMaxConfig, MinMaxInstructions, StdResult, NUMBER_LENGTH,
+};
+
+use super::size_parameter_for_rows;
+
+/// The circuit implementation for the maximum and minimum digits
+///
+/// The digits loaded by the digit sum chip are compared by the min-max chip, over the public count of digits of the
+/// number: the digits before them are constrained to be zero, so that the leading zeros of a number with fewer digits
+/// than `NUMBER_LENGTH` are not compared (e.g. the minimum digit of `999` is `9` with 3 digits, and `0` with 8 digits).
+/// The public inputs are the digit sum, followed by the maximum digit and then the minimum digit if they are exposed.
+pub struct MinMaxDigitCircuit<F: PrimeField> {
+    /// The secret number in decimal representation
+    pub number: [Value<F>; NUMBER_LENGTH],
+
+    /// The number of digits which are compared (the other digits are zero)
+    pub digit_count: usize,
+
+    /// Whether the maximum digit is exposed as a public input
+    pub max_digit: bool,
+
+    /// Whether the minimum digit is exposed as a public input
+    pub min_digit: bool,
+
+    /// The size parameter of the circuit: the circuit must fit into 2^k rows.
+    pub k: u32,
+}
+
+impl<F: PrimeField> Default for MinMaxDigitCircuit<F> {
+    fn default() -> Self {
+        Self::new_without_witness(NUMBER_LENGTH, true, true).unwrap() // safe unwrap
+    }
+}
+
+impl<F: PrimeField> MinMaxDigitCircuit<F> {
+    /// Creates a new min-max digit circuit
+    pub fn new(
+        number: u64,
+        digit_count: usize,
+        max_digit: bool,
+        min_digit: bool,
+    ) -> StdResult<Self> {
+        if number.checked_ilog10().unwrap_or_default() as usize >= digit_count {
+            return Err(anyhow!(
+                "The witness {number} has more than {digit_count} digits"
+            ));
+        }
+
+        Ok(Self {
+            number: DigitSumSecretWitness::<F>::new(number).try_into()?,
+            ..Self::new_without_witness(digit_count, max_digit, min_digit)?
+        })
+    }
+
+    /// Creates a new min-max digit circuit without witness
+    pub fn new_without_witness(
+        digit_count: usize,
+        max_digit: bool,
+        min_digit: bool,
+    ) -> StdResult<Self> {
+        if !(1..=NUMBER_LENGTH).contains(&digit_count) {
+            return Err(anyhow!(
+                "Invalid digit count {digit_count}, expected a value between 1 and {NUMBER_LENGTH}"
+            ));
+        }
+        if !max_digit && !min_digit {
+            return Err(anyhow!(
+                "Expected the maximum or the minimum digit to be exposed"
+            ));
+        }
+        // The digits use one row per digit, and each running comparison one row per compared digit
+        let rows = NUMBER_LENGTH + digit_count * (max_digit as usize + min_digit as usize);
+
+        Ok(Self {
+            number: [Value::unknown(); NUMBER_LENGTH],
+            digit_count,
+            max_digit,
+            min_digit,
+            k: size_parameter_for_rows(rows),
+        })
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for MinMaxDigitCircuit<F> {
+    type Config = (DigitSumConfig, MinMaxConfig);
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            number: [Value::unknown(); NUMBER_LENGTH],
+            digit_count: self.digit_count,
+            max_digit: self.max_digit,
+            min_digit: self.min_digit,
+            k: self.k,
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let advice = (0..3).map(|_| meta.advice_column()).collect::<Vec<_>>();
+        let instance = meta.instance_column();
+        let constant = meta.fixed_column();
+        let digit_sum_config =
+            DigitSumChip::configure(meta, advice.clone().try_into().unwrap(), instance);
+        let min_max_config = MinMaxChip::configure(
+            meta,
+            advice.try_into().unwrap(),
+            instance,
+            constant,
+            digit_sum_config.digit_table,
+        );
+
+        (digit_sum_config, min_max_config)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let (digit_sum_config, min_max_config) = config;
+        let digit_sum_chip = DigitSumChip::<F>::construct(digit_sum_config);
+        let chip = MinMaxChip::construct(min_max_config);
+
+        digit_sum_chip.load_table(layouter.namespace(|| "digit range table"))?;
+
+        let (digits, sum) = digit_sum_chip
+            .load_private_digits(layouter.namespace(|| "private number"), self.number)?;
+        digit_sum_chip.expose_public(layouter.namespace(|| "expose digit sum"), sum, 0)?;
+
+        let digits = digits.map(|digit| digit.cell().clone());
+        let (leading_zeros, digits) = digits.split_at(NUMBER_LENGTH - self.digit_count);
+        chip.constrain_zero(layouter.namespace(|| "leading zeros"), leading_zeros)?;
+
+        let mut row = 1;
+        if self.max_digit {
+            let max_digit = chip.max_digit(layouter.namespace(|| "maximum digit"), digits)?;
+            chip.expose_public(
+                layouter.namespace(|| "expose maximum digit"),
+                max_digit,
+                row,
+            )?;
+            row += 1;
+        }
+        if self.min_digit {
+            let min_digit = chip.min_digit(layouter.namespace(|| "minimum digit"), digits)?;
+            chip.expose_public(
+                layouter.namespace(|| "expose minimum digit"),
+                min_digit,
+                row,
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<F: PrimeField> CircuitSizeParameter for MinMaxDigitCircuit<F> {
+    fn size_parameter(&self) -> u32 {
+        self.k
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use halo2_proofs::{dev::MockProver, pasta::Fp};
+
+    use super::*;
+
+    fn run_min_max_digit_circuit(
+        circuit: MinMaxDigitCircuit<Fp>,
+        public_input: &[u64],
+    ) -> MockProver<Fp> {
+        let public_input = public_input.iter().copied().map(Fp::from).collect();
+        MockProver::run(circuit.k, &circuit, vec![public_input]).unwrap()
+    }
+
+    #[test]
+    fn min_max_digit_circuit_proof_succeeds_with_valid_extrema() {
+        for (max_digit, min_digit, public_input) in [
+            (true, true, vec![29, 7, 1]),
+            (true, false, vec![29, 7]),
+            (false, true, vec![29, 1]),
+        ] {
+            let circuit =
+                MinMaxDigitCircuit::<Fp>::new(31257425, NUMBER_LENGTH, max_digit, min_digit)
+                    .unwrap();
+            let prover = run_min_max_digit_circuit(circuit, &public_input);
+
+            prover.verify().expect("the proof should be valid");
+        }
+    }
+
+    #[test]
+    fn min_max_digit_circuit_proof_succeeds_with_leading_zeros() {
+        let circuit = MinMaxDigitCircuit::<Fp>::new(999, NUMBER_LENGTH, true, true).unwrap();
+        let prover = run_min_max_digit_circuit(circuit, &[27, 9, 0]);
+
+        prover.verify().expect("the proof should be valid");
+    }
+
+    #[test]
+    fn min_max_digit_circuit_skips_the_leading_zeros_of_a_shorter_number() {
+        for (number, digit_count, public_input, valid) in [
+            (999, 3, [27, 9, 9], true),
+            (999, 3, [27, 9, 0], false),
+            (999, 4, [27, 9, 0], true),
+            (1025, 4, [8, 5, 0], true),
+            (7, 1, [7, 7, 7], true),
+        ] {
+            let circuit = MinMaxDigitCircuit::<Fp>::new(number, digit_count, true, true).unwrap();
+            let prover = run_min_max_digit_circuit(circuit, &public_input);
+
+            assert_eq!(valid, prover.verify().is_ok());
+        }
+    }
+
+    #[test]
+    fn min_max_digit_circuit_rejects_a_non_zero_leading_digit() {
+        let mut circuit = MinMaxDigitCircuit::<Fp>::new(999, 3, true, true).unwrap();
+        circuit.number[4] = Value::known(Fp::from(1));
+        let prover = run_min_max_digit_circuit(circuit, &[28, 9, 9]);
+
+        prover
+            .verify()
+            .expect_err("the digits before the digit count should be constrained to zero");
+    }
+
+    #[test]
+    fn min_max_digit_circuit_proof_fails_with_invalid_extrema() {
+        for public_input in [[29, 6, 1], [29, 8, 1], [29, 7, 0], [29, 7, 2], [29, 1, 7]] {
+            let circuit =
+                MinMaxDigitCircuit::<Fp>::new(31257425, NUMBER_LENGTH, true, true).unwrap();
+            let prover = run_min_max_digit_circuit(circuit, &public_input);
+
+            prover.verify().expect_err("the proof should be invalid");
+        }
+    }
+
+    #[test]
+    fn min_max_digit_circuit_requires_an_exposed_extremum() {
+        assert!(MinMaxDigitCircuit::<Fp>::new(31257425, NUMBER_LENGTH, false, false).is_err());
+    }
+
+    #[test]
+    fn min_max_digit_circuit_rejects_invalid_digit_count() {
+        assert!(MinMaxDigitCircuit::<Fp>::new(999, 0, true, true).is_err());
+        assert!(MinMaxDigitCircuit::<Fp>::new(999, 2, true, true).is_err());
+        assert!(MinMaxDigitCircuit::<Fp>::new(999, NUMBER_LENGTH + 1, true, true).is_err());
+    }
+}
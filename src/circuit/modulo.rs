@@ -0,0 +1,198 @@
+use anyhow::anyhow;
+use halo2_proofs::{
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    pasta::group::ff::PrimeField,
+    plonk::{Circuit, ConstraintSystem, Error},
+};
+
+use crate::{
+    CircuitSizeParameter, DigitSumChip, DigitSumConfig, DigitSumInstructions,
+    DigitSumSecretWitness, ModuloChip, ModuloConfig, ModuloInstructions, StdResult,
+    MODULUS_UPPER_BOUND, NUMBER_LENGTH,
+};
+
+/// The size parameter of the circuit: the circuit must fit into 2^k rows.
+const MODULO_CIRCUIT_SIZE_PARAMETER: u32 = 6;
+
+/// The circuit implementation for digit sum and modulo
+///
+/// The public inputs are the digit sum, the modulus and the residue of the secret number.
+pub struct ModuloCircuit<F: PrimeField> {
+    /// The secret number in decimal representation
+    pub number: [Value<F>; NUMBER_LENGTH],
+
+    /// The quotient of the secret number by the modulus in decimal representation
+    pub quotient: [Value<F>; NUMBER_LENGTH],
+
+    /// The difference `modulus - 1 - residue` in decimal representation
+    pub difference: [Value<F>; NUMBER_LENGTH],
+
+    /// The size parameter of the circuit: the circuit must fit into 2^k rows.
+    pub k: u32,
+}
+
+impl<F: PrimeField> Default for ModuloCircuit<F> {
+    fn default() -> Self {
+        Self {
+            number: [Value::unknown(); NUMBER_LENGTH],
+            quotient: [Value::unknown(); NUMBER_LENGTH],
+            difference: [Value::unknown(); NUMBER_LENGTH],
+            k: MODULO_CIRCUIT_SIZE_PARAMETER,
+        }
+    }
+}
+
+impl<F: PrimeField> ModuloCircuit<F> {
+    /// Creates a new modulo circuit
+    pub fn new(number: u64, modulus: u64) -> StdResult<Self> {
+        let k = MODULO_CIRCUIT_SIZE_PARAMETER;
+        if !(1..=MODULUS_UPPER_BOUND).contains(&modulus) {
+            return Err(anyhow!(
+                "Invalid modulus {modulus}, expected a value between 1 and {MODULUS_UPPER_BOUND}"
+            ));
+        }
+        let (quotient, residue) = (number / modulus, number % modulus);
+        let difference = modulus - 1 - residue;
+
+        Ok(Self {
+            number: DigitSumSecretWitness::<F>::new(number).try_into()?,
+            quotient: DigitSumSecretWitness::<F>::new(quotient).try_into()?,
+            difference: DigitSumSecretWitness::<F>::new(difference).try_into()?,
+            k,
+        })
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for ModuloCircuit<F> {
+    type Config = (DigitSumConfig, ModuloConfig);
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let advice = (0..4).map(|_| meta.advice_column()).collect::<Vec<_>>();
+        let instance = meta.instance_column();
+        let constant = meta.fixed_column();
+        let digit_sum_config =
+            DigitSumChip::configure(meta, advice[..3].try_into().unwrap(), instance);
+        let modulo_config = ModuloChip::configure(
+            meta,
+            advice.try_into().unwrap(),
+            instance,
+            constant,
+            digit_sum_config.digit_table,
+        );
+
+        (digit_sum_config, modulo_config)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let (digit_sum_config, modulo_config) = config;
+        let digit_sum_chip = DigitSumChip::<F>::construct(digit_sum_config);
+        let chip = ModuloChip::construct(modulo_config);
+
+        digit_sum_chip.load_table(layouter.namespace(|| "digit range table"))?;
+
+        let (digits, sum) = digit_sum_chip
+            .load_private_digits(layouter.namespace(|| "private number"), self.number)?;
+        digit_sum_chip.expose_public(layouter.namespace(|| "expose digit sum"), sum, 0)?;
+
+        let digits = digits.map(|digit| digit.cell().clone());
+        let number = chip.recompose(layouter.namespace(|| "recompose number"), &digits)?;
+        let quotient = chip.load_private(layouter.namespace(|| "quotient"), self.quotient)?;
+        let difference = chip.load_private(layouter.namespace(|| "difference"), self.difference)?;
+
+        let modulus = chip.load_public(layouter.namespace(|| "modulus"), 1)?;
+        let residue = chip.load_public(layouter.namespace(|| "residue"), 2)?;
+
+        chip.constrain_modulo(
+            layouter.namespace(|| "modulo"),
+            number,
+            quotient,
+            modulus,
+            residue,
+            difference,
+        )
+    }
+}
+
+impl<F: PrimeField> CircuitSizeParameter for ModuloCircuit<F> {
+    fn size_parameter(&self) -> u32 {
+        self.k
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use halo2_proofs::{dev::MockProver, pasta::Fp};
+
+    use super::*;
+
+    fn run_modulo_circuit(
+        circuit: ModuloCircuit<Fp>,
+        digit_sum: u64,
+        modulus: u64,
+        residue: u64,
+    ) -> MockProver<Fp> {
+        let public_input = vec![Fp::from(digit_sum), Fp::from(modulus), Fp::from(residue)];
+        MockProver::run(circuit.k, &circuit, vec![public_input]).unwrap()
+    }
+
+    #[test]
+    fn modulo_circuit_proof_succeeds_with_valid_public_input() {
+        for (secret_witness_number, digit_sum, modulus, residue) in [
+            (12345678, 36, 7, 12345678 % 7),
+            (12345678, 36, 9, 0),
+            (121, 4, 11, 0),
+            (0, 0, 1, 0),
+            (99999999, 72, 100_000_000, 99999999),
+            (42, 6, 43, 42),
+        ] {
+            let circuit = ModuloCircuit::<Fp>::new(secret_witness_number, modulus).unwrap();
+            let prover = run_modulo_circuit(circuit, digit_sum, modulus, residue);
+
+            prover.verify().expect("the proof should be valid");
+        }
+    }
+
+    #[test]
+    fn modulo_circuit_proof_fails_with_invalid_public_input() {
+        for (secret_witness_number, digit_sum, modulus, residue) in [
+            (12345678, 36, 7, 12345678 % 7 + 1),
+            (12345678, 35, 9, 0),
+            (121, 4, 11, 11),
+            (121, 4, 12, 0),
+        ] {
+            let circuit = ModuloCircuit::<Fp>::new(secret_witness_number, modulus).unwrap();
+            let prover = run_modulo_circuit(circuit, digit_sum, modulus, residue);
+
+            prover.verify().expect_err("the proof should be invalid");
+        }
+    }
+
+    #[test]
+    fn residue_equal_to_modulus_is_rejected() {
+        // The division `121 = 10 * 11 + 11` holds, but the residue is not below the modulus
+        let mut circuit = ModuloCircuit::<Fp>::new(121, 11).unwrap();
+        circuit.quotient = DigitSumSecretWitness::new(10_u64).try_into().unwrap();
+        circuit.difference = [Value::known(-Fp::from(1)); NUMBER_LENGTH];
+        circuit.difference[..NUMBER_LENGTH - 1].fill(Value::known(Fp::from(0)));
+        let prover = run_modulo_circuit(circuit, 4, 11, 11);
+
+        prover
+            .verify()
+            .expect_err("a residue not below the modulus should be rejected by the range check");
+    }
+
+    #[test]
+    fn modulo_circuit_rejects_invalid_modulus() {
+        assert!(ModuloCircuit::<Fp>::new(121, 0).is_err());
+        assert!(ModuloCircuit::<Fp>::new(121, MODULUS_UPPER_BOUND + 1).is_err());
+    }
+}
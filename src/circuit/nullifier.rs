@@ -0,0 +1,179 @@
+use halo2_proofs::{
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    pasta::{group::ff::PrimeField, Fp},
+    plonk::{Circuit, ConstraintSystem, Error},
+};
+
+use crate::{
+    configure_poseidon, field_decimal_capacity, hash_poseidon, CircuitSizeParameter, DecimalNumber,
+    DigitSumChip, DigitSumConfig, DigitSumInstructions, DigitSumSecretWitness, ModuloChip,
+    ModuloConfig, ModuloInstructions, PoseidonConfig, StdResult,
+};
+
+/// The size parameter of the circuit: the circuit must fit into 2^k rows.
+const NULLIFIER_CIRCUIT_SIZE_PARAMETER: u32 = 8;
+
+/// The circuit implementation for digit sum with a nullifier
+///
+/// The nullifier is the Poseidon hash of the secret number (recomposed from its digits) and of a public domain
+/// tag: proofs for the same secret number and domain tag have the same nullifier, which lets a verifier detect a
+/// secret number used twice without learning it.
+/// The public inputs are the digit sum, the domain tag and the nullifier.
+/// The secret number is padded to the decimal capacity of the field (i.e. `76` digits for the Pallas base field),
+/// so that its recomposition never overflows the field modulus.
+pub struct NullifierCircuit<F: PrimeField> {
+    /// The secret number in decimal representation
+    pub number: Vec<Value<F>>,
+
+    /// The size parameter of the circuit: the circuit must fit into 2^k rows.
+    pub k: u32,
+}
+
+impl<F: PrimeField> Default for NullifierCircuit<F> {
+    fn default() -> Self {
+        Self {
+            number: vec![Value::unknown(); field_decimal_capacity::<F>()],
+            k: NULLIFIER_CIRCUIT_SIZE_PARAMETER,
+        }
+    }
+}
+
+impl<F: PrimeField> NullifierCircuit<F> {
+    /// Creates a new nullifier circuit
+    pub fn new(number: impl Into<DecimalNumber>) -> StdResult<Self> {
+        Ok(Self {
+            number: DigitSumSecretWitness::<F>::new(number)
+                .padded_digits(field_decimal_capacity::<F>())?,
+            ..Self::default()
+        })
+    }
+}
+
+impl Circuit<Fp> for NullifierCircuit<Fp> {
+    type Config = (DigitSumConfig, ModuloConfig, PoseidonConfig);
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<Fp>) -> Self::Config {
+        let advice = (0..4).map(|_| meta.advice_column()).collect::<Vec<_>>();
+        let instance = meta.instance_column();
+        let constant = meta.fixed_column();
+        let digit_sum_config =
+            DigitSumChip::configure(meta, advice[..3].try_into().unwrap(), instance);
+        let modulo_config = ModuloChip::configure(
+            meta,
+            advice.clone().try_into().unwrap(),
+            instance,
+            constant,
+            digit_sum_config.digit_table,
+        );
+        let poseidon_config = configure_poseidon(meta, advice.try_into().unwrap());
+
+        (digit_sum_config, modulo_config, poseidon_config)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<Fp>,
+    ) -> Result<(), Error> {
+        let (digit_sum_config, modulo_config, poseidon_config) = config;
+        let instance = digit_sum_config.instance;
+        let digit_sum_chip = DigitSumChip::<Fp>::construct(digit_sum_config);
+        let modulo_chip = ModuloChip::construct(modulo_config);
+
+        digit_sum_chip.load_table(layouter.namespace(|| "digit range table"))?;
+
+        let (digits, sum) =
+            digit_sum_chip.load_digits(layouter.namespace(|| "private number"), &self.number)?;
+        digit_sum_chip.expose_public(layouter.namespace(|| "expose digit sum"), sum, 0)?;
+
+        let digits: Vec<_> = digits.iter().map(|digit| digit.cell().clone()).collect();
+        let number = modulo_chip.recompose(layouter.namespace(|| "recompose number"), &digits)?;
+        let domain = modulo_chip.load_public(layouter.namespace(|| "domain tag"), 1)?;
+        let nullifier = hash_poseidon(
+            &poseidon_config,
+            layouter.namespace(|| "nullifier"),
+            [number.cell(), domain.cell()],
+        )?;
+
+        layouter.constrain_instance(nullifier.cell(), instance, 2)
+    }
+}
+
+impl<F: PrimeField> CircuitSizeParameter for NullifierCircuit<F> {
+    fn size_parameter(&self) -> u32 {
+        self.k
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use halo2_proofs::dev::MockProver;
+
+    use crate::{field_modulus, Nullifier, NullifierPublicInput};
+
+    use super::*;
+
+    fn run_nullifier_circuit(
+        circuit: NullifierCircuit<Fp>,
+        public_input: NullifierPublicInput,
+    ) -> MockProver<Fp> {
+        MockProver::run(circuit.k, &circuit, vec![public_input.into()]).unwrap()
+    }
+
+    #[test]
+    fn nullifier_circuit_proof_succeeds_with_valid_nullifier() {
+        let circuit = NullifierCircuit::<Fp>::new(12345678_u64).unwrap();
+        let public_input =
+            NullifierPublicInput::new(36, 1, Nullifier::new(12345678_u64, 1).unwrap());
+        let prover = run_nullifier_circuit(circuit, public_input);
+
+        prover.verify().expect("the proof should be valid");
+    }
+
+    #[test]
+    fn nullifier_circuit_proof_fails_with_nullifier_of_another_number_or_domain() {
+        for nullifier in [
+            Nullifier::new(12345679_u64, 1).unwrap(),
+            Nullifier::new(12345678_u64, 2).unwrap(),
+        ] {
+            let circuit = NullifierCircuit::<Fp>::new(12345678_u64).unwrap();
+            let public_input = NullifierPublicInput::new(36, 1, nullifier);
+            let prover = run_nullifier_circuit(circuit, public_input);
+
+            prover.verify().expect_err("the proof should be invalid");
+        }
+    }
+
+    #[test]
+    fn nullifier_circuit_proof_fails_with_invalid_digit_sum() {
+        let circuit = NullifierCircuit::<Fp>::new(12345678_u64).unwrap();
+        let public_input =
+            NullifierPublicInput::new(35, 1, Nullifier::new(12345678_u64, 1).unwrap());
+        let prover = run_nullifier_circuit(circuit, public_input);
+
+        prover.verify().expect_err("the proof should be invalid");
+    }
+
+    #[test]
+    fn nullifier_circuit_proof_succeeds_with_number_of_field_decimal_capacity() {
+        let number = "9".repeat(76).parse::<DecimalNumber>().unwrap();
+        let circuit = NullifierCircuit::<Fp>::new(number.clone()).unwrap();
+        let public_input = NullifierPublicInput::new(684, 1, Nullifier::new(number, 1).unwrap());
+        let prover = run_nullifier_circuit(circuit, public_input);
+
+        prover.verify().expect("the proof should be valid");
+    }
+
+    #[test]
+    fn nullifier_circuit_rejects_number_beyond_field_decimal_capacity() {
+        let modulus = DecimalNumber::from(field_modulus::<Fp>());
+
+        assert!(NullifierCircuit::<Fp>::new(modulus.clone()).is_err());
+        assert!(Nullifier::new(modulus, 1).is_err());
+    }
+}
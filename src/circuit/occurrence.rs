@@ -0,0 +1,200 @@
+use anyhow::anyhow;
+use halo2_proofs::{
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    pasta::group::ff::PrimeField,
+    plonk::{Circuit, ConstraintSystem, Error},
+};
+
+use crate::{
+    load_digit_table, CircuitSizeParameter, DigitSumSecretWitness, OccurrenceChip,
+    OccurrenceConfig, OccurrenceInstructions, StdResult, DIGIT_VALUES, NUMBER_LENGTH,
+};
+
+/// The size parameter of the circuit: the circuit must fit into 2^k rows.
+const OCCURRENCE_CIRCUIT_SIZE_PARAMETER: u32 = 7;
+
+/// The circuit implementation for digit occurrences
+///
+/// The public inputs are the occurrence counts of the counted digits (in the same order),
+/// followed by the distinct digits boolean if it is proven.
+pub struct OccurrenceCircuit<F: PrimeField> {
+    /// The secret number in decimal representation
+    pub number: [Value<F>; NUMBER_LENGTH],
+
+    /// The digit values which occurrence counts are exposed as public inputs
+    pub counted_digits: Vec<u64>,
+
+    /// Whether the distinct digits boolean is exposed as a public input
+    pub distinct: bool,
+
+    /// The size parameter of the circuit: the circuit must fit into 2^k rows.
+    pub k: u32,
+}
+
+impl<F: PrimeField> Default for OccurrenceCircuit<F> {
+    fn default() -> Self {
+        Self::new_without_witness(vec![], false).unwrap() // safe unwrap
+    }
+}
+
+impl<F: PrimeField> OccurrenceCircuit<F> {
+    /// Creates a new occurrence circuit
+    pub fn new(number: u64, counted_digits: Vec<u64>, distinct: bool) -> StdResult<Self> {
+        let number = DigitSumSecretWitness::<F>::new(number).try_into()?;
+
+        Ok(Self {
+            number,
+            ..Self::new_without_witness(counted_digits, distinct)?
+        })
+    }
+
+    /// Creates a new occurrence circuit without witness for the counted digits and the distinct digits boolean
+    pub fn new_without_witness(counted_digits: Vec<u64>, distinct: bool) -> StdResult<Self> {
+        let k = OCCURRENCE_CIRCUIT_SIZE_PARAMETER;
+        if let Some(digit) = counted_digits
+            .iter()
+            .find(|digit| **digit >= DIGIT_VALUES as u64)
+        {
+            return Err(anyhow!("Invalid counted digit {digit}"));
+        }
+
+        Ok(Self {
+            number: [Value::unknown(); NUMBER_LENGTH],
+            counted_digits,
+            distinct,
+            k,
+        })
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for OccurrenceCircuit<F> {
+    type Config = OccurrenceConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::new_without_witness(self.counted_digits.clone(), self.distinct).unwrap()
+        // safe unwrap
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let advice = (0..4).map(|_| meta.advice_column()).collect::<Vec<_>>();
+        let instance = meta.instance_column();
+        let constant = meta.fixed_column();
+        let digit_table = meta.lookup_table_column();
+
+        OccurrenceChip::configure(
+            meta,
+            advice.try_into().unwrap(),
+            instance,
+            constant,
+            digit_table,
+        )
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let digit_table = config.digit_table;
+        let chip = OccurrenceChip::construct(config);
+
+        load_digit_table(layouter.namespace(|| "digit range table"), digit_table)?;
+
+        let digits = chip.load_private(layouter.namespace(|| "private number"), self.number)?;
+        let counts = chip.count_occurrences(layouter.namespace(|| "occurrences"), &digits)?;
+
+        for (row, digit) in self.counted_digits.iter().enumerate() {
+            chip.expose_public(
+                layouter.namespace(|| format!("expose occurrences of digit {digit}")),
+                counts[*digit as usize].clone(),
+                row,
+            )?;
+        }
+        if self.distinct {
+            let distinct = chip.distinct(layouter.namespace(|| "distinct digits"), &counts)?;
+            chip.expose_public(
+                layouter.namespace(|| "expose distinct digits"),
+                distinct,
+                self.counted_digits.len(),
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<F: PrimeField> CircuitSizeParameter for OccurrenceCircuit<F> {
+    fn size_parameter(&self) -> u32 {
+        self.k
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use halo2_proofs::{dev::MockProver, pasta::Fp};
+
+    use super::*;
+
+    fn run_occurrence_circuit(
+        circuit: OccurrenceCircuit<Fp>,
+        public_input: Vec<u64>,
+    ) -> MockProver<Fp> {
+        let public_input = public_input.into_iter().map(Fp::from).collect();
+        MockProver::run(circuit.k, &circuit, vec![public_input]).unwrap()
+    }
+
+    #[test]
+    fn occurrence_circuit_proof_succeeds_with_valid_public_input() {
+        for (secret_witness_number, counted_digits, distinct, public_input) in [
+            (12775678, vec![7], false, vec![3]),
+            (12775678, vec![7, 9, 1], false, vec![3, 0, 1]),
+            (123, vec![0, 3], false, vec![5, 1]),
+            (12345678, vec![], true, vec![1]),
+            (12345677, vec![], true, vec![0]),
+            (123, vec![], true, vec![0]),
+            (98765432, vec![4], true, vec![1, 1]),
+        ] {
+            let circuit =
+                OccurrenceCircuit::<Fp>::new(secret_witness_number, counted_digits, distinct)
+                    .unwrap();
+            let prover = run_occurrence_circuit(circuit, public_input);
+
+            prover.verify().expect("the proof should be valid");
+        }
+    }
+
+    #[test]
+    fn occurrence_circuit_proof_fails_with_invalid_public_input() {
+        for (secret_witness_number, counted_digits, distinct, public_input) in [
+            (12775678, vec![7], false, vec![2]),
+            (123, vec![0], false, vec![0]),
+            (12345678, vec![], true, vec![0]),
+            (12345677, vec![], true, vec![1]),
+            (98765432, vec![4], true, vec![1, 0]),
+        ] {
+            let circuit =
+                OccurrenceCircuit::<Fp>::new(secret_witness_number, counted_digits, distinct)
+                    .unwrap();
+            let prover = run_occurrence_circuit(circuit, public_input);
+
+            prover.verify().expect_err("the proof should be invalid");
+        }
+    }
+
+    #[test]
+    fn occurrence_circuit_rejects_invalid_counted_digit() {
+        assert!(OccurrenceCircuit::<Fp>::new(123, vec![10], false).is_err());
+    }
+
+    #[test]
+    fn out_of_range_digit_is_rejected() {
+        let mut circuit = OccurrenceCircuit::<Fp>::new(11111111, vec![1], false).unwrap();
+        circuit.number[0] = Value::known(Fp::from(11));
+        let prover = run_occurrence_circuit(circuit, vec![7]);
+
+        prover
+            .verify()
+            .expect_err("out-of-range digit values should be rejected by the range check lookup");
+    }
+}
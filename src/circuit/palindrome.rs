@@ -0,0 +1,218 @@
+use anyhow::anyhow;
+use halo2_proofs::{
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    pasta::group::ff::PrimeField,
+    plonk::{Circuit, ConstraintSystem, Error},
+};
+
+use crate::{
+    CircuitSizeParameter, DigitSumChip, DigitSumConfig, DigitSumInstructions,
+    DigitSumSecretWitness, PalindromeChip, PalindromeConfig, PalindromeInstructions, StdResult,
+    NUMBER_LENGTH,
+};
+
+/// The size parameter of the circuit: the circuit must fit into 2^k rows.
+const PALINDROME_CIRCUIT_SIZE_PARAMETER: u32 = 5;
+
+/// The circuit implementation for palindrome numbers
+///
+/// The palindrome check reuses the digits assigned by the digit sum chip, whose sum is optionally
+/// exposed as the single public input of the circuit.
+pub struct PalindromeCircuit<F: PrimeField> {
+    /// The secret number in decimal representation
+    pub number: [Value<F>; NUMBER_LENGTH],
+
+    /// The number of digits which read the same forwards and backwards (the other digits are zero)
+    pub digit_count: usize,
+
+    /// Whether the most significant of the checked digits can be a leading zero
+    pub allow_leading_zeros: bool,
+
+    /// Whether the digit sum is exposed as a public input
+    pub digit_sum: bool,
+
+    /// The size parameter of the circuit: the circuit must fit into 2^k rows.
+    pub k: u32,
+}
+
+impl<F: PrimeField> Default for PalindromeCircuit<F> {
+    fn default() -> Self {
+        Self::new_without_witness(NUMBER_LENGTH, true, false).unwrap() // safe unwrap
+    }
+}
+
+impl<F: PrimeField> PalindromeCircuit<F> {
+    /// Creates a new palindrome circuit
+    pub fn new(
+        number: u64,
+        digit_count: usize,
+        allow_leading_zeros: bool,
+        digit_sum: bool,
+    ) -> StdResult<Self> {
+        let number = DigitSumSecretWitness::<F>::new(number).try_into()?;
+
+        Ok(Self {
+            number,
+            ..Self::new_without_witness(digit_count, allow_leading_zeros, digit_sum)?
+        })
+    }
+
+    /// Creates a new palindrome circuit without witness
+    pub fn new_without_witness(
+        digit_count: usize,
+        allow_leading_zeros: bool,
+        digit_sum: bool,
+    ) -> StdResult<Self> {
+        let k = PALINDROME_CIRCUIT_SIZE_PARAMETER;
+        if !(1..=NUMBER_LENGTH).contains(&digit_count) {
+            return Err(anyhow!(
+                "Invalid digit count {digit_count}, expected a value between 1 and {NUMBER_LENGTH}"
+            ));
+        }
+
+        Ok(Self {
+            number: [Value::unknown(); NUMBER_LENGTH],
+            digit_count,
+            allow_leading_zeros,
+            digit_sum,
+            k,
+        })
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for PalindromeCircuit<F> {
+    type Config = (DigitSumConfig, PalindromeConfig);
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::new_without_witness(self.digit_count, self.allow_leading_zeros, self.digit_sum)
+            .unwrap() // safe unwrap
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let advice = (0..3).map(|_| meta.advice_column()).collect::<Vec<_>>();
+        let instance = meta.instance_column();
+        let constant = meta.fixed_column();
+        let digit_sum_config =
+            DigitSumChip::configure(meta, advice.clone().try_into().unwrap(), instance);
+        let palindrome_config =
+            PalindromeChip::configure(meta, advice[1], constant, digit_sum_config.digit_table);
+
+        (digit_sum_config, palindrome_config)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let (digit_sum_config, palindrome_config) = config;
+        let digit_sum_chip = DigitSumChip::<F>::construct(digit_sum_config);
+        let chip = PalindromeChip::construct(palindrome_config);
+
+        digit_sum_chip.load_table(layouter.namespace(|| "digit range table"))?;
+
+        let (digits, sum) = digit_sum_chip
+            .load_private_digits(layouter.namespace(|| "private number"), self.number)?;
+        let digits = digits.map(|digit| digit.cell().clone());
+
+        chip.constrain_palindrome(
+            layouter.namespace(|| "palindrome"),
+            &digits,
+            self.digit_count,
+            self.allow_leading_zeros,
+        )?;
+
+        if self.digit_sum {
+            digit_sum_chip.expose_public(layouter.namespace(|| "expose digit sum"), sum, 0)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<F: PrimeField> CircuitSizeParameter for PalindromeCircuit<F> {
+    fn size_parameter(&self) -> u32 {
+        self.k
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use halo2_proofs::{dev::MockProver, pasta::Fp};
+
+    use super::*;
+
+    fn run_palindrome_circuit(
+        circuit: PalindromeCircuit<Fp>,
+        public_input: Vec<u64>,
+    ) -> MockProver<Fp> {
+        let public_input = public_input.into_iter().map(Fp::from).collect();
+        MockProver::run(circuit.k, &circuit, vec![public_input]).unwrap()
+    }
+
+    #[test]
+    fn palindrome_circuit_proof_succeeds_with_palindrome() {
+        for (secret_witness_number, digit_count, allow_leading_zeros) in [
+            (12344321, 8, false),
+            (1234321, 7, false),
+            (7, 1, false),
+            (0, 1, true),
+            (0, 8, true),
+            (1221, 4, false),
+            (110, 4, true),
+        ] {
+            let circuit = PalindromeCircuit::<Fp>::new(
+                secret_witness_number,
+                digit_count,
+                allow_leading_zeros,
+                false,
+            )
+            .unwrap();
+            let prover = run_palindrome_circuit(circuit, vec![]);
+
+            prover.verify().expect("the proof should be valid");
+        }
+    }
+
+    #[test]
+    fn palindrome_circuit_proof_fails_with_non_palindrome() {
+        for (secret_witness_number, digit_count, allow_leading_zeros) in [
+            (12345678, 8, true),
+            (1221, 8, true),
+            (12344321, 7, true),
+            (1221, 3, true),
+            (110, 4, false),
+            (0, 1, false),
+            (121, 4, true),
+        ] {
+            let circuit = PalindromeCircuit::<Fp>::new(
+                secret_witness_number,
+                digit_count,
+                allow_leading_zeros,
+                false,
+            )
+            .unwrap();
+            let prover = run_palindrome_circuit(circuit, vec![]);
+
+            prover.verify().expect_err("the proof should be invalid");
+        }
+    }
+
+    #[test]
+    fn palindrome_circuit_combines_with_digit_sum() {
+        let circuit = PalindromeCircuit::<Fp>::new(1234321, 7, false, true).unwrap();
+        let prover = run_palindrome_circuit(circuit, vec![16]);
+        prover.verify().expect("the proof should be valid");
+
+        let circuit = PalindromeCircuit::<Fp>::new(1234321, 7, false, true).unwrap();
+        let prover = run_palindrome_circuit(circuit, vec![15]);
+        prover.verify().expect_err("the proof should be invalid");
+    }
+
+    #[test]
+    fn palindrome_circuit_rejects_invalid_digit_count() {
+        assert!(PalindromeCircuit::<Fp>::new(0, 0, true, false).is_err());
+        assert!(PalindromeCircuit::<Fp>::new(0, NUMBER_LENGTH + 1, true, false).is_err());
+    }
+}
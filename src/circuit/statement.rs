@@ -0,0 +1,322 @@
+use halo2_proofs::{
+    circuit::{AssignedCell, Layouter, SimpleFloorPlanner, Value},
+    pasta::{group::ff::PrimeField, Fp},
+    plonk::{Circuit, ConstraintSystem, Error},
+};
+
+use crate::{
+    CircuitSizeParameter, DecimalNumber, DigitSumChip, DigitSumConfig, DigitSumInstructions,
+    DigitSumSecretWitness, MinMaxChip, MinMaxConfig, MinMaxInstructions, ModuloChip, ModuloConfig,
+    ModuloInstructions, Statement, StatementOperator, StatementTerm, StatementValue, StdResult,
+    NUMBER_LENGTH,
+};
+
+use super::size_parameter_for_rows;
+
+/// The count of rows of the digit range lookup table
+const DIGIT_TABLE_ROWS: usize = 10;
+
+/// The euclidean division of the secret number by the modulus of a residue term, in decimal representation
+#[derive(Clone, Debug)]
+pub struct StatementDivision<F: PrimeField> {
+    /// The quotient of the secret number by the modulus
+    pub quotient: [Value<F>; NUMBER_LENGTH],
+
+    /// The residue of the secret number by the modulus
+    pub residue: [Value<F>; NUMBER_LENGTH],
+
+    /// The difference `modulus - 1 - residue`
+    pub difference: [Value<F>; NUMBER_LENGTH],
+}
+
+impl<F: PrimeField> StatementDivision<F> {
+    /// Creates the euclidean division of a number by a modulus
+    fn new(number: u64, modulus: u64) -> StdResult<Self> {
+        let (quotient, residue) = (number / modulus, number % modulus);
+        let difference = modulus - 1 - residue;
+
+        Ok(Self {
+            quotient: DigitSumSecretWitness::<F>::new(quotient).try_into()?,
+            residue: DigitSumSecretWitness::<F>::new(residue).try_into()?,
+            difference: DigitSumSecretWitness::<F>::new(difference).try_into()?,
+        })
+    }
+
+    /// Creates a euclidean division without witness
+    fn without_witness() -> Self {
+        Self {
+            quotient: [Value::unknown(); NUMBER_LENGTH],
+            residue: [Value::unknown(); NUMBER_LENGTH],
+            difference: [Value::unknown(); NUMBER_LENGTH],
+        }
+    }
+}
+
+/// The circuit implementation for a statement parsed from the statement language
+///
+/// The digits are loaded by the digit sum chip, the terms of the constraints are computed by the digit sum, min-max
+/// and modulo chips, and the hash of the statement text is loaded as a constant, so that the verifying key depends on
+/// the statement. The public inputs are the values of the public parameters of the statement.
+pub struct StatementCircuit<F: PrimeField> {
+    /// The statement proven by the circuit
+    pub statement: Statement,
+
+    /// The secret number in decimal representation
+    pub number: Vec<Value<F>>,
+
+    /// The euclidean divisions of the secret number, one for each residue term of the statement
+    pub divisions: Vec<StatementDivision<F>>,
+
+    /// The size parameter of the circuit: the circuit must fit into 2^k rows.
+    pub k: u32,
+}
+
+impl<F: PrimeField> StatementCircuit<F> {
+    /// Creates a new statement circuit
+    pub fn new(statement: Statement, number: impl Into<DecimalNumber>) -> StdResult<Self> {
+        let number = number.into();
+        let digits =
+            DigitSumSecretWitness::<F>::new(number.clone()).padded_digits(statement.digits)?;
+        let divisions = statement
+            .constraints
+            .iter()
+            .filter_map(|constraint| match constraint.term {
+                StatementTerm::Residue(modulus) => Some(modulus),
+                _ => None,
+            })
+            .map(|modulus| StatementDivision::new(number.to_u64()?, modulus))
+            .collect::<StdResult<Vec<_>>>()?;
+
+        Ok(Self {
+            number: digits,
+            divisions,
+            ..Self::new_without_witness(statement)
+        })
+    }
+
+    /// Creates a new statement circuit without witness
+    pub fn new_without_witness(statement: Statement) -> Self {
+        // The digits are loaded on one row per digit, each constraint loads its value or its inverse on one row and
+        // the residue terms recompose the number and load their quotient, residue and difference
+        let rows = 1
+            + statement.digits
+            + statement
+                .constraints
+                .iter()
+                .map(|constraint| {
+                    2 + match constraint.term {
+                        StatementTerm::Sum | StatementTerm::Digit(_) => 0,
+                        StatementTerm::Max | StatementTerm::Min => statement.digits,
+                        StatementTerm::Residue(_) => statement.digits + 3 * NUMBER_LENGTH + 3,
+                    }
+                })
+                .sum::<usize>();
+        let divisions = statement
+            .constraints
+            .iter()
+            .filter(|constraint| matches!(constraint.term, StatementTerm::Residue(_)))
+            .map(|_| StatementDivision::without_witness())
+            .collect();
+
+        Self {
+            number: vec![Value::unknown(); statement.digits],
+            divisions,
+            k: size_parameter_for_rows(rows.max(DIGIT_TABLE_ROWS)),
+            statement,
+        }
+    }
+}
+
+impl Circuit<Fp> for StatementCircuit<Fp> {
+    type Config = (DigitSumConfig, ModuloConfig, MinMaxConfig);
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::new_without_witness(self.statement.clone())
+    }
+
+    fn configure(meta: &mut ConstraintSystem<Fp>) -> Self::Config {
+        let advice = (0..4).map(|_| meta.advice_column()).collect::<Vec<_>>();
+        let instance = meta.instance_column();
+        let constant = meta.fixed_column();
+        let digit_sum_config =
+            DigitSumChip::configure(meta, advice[..3].try_into().unwrap(), instance);
+        let modulo_config = ModuloChip::configure(
+            meta,
+            advice.clone().try_into().unwrap(),
+            instance,
+            constant,
+            digit_sum_config.digit_table,
+        );
+        let min_max_config = MinMaxChip::configure(
+            meta,
+            advice[..3].try_into().unwrap(),
+            instance,
+            constant,
+            digit_sum_config.digit_table,
+        );
+
+        (digit_sum_config, modulo_config, min_max_config)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<Fp>,
+    ) -> Result<(), Error> {
+        let (digit_sum_config, modulo_config, min_max_config) = config;
+        let digit_sum_chip = DigitSumChip::<Fp>::construct(digit_sum_config);
+        let modulo_chip = ModuloChip::construct(modulo_config);
+        let min_max_chip = MinMaxChip::construct(min_max_config);
+
+        digit_sum_chip.load_table(layouter.namespace(|| "digit range table"))?;
+        modulo_chip.load_constant(
+            layouter.namespace(|| "statement hash"),
+            self.statement.hash(),
+        )?;
+
+        let (digits, sum) =
+            digit_sum_chip.load_digits(layouter.namespace(|| "private number"), &self.number)?;
+        let digits = digits
+            .iter()
+            .map(|digit| digit.cell().clone())
+            .collect::<Vec<_>>();
+
+        let mut divisions = self.divisions.iter();
+        for (i, constraint) in self.statement.constraints.iter().enumerate() {
+            let mut layouter = layouter.namespace(|| format!("constraint {i}: {constraint}"));
+            let term: AssignedCell<Fp, Fp> = match constraint.term {
+                StatementTerm::Sum => sum.cell().clone(),
+                StatementTerm::Digit(position) => digits[position].clone(),
+                StatementTerm::Max => min_max_chip
+                    .max_digit(layouter.namespace(|| "maximum digit"), &digits)?
+                    .cell()
+                    .clone(),
+                StatementTerm::Min => min_max_chip
+                    .min_digit(layouter.namespace(|| "minimum digit"), &digits)?
+                    .cell()
+                    .clone(),
+                StatementTerm::Residue(modulus) => {
+                    let division = divisions.next().ok_or(Error::Synthesis)?;
+                    let number = modulo_chip
+                        .recompose(layouter.namespace(|| "recompose number"), &digits)?;
+                    let quotient = modulo_chip
+                        .load_private(layouter.namespace(|| "quotient"), division.quotient)?;
+                    let residue = modulo_chip
+                        .load_private(layouter.namespace(|| "residue"), division.residue)?;
+                    let difference = modulo_chip
+                        .load_private(layouter.namespace(|| "difference"), division.difference)?;
+                    let modulus = modulo_chip
+                        .load_constant(layouter.namespace(|| "modulus"), Fp::from(modulus))?;
+                    modulo_chip.constrain_modulo(
+                        layouter.namespace(|| "modulo"),
+                        number,
+                        quotient,
+                        modulus,
+                        residue.clone(),
+                        difference,
+                    )?;
+
+                    residue.cell().clone()
+                }
+            };
+
+            match (&constraint.operator, &constraint.value) {
+                (StatementOperator::Equal, StatementValue::Constant(value)) => {
+                    let value = modulo_chip
+                        .load_constant(layouter.namespace(|| "constant"), Fp::from(*value))?;
+                    digit_sum_chip.constrain_equal(
+                        layouter.namespace(|| "equal"),
+                        &term.into(),
+                        &value.cell().clone().into(),
+                    )?;
+                }
+                (StatementOperator::Equal, StatementValue::Public(name)) => {
+                    let row = self
+                        .statement
+                        .public_parameter_row(name)
+                        .ok_or(Error::Synthesis)?;
+                    digit_sum_chip.expose_public(
+                        layouter.namespace(|| format!("expose ${name}")),
+                        term.into(),
+                        row,
+                    )?;
+                }
+                (StatementOperator::NotEqual, _) => {
+                    digit_sum_chip
+                        .constrain_non_zero(layouter.namespace(|| "non-zero"), &term.into())?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<F: PrimeField> CircuitSizeParameter for StatementCircuit<F> {
+    fn size_parameter(&self) -> u32 {
+        self.k
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use halo2_proofs::{dev::MockProver, pasta::Fp};
+
+    use super::*;
+
+    fn run_statement_circuit(
+        circuit: StatementCircuit<Fp>,
+        public_input: &[u64],
+    ) -> MockProver<Fp> {
+        let public_input = public_input.iter().copied().map(Fp::from).collect();
+        MockProver::run(circuit.k, &circuit, vec![public_input]).unwrap()
+    }
+
+    #[test]
+    fn statement_circuit_proof_succeeds_with_satisfied_statement() {
+        for (statement, number, public_input) in [
+            (
+                "digits=8; sum == $p; d[0] != 0; n % 3 == 2",
+                31257425_u64,
+                vec![29],
+            ),
+            ("sum == $p; max == $max; min == 1", 31257425, vec![29, 7]),
+            (
+                "digits=4; d[3] == $last; n % 7 != 0; sum != 0",
+                1234,
+                vec![4],
+            ),
+            ("digits=12; sum == 3; d[0] == 0", 111, vec![]),
+        ] {
+            let circuit = StatementCircuit::<Fp>::new(statement.parse().unwrap(), number).unwrap();
+            let prover = run_statement_circuit(circuit, &public_input);
+
+            prover.verify().expect("the proof should be valid");
+        }
+    }
+
+    #[test]
+    fn statement_circuit_proof_fails_with_unsatisfied_statement() {
+        for (statement, number, public_input) in [
+            ("sum == $p", 31257425_u64, vec![28]),
+            ("sum == $p; d[0] != 0", 1257425, vec![26]),
+            ("n % 3 == 0", 31257425, vec![]),
+            ("n % 5 != 0", 31257425, vec![]),
+            ("max == 8", 31257425, vec![]),
+            ("min == $min", 31257425, vec![2]),
+        ] {
+            let circuit = StatementCircuit::<Fp>::new(statement.parse().unwrap(), number).unwrap();
+            let prover = run_statement_circuit(circuit, &public_input);
+
+            prover.verify().expect_err("the proof should be invalid");
+        }
+    }
+
+    #[test]
+    fn statement_circuit_fails_with_too_long_number() {
+        let statement = "digits=4; sum == $p".parse().unwrap();
+
+        assert!(StatementCircuit::<Fp>::new(statement, 12345_u64).is_err());
+    }
+}
@@ -0,0 +1,184 @@
+use halo2_proofs::{
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    pasta::group::ff::PrimeField,
+    plonk::{Circuit, ConstraintSystem, Error},
+};
+
+use crate::{
+    load_digit_table, CircuitSizeParameter, SudokuChip, SudokuConfig, SudokuGrid,
+    SudokuInstructions, SUDOKU_CELLS,
+};
+
+/// The size parameter of the circuit: the circuit must fit into 2^k rows.
+const SUDOKU_CIRCUIT_SIZE_PARAMETER: u32 = 9;
+
+/// The circuit implementation for sudoku solutions
+///
+/// The public inputs are the cells of the puzzle, row by row, where the empty cells are `0`.
+pub struct SudokuCircuit<F: PrimeField> {
+    /// The secret solution, row by row
+    pub solution: [Value<F>; SUDOKU_CELLS],
+
+    /// The size parameter of the circuit: the circuit must fit into 2^k rows.
+    pub k: u32,
+}
+
+impl<F: PrimeField> Default for SudokuCircuit<F> {
+    fn default() -> Self {
+        Self {
+            solution: [Value::unknown(); SUDOKU_CELLS],
+            k: SUDOKU_CIRCUIT_SIZE_PARAMETER,
+        }
+    }
+}
+
+impl<F: PrimeField> SudokuCircuit<F> {
+    /// Creates a new sudoku circuit
+    pub fn new(solution: &SudokuGrid) -> Self {
+        Self {
+            solution: solution.cells().map(|cell| Value::known(F::from(cell))),
+            ..Self::default()
+        }
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for SudokuCircuit<F> {
+    type Config = SudokuConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let advice = (0..3).map(|_| meta.advice_column()).collect::<Vec<_>>();
+        let instance = meta.instance_column();
+        let constant = meta.fixed_column();
+        let digit_table = meta.lookup_table_column();
+
+        SudokuChip::configure(
+            meta,
+            advice.try_into().unwrap(),
+            instance,
+            constant,
+            digit_table,
+        )
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let digit_table = config.digit_table;
+        let chip = SudokuChip::construct(config);
+
+        load_digit_table(layouter.namespace(|| "digit range table"), digit_table)?;
+        chip.load_table(layouter.namespace(|| "digit weight table"))?;
+
+        let weights = chip.load_solution(layouter.namespace(|| "solution"), self.solution)?;
+        for (i, group) in SudokuGrid::groups().into_iter().enumerate() {
+            chip.constrain_group(
+                layouter.namespace(|| format!("group {i}")),
+                group.map(|cell| &weights[cell]),
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<F: PrimeField> CircuitSizeParameter for SudokuCircuit<F> {
+    fn size_parameter(&self) -> u32 {
+        self.k
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use halo2_proofs::{dev::MockProver, pasta::Fp};
+
+    use crate::SudokuPublicInput;
+
+    use super::*;
+
+    const PUZZLE: &str = r#"
+        53. .7. ...
+        6.. 195 ...
+        .98 ... .6.
+        8.. .6. ..3
+        4.. 8.3 ..1
+        7.. .2. ..6
+        .6. ... 28.
+        ... 419 ..5
+        ... .8. .79
+    "#;
+
+    const SOLUTION: &str = r#"
+        534 678 912
+        672 195 348
+        198 342 567
+        859 761 423
+        426 853 791
+        713 924 856
+        961 537 284
+        287 419 635
+        345 286 179
+    "#;
+
+    fn run_sudoku_circuit(circuit: SudokuCircuit<Fp>, puzzle: &SudokuGrid) -> MockProver<Fp> {
+        let public_input = SudokuPublicInput::new(puzzle.clone()).into();
+        MockProver::run(circuit.k, &circuit, vec![public_input]).unwrap()
+    }
+
+    #[test]
+    fn sudoku_circuit_proof_succeeds_with_solution() {
+        let puzzle: SudokuGrid = PUZZLE.parse().unwrap();
+        let solution: SudokuGrid = SOLUTION.parse().unwrap();
+        let circuit = SudokuCircuit::<Fp>::new(&solution);
+        let prover = run_sudoku_circuit(circuit, &puzzle);
+
+        prover.verify().expect("the proof should be valid");
+    }
+
+    #[test]
+    fn sudoku_circuit_proof_fails_with_solution_of_another_puzzle() {
+        let puzzle: SudokuGrid = PUZZLE.replacen("53.", "54.", 1).parse().unwrap();
+        let solution: SudokuGrid = SOLUTION.parse().unwrap();
+        let circuit = SudokuCircuit::<Fp>::new(&solution);
+        let prover = run_sudoku_circuit(circuit, &puzzle);
+
+        prover.verify().expect_err("the proof should be invalid");
+    }
+
+    #[test]
+    fn sudoku_circuit_proof_fails_with_invalid_solution() {
+        let puzzle: SudokuGrid = PUZZLE.parse().unwrap();
+        // The last two rows are swapped: the rows and boxes are still permutations, but not the columns
+        let solution: SudokuGrid = SOLUTION
+            .replace("287 419 635", "xxx")
+            .replace("345 286 179", "287 419 635")
+            .replace("xxx", "345 286 179")
+            .parse()
+            .unwrap();
+        let circuit = SudokuCircuit::<Fp>::new(&solution);
+        let prover = run_sudoku_circuit(circuit, &puzzle);
+
+        prover.verify().expect_err("the proof should be invalid");
+    }
+
+    #[test]
+    fn out_of_range_cell_is_rejected() {
+        let puzzle: SudokuGrid = PUZZLE.parse().unwrap();
+        let solution: SudokuGrid = SOLUTION.parse().unwrap();
+        let mut circuit = SudokuCircuit::<Fp>::new(&solution);
+        // A `0` and a `10` cell keep the row sum of digits, but the weight lookup rejects them
+        circuit.solution[2] = Value::known(Fp::from(0));
+        circuit.solution[3] = Value::known(Fp::from(10));
+        let prover = run_sudoku_circuit(circuit, &puzzle);
+
+        prover
+            .verify()
+            .expect_err("out-of-range cells should be rejected by the lookups");
+    }
+}
@@ -0,0 +1,63 @@
+use clap::Parser;
+use std::path::PathBuf;
+
+use super::{read_input, CircuitOptionArgs};
+use crate::{find_circuit, CircuitOptions, StdResult};
+
+#[derive(Parser, Debug, Clone)]
+pub struct CheckCommand {
+    /// Name of the registered circuit to check, see the `list-circuits` command (defaults to the circuit selected by the options below, or `digitsum`).
+    #[clap(long)]
+    circuit: Option<String>,
+
+    /// Secret witness of the circuit, which is checked without generating a proof (a.k.a. the witness).
+    #[clap(long, short = 'w', required_unless_present = "witness_file")]
+    witness: Option<String>,
+
+    /// Secret witness file of the circuit, which takes precedence over the witness (a.k.a. the witness).
+    #[clap(long)]
+    witness_file: Option<PathBuf>,
+
+    /// Public input of the circuit, e.g. `36` for the `digitsum` circuit or `36,7,2` for the `modulo` circuit (a.k.a. the public input).
+    #[clap(long, short = 'p')]
+    public_input: Option<String>,
+
+    /// Public input file of the circuit, which takes precedence over the public input (a.k.a. the public input).
+    #[clap(long)]
+    public_input_file: Option<PathBuf>,
+
+    /// Positions of the digits of the witness disclosed by the public input, starting at 0 for the most significant digit, whose values are derived from the witness (a.k.a. the public input).
+    #[clap(long, value_delimiter = ',')]
+    reveal: Vec<usize>,
+
+    #[clap(flatten)]
+    options: CircuitOptionArgs,
+}
+
+impl CheckCommand {
+    /// The circuit options given on the command line
+    fn circuit_options(&self) -> CircuitOptions {
+        CircuitOptions {
+            reveal: self.reveal.clone(),
+            ..self.options.circuit_options()
+        }
+    }
+
+    /// Main command execution
+    pub fn execute(&self) -> StdResult<()> {
+        let witness = read_input(self.witness.as_deref(), self.witness_file.as_deref())?;
+        let public_input = read_input(
+            self.public_input.as_deref(),
+            self.public_input_file.as_deref(),
+        )?;
+        let options = self.circuit_options();
+        find_circuit(self.circuit.as_deref().unwrap_or(options.circuit_name()))?.check(
+            witness.as_deref().unwrap_or_default(),
+            public_input.as_deref().unwrap_or_default(),
+            &options,
+        )?;
+        println!(">> Circuit satisfied!");
+
+        Ok(())
+    }
+}
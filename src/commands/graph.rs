@@ -1,12 +1,24 @@
 use clap::Parser;
-use halo2_proofs::pasta::Fp;
 use plotters::prelude::*;
 use std::path::PathBuf;
 
-use crate::{DigitSumCircuit, StdResult};
+use super::read_input;
+use crate::{find_circuit, StdResult};
 
 #[derive(Parser, Debug, Clone)]
 pub struct GraphCommand {
+    /// Name of the registered circuit, see the `list-circuits` command.
+    #[clap(long, default_value = "digitsum")]
+    circuit: String,
+
+    /// Public input of the circuit which gives its shape (defaults to the default shape of the circuit).
+    #[clap(long, short = 'p')]
+    public_input: Option<String>,
+
+    /// Public input file of the circuit, which takes precedence over the public input.
+    #[clap(long)]
+    public_input_file: Option<PathBuf>,
+
     /// Circuit layout export filename.
     #[clap(long, default_value = "circuit-layout.png")]
     graph_file_name: PathBuf,
@@ -39,9 +51,13 @@ pub struct GraphCommand {
 impl GraphCommand {
     /// Main command execution
     pub fn execute(&self) -> StdResult<()> {
-        let circuit = DigitSumCircuit::<Fp>::default();
+        let circuit = find_circuit(&self.circuit)?;
+        let public_input = read_input(
+            self.public_input.as_deref(),
+            self.public_input_file.as_deref(),
+        )?;
 
-        let graph_layout_title = "Digit Sum Circuit";
+        let graph_layout_title = circuit.description();
         let graph_layout_dimensions = (self.graph_width, self.graph_height);
         let graph_export_path = self.graph_export_dir.join(&self.graph_file_name);
         let root =
@@ -49,11 +65,11 @@ impl GraphCommand {
         root.fill(&WHITE)?;
         let root = root.titled(graph_layout_title, ("sans-serif", 30))?;
 
-        halo2_proofs::dev::CircuitLayout::default()
+        let layout = halo2_proofs::dev::CircuitLayout::default()
             .show_labels(self.graph_with_labels)
             .mark_equality_cells(self.graph_show_equality_constraints)
-            .show_equality_constraints(self.graph_show_equality_constraints)
-            .render(circuit.k, &circuit, &root)?;
+            .show_equality_constraints(self.graph_show_equality_constraints);
+        circuit.render_layout(public_input.as_deref(), layout, &root)?;
         println!(">> Circuit layout generated to {:?}", graph_export_path);
 
         Ok(())
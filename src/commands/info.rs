@@ -0,0 +1,45 @@
+use clap::Parser;
+use std::path::PathBuf;
+
+use super::read_input;
+use crate::{find_circuit, StdResult};
+
+#[derive(Parser, Debug, Clone)]
+pub struct InfoCommand {
+    /// Name of the registered circuit, see the `list-circuits` command.
+    #[clap(long, default_value = "digitsum")]
+    circuit: String,
+
+    /// Public input of the circuit which gives its shape (defaults to the default shape of the circuit).
+    #[clap(long, short = 'p')]
+    public_input: Option<String>,
+
+    /// Public input file of the circuit, which takes precedence over the public input.
+    #[clap(long)]
+    public_input_file: Option<PathBuf>,
+}
+
+impl InfoCommand {
+    /// Main command execution
+    pub fn execute(&self) -> StdResult<()> {
+        let circuit = find_circuit(&self.circuit)?;
+        let public_input = read_input(
+            self.public_input.as_deref(),
+            self.public_input_file.as_deref(),
+        )?;
+        println!(">> Circuit: {}", circuit.name());
+        println!(">> Description: {}", circuit.description());
+        println!(">> Witness: {}", circuit.witness_format());
+        println!(">> Public input: {}", circuit.public_input_format());
+        println!(
+            ">> Size parameter: k={}",
+            circuit.size_parameter(public_input.as_deref())?
+        );
+        println!(
+            ">> Verifying key fingerprint: {}",
+            circuit.verifying_key_fingerprint(public_input.as_deref())?
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,23 @@
+use clap::Parser;
+
+use crate::{StdResult, CIRCUIT_REGISTRY};
+
+#[derive(Parser, Debug, Clone)]
+pub struct ListCircuitsCommand {}
+
+impl ListCircuitsCommand {
+    /// Main command execution
+    pub fn execute(&self) -> StdResult<()> {
+        for circuit in CIRCUIT_REGISTRY {
+            println!(
+                ">> {}: {} (witness: {}, public input: {})",
+                circuit.name(),
+                circuit.description(),
+                circuit.witness_format(),
+                circuit.public_input_format()
+            );
+        }
+
+        Ok(())
+    }
+}
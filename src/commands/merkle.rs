@@ -0,0 +1,88 @@
+use anyhow::anyhow;
+use clap::{Parser, Subcommand};
+use std::{
+    fs::{read_to_string, File},
+    io::Write,
+    path::PathBuf,
+};
+
+use crate::{MerkleTree, StdResult};
+
+#[derive(Parser, Debug, Clone)]
+pub struct MerkleCommand {
+    /// Available Merkle tree commands
+    #[clap(subcommand)]
+    command: MerkleCommands,
+}
+
+impl MerkleCommand {
+    /// Main command execution
+    pub fn execute(&self) -> StdResult<()> {
+        self.command.execute()
+    }
+}
+
+/// The available Merkle tree sub-commands
+#[derive(Subcommand, Debug, Clone)]
+pub enum MerkleCommands {
+    /// Build the Merkle tree of an allow-list of numbers
+    #[clap(arg_required_else_help = true)]
+    Build(MerkleBuildCommand),
+}
+
+impl MerkleCommands {
+    /// Execute the command
+    pub fn execute(&self) -> StdResult<()> {
+        match self {
+            Self::Build(cmd) => cmd.execute(),
+        }
+    }
+}
+
+#[derive(Parser, Debug, Clone)]
+pub struct MerkleBuildCommand {
+    /// Public allow-list file, with one number per line.
+    #[clap(long, short = 'n')]
+    numbers_file: PathBuf,
+
+    /// Merkle root export filename (a.k.a. the public input).
+    #[clap(long, default_value = "merkle_root.hex")]
+    root_file_name: PathBuf,
+
+    /// Merkle paths export filename, with the number, its index and its siblings on each line.
+    #[clap(long, default_value = "merkle_paths.txt")]
+    paths_file_name: PathBuf,
+
+    /// Merkle tree export directory.
+    #[clap(long, default_value = "./")]
+    export_dir: PathBuf,
+}
+
+impl MerkleBuildCommand {
+    /// Main command execution
+    pub fn execute(&self) -> StdResult<()> {
+        let numbers = read_to_string(&self.numbers_file)?
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(line_number, line)| {
+                line.trim()
+                    .parse::<u64>()
+                    .map_err(|_| anyhow!("Line {}: invalid number '{line}'", line_number + 1))
+            })
+            .collect::<StdResult<Vec<_>>>()?;
+        let tree = MerkleTree::build(&numbers)?;
+
+        let root_export_path = self.export_dir.join(&self.root_file_name);
+        let mut root_file = File::create(&root_export_path)?;
+        write!(root_file, "{}", tree.root())?;
+        println!(">> Merkle root exported to {:?}", root_export_path);
+
+        let paths_export_path = self.export_dir.join(&self.paths_file_name);
+        let mut paths_file = File::create(&paths_export_path)?;
+        write!(paths_file, "{}", tree.paths())?;
+        println!(">> Merkle paths exported to {:?}", paths_export_path);
+
+        Ok(())
+    }
+}
@@ -1,18 +1,88 @@
 //! The command module contains the command line interface for the digit sum circuit:
-//! - `prove` generates a proof for a given number (witness) and digit sum (public input)
-//! - `verify` verifies a proof for a given number (witness) and digit sum (public input)
-//! - `graph` generates a graph representation of the circuit
+//! - `prove` generates a proof of a registered circuit for a given witness and public input
+//! - `verify` verifies a proof of a registered circuit for a given public input
+//! - `graph` generates a graph representation of a registered circuit
+//! - `merkle build` builds the Merkle tree of an allow-list of numbers and exports its root and authentication paths
+//! - `check` checks that a given witness and public input satisfy a registered circuit, without generating a proof
+//! - `info` prints the formats of the inputs, the size parameter and the verifying key fingerprint of a registered circuit
+//! - `list-circuits` lists the registered circuits which can be selected with the `--circuit` option
 
+mod check;
 mod graph;
+mod info;
+mod list_circuits;
+mod merkle;
 mod prove;
 mod verify;
 
 use clap::{Parser, Subcommand};
 use slog::Level;
+use std::{fs::read_to_string, path::Path};
 
-use crate::StdResult;
+use crate::{CircuitOptions, Statement, StatementParameter, StdResult};
 
-use self::{graph::GraphCommand, prove::ProveCommand, verify::VerifyCommand};
+use self::{
+    check::CheckCommand, graph::GraphCommand, info::InfoCommand,
+    list_circuits::ListCircuitsCommand, merkle::MerkleCommand, prove::ProveCommand,
+    verify::VerifyCommand,
+};
+
+/// Reads an input of a registered circuit, from its file when there is one
+fn read_input(input: Option<&str>, input_file: Option<&Path>) -> StdResult<Option<String>> {
+    match input_file {
+        Some(path) => Ok(Some(read_to_string(path)?)),
+        None => Ok(input.map(str::to_string)),
+    }
+}
+
+/// The family specific options of the `prove`, `verify` and `check` commands, which select the circuit when none is
+/// given
+#[derive(clap::Args, Debug, Clone)]
+struct CircuitOptionArgs {
+    /// Public modulus of the euclidean division of the witness, which selects the `modulo` circuit (a.k.a. the public input).
+    #[clap(long, requires = "residue")]
+    modulus: Option<u64>,
+
+    /// Public residue of the euclidean division of the witness by the modulus (a.k.a. the public input).
+    #[clap(long, requires = "modulus")]
+    residue: Option<u64>,
+
+    /// Public count of digits of the witness, including its leading zeros (a.k.a. the public input).
+    #[clap(long)]
+    digits: Option<usize>,
+
+    /// Constrains the most significant digit of the witness to be non-zero, which selects the `digitsum-exact` circuit.
+    #[clap(long)]
+    non_zero_leading_digit: bool,
+
+    /// Public domain tag of the nullifier of the witness, which selects the `nullifier` circuit (a.k.a. the public input).
+    #[clap(long)]
+    nullifier_domain: Option<u64>,
+
+    /// Statement about the digits of the witness, e.g. `digits=8; sum == $p; d[0] != 0; n % 3 == 0`, which selects the `statement` circuit and whose `$p` parameter is bound by the public input.
+    #[clap(long)]
+    statement: Option<Statement>,
+
+    /// Values of the other public parameters of the statement, e.g. `max=7,last=4` (a.k.a. the public input).
+    #[clap(long, value_delimiter = ',')]
+    param: Vec<StatementParameter>,
+}
+
+impl CircuitOptionArgs {
+    /// The circuit options given on the command line
+    fn circuit_options(&self) -> CircuitOptions {
+        CircuitOptions {
+            modulus: self.modulus,
+            residue: self.residue,
+            digits: self.digits,
+            non_zero_leading_digit: self.non_zero_leading_digit,
+            nullifier_domain: self.nullifier_domain,
+            statement: self.statement.clone(),
+            params: self.param.clone(),
+            ..CircuitOptions::default()
+        }
+    }
+}
 
 /// The main CLI arguments
 #[derive(Parser, Debug, Clone)]
@@ -53,17 +123,31 @@ impl Args {
 /// The available sub-commands of the CLI
 #[derive(Subcommand, Debug, Clone)]
 pub enum CliCommands {
-    /// Run the prover for the digit sum circuit
+    /// Run the prover for a registered circuit
     #[clap(arg_required_else_help = true)]
     Prove(ProveCommand),
 
-    /// Run the verifier for the digit sum circuit
+    /// Run the verifier for a registered circuit
     #[clap(arg_required_else_help = true)]
     Verify(VerifyCommand),
 
-    /// Run the graph exporter for the digit sum circuit
+    /// Run the graph exporter for a registered circuit
     #[clap(arg_required_else_help = false)]
     Graph(GraphCommand),
+
+    /// Run the Merkle tree builder for the Merkle membership circuit
+    #[clap(arg_required_else_help = true)]
+    Merkle(MerkleCommand),
+
+    /// Run the checker of a registered circuit
+    #[clap(arg_required_else_help = true)]
+    Check(CheckCommand),
+
+    /// Run the information printer of a registered circuit
+    Info(InfoCommand),
+
+    /// Run the lister of the registered circuits
+    ListCircuits(ListCircuitsCommand),
 }
 
 impl CliCommands {
@@ -73,6 +157,10 @@ impl CliCommands {
             Self::Prove(cmd) => cmd.execute(),
             Self::Verify(cmd) => cmd.execute(),
             Self::Graph(cmd) => cmd.execute(),
+            Self::Merkle(cmd) => cmd.execute(),
+            Self::Check(cmd) => cmd.execute(),
+            Self::Info(cmd) => cmd.execute(),
+            Self::ListCircuits(cmd) => cmd.execute(),
         }
     }
 }
@@ -1,18 +1,37 @@
 use clap::Parser;
-use halo2_proofs::pasta::Fp;
 use std::{fs::File, io::Write, path::PathBuf};
 
-use crate::{CircuitProver, DigitSumCircuit, StdResult};
+use super::{read_input, CircuitOptionArgs};
+use crate::{find_circuit, CircuitOptions, StdResult};
 
 #[derive(Parser, Debug, Clone)]
 pub struct ProveCommand {
-    /// Secret number that Alice knows (a.k.a. the witness).
-    #[clap(long, short = 'w')]
-    witness: u64,
+    /// Name of the registered circuit to prove, see the `list-circuits` command (defaults to the circuit selected by the options below, or `digitsum`).
+    #[clap(long)]
+    circuit: Option<String>,
 
-    /// Public number that Bob knows and which represents the sum of the digits of the witness (a.k.a. the public input).
+    /// Secret witness of the circuit, e.g. the secret number that Alice knows (a.k.a. the witness).
+    #[clap(long, short = 'w', required_unless_present = "witness_file")]
+    witness: Option<String>,
+
+    /// Secret witness file of the circuit, which takes precedence over the witness (a.k.a. the witness).
+    #[clap(long)]
+    witness_file: Option<PathBuf>,
+
+    /// Public input of the circuit, e.g. `36` for the `digitsum` circuit or `36,7,2` for the `modulo` circuit (a.k.a. the public input).
     #[clap(long, short = 'p')]
-    public_input: u64,
+    public_input: Option<String>,
+
+    /// Public input file of the circuit, which takes precedence over the public input (a.k.a. the public input).
+    #[clap(long)]
+    public_input_file: Option<PathBuf>,
+
+    /// Positions of the digits of the witness disclosed to Bob, starting at 0 for the most significant digit, whose values are derived from the witness (a.k.a. the public input).
+    #[clap(long, value_delimiter = ',')]
+    reveal: Vec<usize>,
+
+    #[clap(flatten)]
+    options: CircuitOptionArgs,
 
     /// Proof export filename.
     #[clap(long, default_value = "proof.hex")]
@@ -24,11 +43,31 @@ pub struct ProveCommand {
 }
 
 impl ProveCommand {
+    /// The circuit options given on the command line
+    fn circuit_options(&self) -> CircuitOptions {
+        CircuitOptions {
+            reveal: self.reveal.clone(),
+            ..self.options.circuit_options()
+        }
+    }
+
     /// Main command execution
     pub fn execute(&self) -> StdResult<()> {
-        let secret_witness_number = self.witness;
-        let circuit = DigitSumCircuit::<Fp>::new(secret_witness_number)?;
-        let proof = circuit.prove(&[self.public_input.into()])?;
+        let witness = read_input(self.witness.as_deref(), self.witness_file.as_deref())?;
+        let public_input = read_input(
+            self.public_input.as_deref(),
+            self.public_input_file.as_deref(),
+        )?;
+        let options = self.circuit_options();
+        let circuit = find_circuit(self.circuit.as_deref().unwrap_or(options.circuit_name()))?;
+        let (proof, completed_public_input) = circuit.prove(
+            witness.as_deref().unwrap_or_default(),
+            public_input.as_deref().unwrap_or_default(),
+            &options,
+        )?;
+        if let Some(public_input) = completed_public_input {
+            println!(">> Public input: {public_input}");
+        }
 
         let proof_hex = hex::encode(proof);
         let proof_export_path = self.proof_export_dir.join(&self.proof_file_name);
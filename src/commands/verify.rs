@@ -1,15 +1,41 @@
-use std::{fs::read, path::PathBuf};
-
+use anyhow::anyhow;
 use clap::Parser;
-use halo2_proofs::pasta::Fp;
+use std::{
+    fs::{read, read_to_string, write},
+    path::PathBuf,
+};
 
-use crate::{CircuitVerifier, DigitSumCircuit, StdResult};
+use super::{read_input, CircuitOptionArgs};
+use crate::{find_circuit, CircuitOptions, DisclosedDigit, Nullifier, NullifierSet, StdResult};
 
 #[derive(Parser, Debug, Clone)]
 pub struct VerifyCommand {
-    /// Public number that Bob knows and which represents the sum of the digits of the witness (a.k.a. the public input).
+    /// Name of the registered circuit to verify, see the `list-circuits` command (defaults to the circuit selected by the options below, or `digitsum`).
+    #[clap(long)]
+    circuit: Option<String>,
+
+    /// Public input of the circuit, e.g. `36` for the `digitsum` circuit or `36,7,2` for the `modulo` circuit (a.k.a. the public input).
     #[clap(long, short = 'p')]
-    public_input: u64,
+    public_input: Option<String>,
+
+    /// Public input file of the circuit, which takes precedence over the public input (a.k.a. the public input).
+    #[clap(long)]
+    public_input_file: Option<PathBuf>,
+
+    /// Digits of the witness disclosed to Bob with their positions, e.g. `0=1,1=2` (a.k.a. the public input).
+    #[clap(long, value_delimiter = ',')]
+    revealed: Vec<DisclosedDigit>,
+
+    /// Public nullifier of the witness printed by the prover, along with the nullifier domain (a.k.a. the public input).
+    #[clap(long)]
+    nullifier: Option<Nullifier>,
+
+    #[clap(flatten)]
+    options: CircuitOptionArgs,
+
+    /// Nullifier set file, which must not contain the nullifier of the public input and to which it is added once the proof is verified.
+    #[clap(long)]
+    nullifier_set: Option<PathBuf>,
 
     /// Proof import filename.
     #[clap(long, default_value = "proof.hex")]
@@ -21,14 +47,51 @@ pub struct VerifyCommand {
 }
 
 impl VerifyCommand {
+    /// The circuit options given on the command line
+    fn circuit_options(&self) -> CircuitOptions {
+        CircuitOptions {
+            revealed: self.revealed.clone(),
+            nullifier: self.nullifier,
+            ..self.options.circuit_options()
+        }
+    }
+
     /// Main command execution
     pub fn execute(&self) -> StdResult<()> {
         let proof_import_path = self.proof_import_dir.join(&self.proof_file_name);
         let proof = read(proof_import_path)?;
         let proof = hex::decode(proof)?;
 
-        let circuit = DigitSumCircuit::<Fp>::default();
-        circuit.verify(&[self.public_input.into()], &proof)?;
+        let options = self.circuit_options();
+        let circuit = find_circuit(self.circuit.as_deref().unwrap_or(options.circuit_name()))?;
+        let public_input = read_input(
+            self.public_input.as_deref(),
+            self.public_input_file.as_deref(),
+        )?
+        .unwrap_or_default();
+        let Some(path) = &self.nullifier_set else {
+            circuit.verify(&public_input, &options, &proof)?;
+            println!(">> Proof verified!");
+
+            return Ok(());
+        };
+
+        let nullifier = circuit.nullifier(&public_input, &options)?.ok_or_else(|| {
+            anyhow!(
+                "The circuit {} has no nullifier to add to the nullifier set",
+                circuit.name()
+            )
+        })?;
+        let mut nullifier_set = match path.exists() {
+            true => read_to_string(path)?.parse()?,
+            false => NullifierSet::default(),
+        };
+        if nullifier_set.contains(&nullifier) {
+            return Err(anyhow!("Nullifier {nullifier} has already been used"));
+        }
+        circuit.verify(&public_input, &options, &proof)?;
+        nullifier_set.insert(nullifier);
+        write(path, nullifier_set.to_string())?;
         println!(">> Proof verified!");
 
         Ok(())
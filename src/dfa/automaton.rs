@@ -0,0 +1,225 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    str::FromStr,
+};
+
+use anyhow::anyhow;
+
+use crate::{StdError, StdResult};
+
+/// The number of symbols of the automaton alphabet (the decimal digits)
+const ALPHABET_SIZE: u64 = 10;
+
+/// A deterministic finite automaton over the decimal digits
+///
+/// The automaton is described with a text format made of one statement per line
+/// (empty lines and comments starting with `#` are ignored):
+/// - `start <state>` declares the start state.
+/// - `accept <state> [<state> ...]` declares the accepting states.
+/// - `<state> <digits> <next state>` declares transitions, where `<digits>` is a comma separated
+///   list of digits or digit ranges (e.g. `0-4,7`).
+///
+/// Missing transitions lead to the rejection of the digit string.
+///
+/// ```text
+/// # Digit strings with an even number of digit 7
+/// start even
+/// accept even
+/// even 0-6,8-9 even
+/// even 7 odd
+/// odd 0-6,8-9 odd
+/// odd 7 even
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Automaton {
+    states: Vec<String>,
+    start: usize,
+    accepting: BTreeSet<usize>,
+    transitions: BTreeMap<(usize, u64), usize>,
+}
+
+impl Automaton {
+    /// Computes the code of a state in the circuit (the code `0` is reserved for disabled lookups)
+    fn state_code(state: usize) -> u64 {
+        state as u64 + 1
+    }
+
+    /// The code of the start state in the circuit
+    pub fn start_code(&self) -> u64 {
+        Self::state_code(self.start)
+    }
+
+    /// The transitions of the automaton as `(state code, digit, next state code)`
+    pub fn transition_codes(&self) -> Vec<[u64; 3]> {
+        self.transitions
+            .iter()
+            .map(|((state, digit), next_state)| {
+                [
+                    Self::state_code(*state),
+                    *digit,
+                    Self::state_code(*next_state),
+                ]
+            })
+            .collect()
+    }
+
+    /// The codes of the accepting states of the automaton
+    pub fn accepting_codes(&self) -> Vec<u64> {
+        self.accepting
+            .iter()
+            .map(|state| Self::state_code(*state))
+            .collect()
+    }
+
+    /// Walks the automaton with a digit string and returns the codes of the visited states
+    pub fn walk(&self, digits: &[u64]) -> StdResult<Vec<u64>> {
+        let mut state = self.start;
+        let mut states = vec![Self::state_code(state)];
+        for (position, digit) in digits.iter().enumerate() {
+            state = *self.transitions.get(&(state, *digit)).ok_or_else(|| {
+                anyhow!(
+                    "No transition from state '{}' with digit {digit} at position {position}",
+                    self.states[state]
+                )
+            })?;
+            states.push(Self::state_code(state));
+        }
+        if !self.accepting.contains(&state) {
+            return Err(anyhow!(
+                "The walk ends in the non accepting state '{}'",
+                self.states[state]
+            ));
+        }
+
+        Ok(states)
+    }
+
+    /// Checks if the automaton accepts a digit string
+    pub fn accepts(&self, digits: &[u64]) -> bool {
+        self.walk(digits).is_ok()
+    }
+
+    /// Parses a comma separated list of digits or digit ranges
+    fn parse_digits(digits: &str) -> StdResult<Vec<u64>> {
+        let parse_digit = |digit: &str| {
+            digit
+                .parse::<u64>()
+                .ok()
+                .filter(|digit| *digit < ALPHABET_SIZE)
+                .ok_or_else(|| anyhow!("Invalid digit '{digit}'"))
+        };
+        let mut values = vec![];
+        for item in digits.split(',') {
+            match item.split_once('-') {
+                Some((from, to)) => values.extend(parse_digit(from)?..=parse_digit(to)?),
+                None => values.push(parse_digit(item)?),
+            }
+        }
+
+        Ok(values)
+    }
+}
+
+impl FromStr for Automaton {
+    type Err = StdError;
+
+    fn from_str(description: &str) -> Result<Self, Self::Err> {
+        let mut states: Vec<String> = vec![];
+        let mut state_index = |name: &str| match states.iter().position(|s| s == name) {
+            Some(index) => index,
+            None => {
+                states.push(name.to_string());
+                states.len() - 1
+            }
+        };
+        let mut start = None;
+        let mut accepting = BTreeSet::new();
+        let mut transitions = BTreeMap::new();
+        for (line_number, line) in description.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or_default(); // safe unwrap
+            let tokens = line.split_whitespace().collect::<Vec<_>>();
+            let error = |message: &str| anyhow!("Line {}: {message}", line_number + 1);
+            match tokens.as_slice() {
+                [] => {}
+                ["start", state] => {
+                    if start.replace(state_index(state)).is_some() {
+                        return Err(error("the start state is declared twice"));
+                    }
+                }
+                ["accept", accept_states @ ..] if !accept_states.is_empty() => {
+                    accepting.extend(accept_states.iter().map(|state| state_index(state)));
+                }
+                [state, digits, next_state] => {
+                    let (state, next_state) = (state_index(state), state_index(next_state));
+                    let digits = Self::parse_digits(digits).map_err(|e| error(&e.to_string()))?;
+                    for digit in digits {
+                        if transitions.insert((state, digit), next_state).is_some() {
+                            return Err(error(&format!(
+                                "the transition with digit {digit} is declared twice"
+                            )));
+                        }
+                    }
+                }
+                _ => return Err(error(&format!("invalid statement '{}'", line.trim()))),
+            }
+        }
+        let start = start.ok_or_else(|| anyhow!("The start state is not declared"))?;
+
+        Ok(Self {
+            states,
+            start,
+            accepting,
+            transitions,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EVEN_SEVENS_AUTOMATON: &str = r#"
+        # Digit strings with an even number of digit 7
+        start even
+        accept even
+        even 0-6,8-9 even
+        even 7 odd
+        odd 0-6,8-9 odd
+        odd 7 even
+    "#;
+
+    #[test]
+    fn automaton_parses_valid_description() {
+        let automaton: Automaton = EVEN_SEVENS_AUTOMATON.parse().unwrap();
+
+        assert_eq!(1, automaton.start_code());
+        assert_eq!(vec![1], automaton.accepting_codes());
+        assert_eq!(20, automaton.transition_codes().len());
+        assert!(automaton.transition_codes().contains(&[1, 7, 2]));
+    }
+
+    #[test]
+    fn automaton_rejects_invalid_descriptions() {
+        for description in [
+            "accept a",
+            "start a\nstart b",
+            "start a\na 0-10 a",
+            "start a\na 1 a\na 1 b",
+            "start a\na 1",
+        ] {
+            assert!(
+                description.parse::<Automaton>().is_err(),
+                "description '{description}' should be invalid"
+            );
+        }
+    }
+
+    #[test]
+    fn automaton_walks_digit_strings() {
+        let automaton: Automaton = EVEN_SEVENS_AUTOMATON.parse().unwrap();
+
+        assert_eq!(vec![1, 2, 2, 1], automaton.walk(&[7, 1, 7]).unwrap());
+        assert!(automaton.accepts(&[]));
+        assert!(!automaton.accepts(&[7, 1, 2]));
+    }
+}
@@ -0,0 +1,197 @@
+use std::marker::PhantomData;
+
+use halo2_proofs::{
+    circuit::{Chip, Layouter, Value},
+    pasta::group::ff::PrimeField,
+    plonk::{Advice, Column, ConstraintSystem, Error, Fixed},
+    poly::Rotation,
+};
+
+use crate::{Automaton, AutomatonConfig, AutomatonInstructions};
+
+/// The chip that implements the automaton walk instructions
+pub struct AutomatonChip<F: PrimeField> {
+    config: AutomatonConfig,
+    _marker: PhantomData<F>,
+}
+
+impl<F: PrimeField> AutomatonChip<F> {
+    /// Creates a new instance of the automaton chip
+    pub fn construct(config: AutomatonConfig) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Configures the automaton chip
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        advice: [Column<Advice>; 3],
+        constant: Column<Fixed>,
+    ) -> <Self as Chip<F>>::Config {
+        meta.enable_constant(constant);
+        for column in &advice {
+            meta.enable_equality(*column);
+        }
+        let s_transition = meta.complex_selector();
+        let s_accept = meta.complex_selector();
+        let transition_table = [
+            meta.lookup_table_column(),
+            meta.lookup_table_column(),
+            meta.lookup_table_column(),
+        ];
+        let accept_table = meta.lookup_table_column();
+
+        // This lookup implements the walk of the automaton with the digits of the provided string
+        // Here is the arrangement of the cells of the lookups
+        //
+        // | a0  | a1     | a2     | s_transition | s_accept |
+        // |-----|--------|--------|--------------|----------|
+        // | in0 | start  | state1 | s_transition |          |
+        // | in1 | state1 | state2 | s_transition |          |
+        // | ... | ...    | ...    | s_transition |          |
+        // | inN | stateN | end    | s_transition |          |
+        // |     | end    |        |              | s_accept |
+        //
+        // State codes start at 1, so that the disabled selector `(0, 0, 0)` entry of the
+        // transition table never matches an actual transition.
+        meta.lookup(|meta| {
+            let s_transition = meta.query_selector(s_transition);
+            let digit = meta.query_advice(advice[0], Rotation::cur());
+            let state = meta.query_advice(advice[1], Rotation::cur());
+            let next_state = meta.query_advice(advice[2], Rotation::cur());
+            vec![
+                (s_transition.clone() * state, transition_table[0]),
+                (s_transition.clone() * digit, transition_table[1]),
+                (s_transition * next_state, transition_table[2]),
+            ]
+        });
+
+        meta.lookup(|meta| {
+            let s_accept = meta.query_selector(s_accept);
+            let state = meta.query_advice(advice[1], Rotation::cur());
+            vec![(s_accept * state, accept_table)]
+        });
+
+        AutomatonConfig {
+            advice,
+            s_transition,
+            s_accept,
+            transition_table,
+            accept_table,
+        }
+    }
+}
+
+impl<F: PrimeField> Chip<F> for AutomatonChip<F> {
+    type Config = AutomatonConfig;
+    type Loaded = ();
+
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+impl<F: PrimeField> AutomatonInstructions<F> for AutomatonChip<F> {
+    fn load_table(
+        &self,
+        mut layouter: impl Layouter<F>,
+        automaton: &Automaton,
+    ) -> Result<(), Error> {
+        let config = self.config();
+
+        layouter.assign_table(
+            || "transition table",
+            |mut table| {
+                let disabled_entry = [0, 0, 0];
+                let entries = std::iter::once(disabled_entry).chain(automaton.transition_codes());
+                for (row, entry) in entries.enumerate() {
+                    for (column, value) in config.transition_table.into_iter().zip(entry) {
+                        table.assign_cell(
+                            || format!("transition {entry:?}"),
+                            column,
+                            row,
+                            || Value::known(F::from(value)),
+                        )?;
+                    }
+                }
+                Ok(())
+            },
+        )?;
+
+        layouter.assign_table(
+            || "accept table",
+            |mut table| {
+                let entries = std::iter::once(0).chain(automaton.accepting_codes());
+                for (row, state) in entries.enumerate() {
+                    table.assign_cell(
+                        || format!("accepting state {state}"),
+                        config.accept_table,
+                        row,
+                        || Value::known(F::from(state)),
+                    )?;
+                }
+                Ok(())
+            },
+        )
+    }
+
+    fn load_private(
+        &self,
+        mut layouter: impl Layouter<F>,
+        automaton: &Automaton,
+        digits: &[Value<F>],
+        states: &[Value<F>],
+    ) -> Result<(), Error> {
+        let config = self.config();
+
+        layouter.assign_region(
+            || "automaton walk",
+            |mut region| {
+                let mut state = region.assign_advice_from_constant(
+                    || "start state",
+                    config.advice[1],
+                    0,
+                    F::from(automaton.start_code()),
+                )?;
+                for (i, (digit, next_state)) in digits.iter().zip(&states[1..]).enumerate() {
+                    config.s_transition.enable(&mut region, i)?;
+
+                    region.assign_advice(
+                        || format!("digit {i}"),
+                        config.advice[0],
+                        i,
+                        || *digit,
+                    )?;
+                    if i > 0 {
+                        state.copy_advice(
+                            || format!("state [{i}]"),
+                            &mut region,
+                            config.advice[1],
+                            i,
+                        )?;
+                    }
+                    state = region.assign_advice(
+                        || format!("state [{}]", i + 1),
+                        config.advice[2],
+                        i,
+                        || *next_state,
+                    )?;
+                }
+
+                let row = digits.len();
+                config.s_accept.enable(&mut region, row)?;
+                if row > 0 {
+                    state.copy_advice(|| "end state", &mut region, config.advice[1], row)?;
+                }
+
+                Ok(())
+            },
+        )
+    }
+}
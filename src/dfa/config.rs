@@ -0,0 +1,20 @@
+use halo2_proofs::plonk::{Advice, Column, Selector, TableColumn};
+
+/// The configuration for the automaton circuit
+#[derive(Debug, Clone)]
+pub struct AutomatonConfig {
+    /// Advice columns of the chip
+    pub advice: [Column<Advice>; 3],
+
+    /// Lookup selector for the transition check
+    pub s_transition: Selector,
+
+    /// Lookup selector for the accepting state check
+    pub s_accept: Selector,
+
+    /// Table columns for the transition lookup: `(state, digit, next state)`
+    pub transition_table: [TableColumn; 3],
+
+    /// Table column for the accepting state lookup
+    pub accept_table: TableColumn,
+}
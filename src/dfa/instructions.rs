@@ -0,0 +1,24 @@
+use halo2_proofs::{
+    arithmetic::Field,
+    circuit::{Chip, Layouter, Value},
+    plonk::Error,
+};
+
+use crate::Automaton;
+
+/// Traits for the chip that walks a deterministic finite automaton
+pub trait AutomatonInstructions<F: Field>: Chip<F> {
+    /// Loads the transition and accepting state lookup tables of an automaton into the circuit.
+    fn load_table(&self, layouter: impl Layouter<F>, automaton: &Automaton) -> Result<(), Error>;
+
+    /// Loads a private digit string to the circuit and constrains it to walk the automaton from its start state to an accepting state.
+    ///
+    /// The `states` are the codes of the states visited by the walk, starting with the start state.
+    fn load_private(
+        &self,
+        layouter: impl Layouter<F>,
+        automaton: &Automaton,
+        digits: &[Value<F>],
+        states: &[Value<F>],
+    ) -> Result<(), Error>;
+}
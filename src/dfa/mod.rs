@@ -0,0 +1,16 @@
+//! The automaton chip that provably checks that a secret digit string is accepted by a public deterministic finite automaton
+//! The module is splitted into four files:
+//! - `automaton.rs` contains the deterministic finite automaton and its text format
+//! - `chip.rs` contains the implementation of the chip that walks the automaton
+//! - `config.rs` contains the configuration for the automaton circuit
+//! - `instructions.rs` contains the instructions interface for the automaton chip
+
+mod automaton;
+mod chip;
+mod config;
+mod instructions;
+
+pub use automaton::*;
+pub use chip::*;
+pub use config::*;
+pub use instructions::*;
@@ -0,0 +1,211 @@
+use std::marker::PhantomData;
+
+use halo2_proofs::{
+    arithmetic::Field,
+    circuit::{AssignedCell, Chip, Layouter, Value},
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, Instance},
+    poly::Rotation,
+};
+
+use crate::{DigitalRootConfig, DigitalRootInstructions, DIGITAL_ROOT_ITERATIONS};
+
+/// The chip that implements the additive persistence instructions
+pub struct DigitalRootChip<F: Field> {
+    config: DigitalRootConfig,
+    _marker: PhantomData<F>,
+}
+
+impl<F: Field> DigitalRootChip<F> {
+    /// Creates a new instance of the digital root chip
+    pub fn construct(config: DigitalRootConfig) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Configures the digital root chip
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        advice: [Column<Advice>; 4],
+        instance: Column<Instance>,
+    ) -> <Self as Chip<F>>::Config {
+        meta.enable_equality(instance);
+        for column in &advice {
+            meta.enable_equality(*column);
+        }
+        let s_multi_digit = meta.selector();
+        let s_persistence = meta.selector();
+
+        meta.create_gate("multi_digit", |meta| {
+            // This gate computes a boolean which is set if and only if the number differs from its last digit,
+            // with an is-zero gadget on the difference
+            // Here is the arrangement of the cells of the gate
+            //
+            // | a0     | a1         | a2  | a3          | s_multi_digit |
+            // |--------|------------|-----|-------------|---------------|
+            // | number | last_digit | inv | multi_digit | s_multi_digit |
+            //
+            let number = meta.query_advice(advice[0], Rotation::cur());
+            let last_digit = meta.query_advice(advice[1], Rotation::cur());
+            let inverse = meta.query_advice(advice[2], Rotation::cur());
+            let multi_digit = meta.query_advice(advice[3], Rotation::cur());
+            let s_multi_digit = meta.query_selector(s_multi_digit);
+
+            let difference = number - last_digit;
+
+            vec![
+                s_multi_digit.clone() * (difference.clone() * inverse - multi_digit.clone()),
+                s_multi_digit * difference * (Expression::Constant(F::ONE) - multi_digit),
+            ]
+        });
+
+        meta.create_gate("persistence", |meta| {
+            // This gate sums the multiple digits booleans of the iterated digit sums
+            // Here is the arrangement of the cells of the gate
+            //
+            // | a0           | a1           | a2           | a3          | s_persistence |
+            // |--------------|--------------|--------------|-------------|---------------|
+            // | multi_digit0 | multi_digit1 | multi_digit2 | persistence | s_persistence |
+            //
+            let multi_digits = (0..DIGITAL_ROOT_ITERATIONS)
+                .map(|i| meta.query_advice(advice[i], Rotation::cur()))
+                .collect::<Vec<_>>();
+            let persistence = meta.query_advice(advice[DIGITAL_ROOT_ITERATIONS], Rotation::cur());
+            let s_persistence = meta.query_selector(s_persistence);
+
+            let sum = multi_digits
+                .into_iter()
+                .fold(Expression::Constant(F::ZERO), |sum, multi_digit| {
+                    sum + multi_digit
+                });
+
+            vec![s_persistence * (sum - persistence)]
+        });
+
+        DigitalRootConfig {
+            advice,
+            instance,
+            s_multi_digit,
+            s_persistence,
+        }
+    }
+}
+
+impl<F: Field> Chip<F> for DigitalRootChip<F> {
+    type Config = DigitalRootConfig;
+    type Loaded = ();
+
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+/// A number represented in the digital root chip
+#[derive(Clone, Debug)]
+pub struct DigitalRootNumber<F: Field>(AssignedCell<F, F>);
+
+impl<F: Field> DigitalRootInstructions<F> for DigitalRootChip<F> {
+    type Num = DigitalRootNumber<F>;
+
+    fn is_multi_digit(
+        &self,
+        mut layouter: impl Layouter<F>,
+        number: &AssignedCell<F, F>,
+        last_digit: &AssignedCell<F, F>,
+    ) -> Result<Self::Num, Error> {
+        let config = self.config();
+
+        layouter
+            .assign_region(
+                || "multi digit",
+                |mut region| {
+                    config.s_multi_digit.enable(&mut region, 0)?;
+
+                    let number =
+                        number.copy_advice(|| "number", &mut region, config.advice[0], 0)?;
+                    let last_digit = last_digit.copy_advice(
+                        || "last digit",
+                        &mut region,
+                        config.advice[1],
+                        0,
+                    )?;
+                    let difference = number.value().copied() - last_digit.value();
+                    region.assign_advice(
+                        || "inverse",
+                        config.advice[2],
+                        0,
+                        || difference.map(|d| d.invert().unwrap_or(F::ZERO)),
+                    )?;
+                    region.assign_advice(
+                        || "multi digit",
+                        config.advice[3],
+                        0,
+                        || difference.map(|d| if d == F::ZERO { F::ZERO } else { F::ONE }),
+                    )
+                },
+            )
+            .map(DigitalRootNumber)
+    }
+
+    fn persistence(
+        &self,
+        mut layouter: impl Layouter<F>,
+        multi_digits: [Self::Num; DIGITAL_ROOT_ITERATIONS],
+    ) -> Result<Self::Num, Error> {
+        let config = self.config();
+
+        layouter
+            .assign_region(
+                || "persistence",
+                |mut region| {
+                    config.s_persistence.enable(&mut region, 0)?;
+
+                    let mut persistence = Value::known(F::ZERO);
+                    for (i, multi_digit) in multi_digits.iter().enumerate() {
+                        let multi_digit = multi_digit.0.copy_advice(
+                            || format!("multi digit {i}"),
+                            &mut region,
+                            config.advice[i],
+                            0,
+                        )?;
+                        persistence = persistence + multi_digit.value();
+                    }
+                    region.assign_advice(
+                        || "persistence",
+                        config.advice[DIGITAL_ROOT_ITERATIONS],
+                        0,
+                        || persistence,
+                    )
+                },
+            )
+            .map(DigitalRootNumber)
+    }
+
+    fn constrain_equal(
+        &self,
+        mut layouter: impl Layouter<F>,
+        lhs: &AssignedCell<F, F>,
+        rhs: &AssignedCell<F, F>,
+    ) -> Result<(), Error> {
+        layouter.assign_region(
+            || "equal numbers",
+            |mut region| region.constrain_equal(lhs.cell(), rhs.cell()),
+        )
+    }
+
+    fn expose_public(
+        &self,
+        mut layouter: impl Layouter<F>,
+        num: Self::Num,
+        row: usize,
+    ) -> Result<(), Error> {
+        let config = self.config();
+
+        layouter.constrain_instance(num.0.cell(), config.instance, row)
+    }
+}
@@ -0,0 +1,17 @@
+use halo2_proofs::plonk::{Advice, Column, Instance, Selector};
+
+/// The configuration for the digital root circuit
+#[derive(Debug, Clone)]
+pub struct DigitalRootConfig {
+    /// Advice columns of the chip
+    pub advice: [Column<Advice>; 4],
+
+    /// Instance column of the chip
+    pub instance: Column<Instance>,
+
+    /// Multiple digits check selector of the chip
+    pub s_multi_digit: Selector,
+
+    /// Persistence selector of the chip
+    pub s_persistence: Selector,
+}
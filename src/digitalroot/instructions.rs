@@ -0,0 +1,44 @@
+use halo2_proofs::{
+    arithmetic::Field,
+    circuit::{AssignedCell, Chip, Layouter},
+    plonk::Error,
+};
+
+use crate::DIGITAL_ROOT_ITERATIONS;
+
+/// Traits for the chip that computes the additive persistence of a number
+pub trait DigitalRootInstructions<F: Field>: Chip<F> {
+    /// The number type associated to the digital root instruction
+    type Num;
+
+    /// Computes a boolean which is set if and only if the number has more than one significant digit, given its last digit.
+    fn is_multi_digit(
+        &self,
+        layouter: impl Layouter<F>,
+        number: &AssignedCell<F, F>,
+        last_digit: &AssignedCell<F, F>,
+    ) -> Result<Self::Num, Error>;
+
+    /// Computes the additive persistence as the number of iterated digit sums applied to a number with more than one digit.
+    fn persistence(
+        &self,
+        layouter: impl Layouter<F>,
+        multi_digits: [Self::Num; DIGITAL_ROOT_ITERATIONS],
+    ) -> Result<Self::Num, Error>;
+
+    /// Constrains two cells to be equal.
+    fn constrain_equal(
+        &self,
+        layouter: impl Layouter<F>,
+        lhs: &AssignedCell<F, F>,
+        rhs: &AssignedCell<F, F>,
+    ) -> Result<(), Error>;
+
+    /// Exposes a number as a public input to the circuit.
+    fn expose_public(
+        &self,
+        layouter: impl Layouter<F>,
+        num: Self::Num,
+        row: usize,
+    ) -> Result<(), Error>;
+}
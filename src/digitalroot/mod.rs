@@ -0,0 +1,18 @@
+//! The digital root chip that provably counts the digit sum iterations needed to reach a single digit
+//! The module is splitted into three files:
+//! - `chip.rs` contains the implementation of the chip that computes the additive persistence
+//! - `config.rs` contains the configuration for the digital root circuit
+//! - `instructions.rs` contains the instructions interface for the digital root chip
+
+mod chip;
+mod config;
+mod instructions;
+
+pub use chip::*;
+pub use config::*;
+pub use instructions::*;
+
+/// The number of digit sum iterations which always reach a single digit for a number with `NUMBER_LENGTH` digits
+///
+/// The digit sum of such a number is at most `72`, whose digit sum is at most `15`, whose digit sum is a single digit.
+pub const DIGITAL_ROOT_ITERATIONS: usize = 3;
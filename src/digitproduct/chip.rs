@@ -0,0 +1,318 @@
+use std::marker::PhantomData;
+
+use halo2_proofs::{
+    arithmetic::Field,
+    circuit::{AssignedCell, Chip, Layouter, Region, Value},
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, Fixed, Instance, TableColumn},
+    poly::Rotation,
+};
+
+use crate::{load_digit_table, DigitProductConfig, DigitSumDigits, DigitSumInstructions};
+
+/// The chip that implements the digit product computation instructions
+///
+/// The digits of the fixed length number are the factors of the product, except its leading zeros when the number
+/// is loaded with a count of leading zeros, which are then constrained to the constant `0` (e.g. the digit product
+/// of `00001234` is `0`, or `24` with 4 leading zeros).
+pub struct DigitProductChip<F: Field> {
+    config: DigitProductConfig,
+    _marker: PhantomData<F>,
+}
+
+impl<F: Field> DigitProductChip<F> {
+    /// Creates a new instance of the digit product chip
+    pub fn construct(config: DigitProductConfig) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Configures the digit product chip
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        advice: [Column<Advice>; 3],
+        instance: Column<Instance>,
+        constant: Column<Fixed>,
+        digit_table: TableColumn,
+    ) -> <Self as Chip<F>>::Config {
+        meta.enable_constant(constant);
+        meta.enable_equality(instance);
+        for column in &advice {
+            meta.enable_equality(*column);
+        }
+        let s_first = meta.selector();
+        let s_product = meta.selector();
+        let s_non_zero = meta.selector();
+        let s_lookup = meta.complex_selector();
+
+        // When s_lookup is enabled, the lookup constrains digit values to {0..9}.
+        // When s_lookup is disabled, the expression evaluates to 0 which is in the table.
+        meta.lookup(|meta| {
+            let s_lookup = meta.query_selector(s_lookup);
+            let digit = meta.query_advice(advice[0], Rotation::cur());
+            vec![(s_lookup * digit, digit_table)]
+        });
+
+        meta.create_gate("digit_product_first", |meta| {
+            // This gate starts the product of the digits with the first digit
+            // Here is the arrangement of the cells of the gate
+            //
+            // | a0  | a1 | a2    | s_first | s_lookup |
+            // |-----|----|-------|---------|----------|
+            // | in0 |    | prod0 | s_first | s_lookup |
+            //
+            let input = meta.query_advice(advice[0], Rotation::cur());
+            let output = meta.query_advice(advice[2], Rotation::cur());
+            let s_first = meta.query_selector(s_first);
+
+            vec![s_first * (input - output)]
+        });
+
+        meta.create_gate("digit_product", |meta| {
+            // This gate implements the product of the digits of the provided number in decimal representation
+            // Here is the arrangement of the cells of the gate, the first row being constrained by the
+            // `digit_product_first` gate
+            //
+            // | a0  | a1    | a2    | s_product | s_lookup |
+            // |-----|-------|-------|-----------|----------|
+            // | in0 |       | prod0 |           | s_lookup |
+            // | in1 | prod0 | prod1 | s_product | s_lookup |
+            // | ... | ...   | ...   | s_product | s_lookup |
+            // | in7 | prod6 | prod7 | s_product | s_lookup |
+            //
+            let input = meta.query_advice(advice[0], Rotation::cur());
+            let accumulator = meta.query_advice(advice[1], Rotation::cur());
+            let output = meta.query_advice(advice[2], Rotation::cur());
+            let s_product = meta.query_selector(s_product);
+
+            vec![s_product * (input * accumulator - output)]
+        });
+
+        meta.create_gate("non_zero", |meta| {
+            // This gate constrains a digit to be non-zero with its inverse
+            // Here is the arrangement of the cells of the gate
+            //
+            // | a0    | a1      | s_non_zero |
+            // |-------|---------|------------|
+            // | digit | inverse | s_non_zero |
+            //
+            let digit = meta.query_advice(advice[0], Rotation::cur());
+            let inverse = meta.query_advice(advice[1], Rotation::cur());
+            let s_non_zero = meta.query_selector(s_non_zero);
+
+            vec![s_non_zero * (digit * inverse - Expression::Constant(F::ONE))]
+        });
+
+        DigitProductConfig {
+            advice,
+            instance,
+            s_first,
+            s_product,
+            s_non_zero,
+            s_lookup,
+            digit_table,
+        }
+    }
+}
+
+impl<F: Field> DigitProductChip<F> {
+    /// Loads a private number whose first digits are leading zeros, which are constrained to be zero and are not
+    /// factors of the product, and returns its digits along with the prefix products of the other digits
+    pub fn load_digits_with_leading_zeros(
+        &self,
+        mut layouter: impl Layouter<F>,
+        values: &[Value<F>],
+        leading_zeros: usize,
+    ) -> Result<DigitSumDigits<DigitProductNumber<F>>, Error> {
+        let config = self.config();
+        if leading_zeros >= values.len() {
+            return Err(Error::Synthesis);
+        }
+
+        layouter.assign_region(
+            || "digits product",
+            |mut region| {
+                // First advice column of each row is the witness digit
+                let digits = values
+                    .iter()
+                    .enumerate()
+                    .map(|(i, value)| {
+                        region.assign_advice(
+                            || format!("witness {i}"),
+                            config.advice[0],
+                            i,
+                            || *value,
+                        )
+                    })
+                    .collect::<Result<Vec<_>, Error>>()?;
+
+                self.assign_prefix_products(&mut region, digits, leading_zeros)
+            },
+        )
+    }
+
+    /// Assigns the prefix products of the digits after the leading zeros once they are assigned in the first advice
+    /// column, constrains the leading zeros to be zero, and range checks the digits
+    fn assign_prefix_products(
+        &self,
+        region: &mut Region<'_, F>,
+        digits: Vec<AssignedCell<F, F>>,
+        leading_zeros: usize,
+    ) -> Result<DigitSumDigits<DigitProductNumber<F>>, Error> {
+        let config = self.config();
+
+        let mut prefix_products: Vec<AssignedCell<F, F>> = Vec::with_capacity(digits.len());
+        for (i, digit) in digits.iter().enumerate() {
+            config.s_lookup.enable(region, i)?;
+            if i < leading_zeros {
+                region.constrain_constant(digit.cell(), F::ZERO)?;
+                continue;
+            }
+
+            // Second advice column of ith row is the product of the first i-1 digits, and the third one is the
+            // product of the first i digits
+            let prefix_product = match prefix_products.last() {
+                None => {
+                    config.s_first.enable(region, i)?;
+                    digit.value().copied()
+                }
+                Some(previous_value) => {
+                    config.s_product.enable(region, i)?;
+                    previous_value.copy_advice(
+                        || format!("digit product [{}]", i - 1),
+                        region,
+                        config.advice[1],
+                        i,
+                    )?;
+                    previous_value.value().copied() * digit.value()
+                }
+            };
+            prefix_products.push(region.assign_advice(
+                || format!("digit product [{i}]"),
+                config.advice[2],
+                i,
+                || prefix_product,
+            )?);
+        }
+
+        Ok(DigitSumDigits {
+            digits: digits.into_iter().map(DigitProductNumber).collect(),
+            prefix_sums: prefix_products
+                .into_iter()
+                .map(DigitProductNumber)
+                .collect(),
+        })
+    }
+}
+
+impl<F: Field> Chip<F> for DigitProductChip<F> {
+    type Config = DigitProductConfig;
+    type Loaded = ();
+
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+/// A number represented in the digit product chip
+#[derive(Clone, Debug)]
+pub struct DigitProductNumber<F: Field>(AssignedCell<F, F>);
+
+impl<F: Field> DigitSumInstructions<F> for DigitProductChip<F> {
+    type Num = DigitProductNumber<F>;
+
+    fn load_table(&self, layouter: impl Layouter<F>) -> Result<(), Error> {
+        let config = self.config();
+
+        load_digit_table(layouter, config.digit_table)
+    }
+
+    fn load_digits_with_prefix_sums(
+        &self,
+        layouter: impl Layouter<F>,
+        values: &[Value<F>],
+    ) -> Result<DigitSumDigits<Self::Num>, Error> {
+        self.load_digits_with_leading_zeros(layouter, values, 0)
+    }
+
+    fn sum_assigned_digits(
+        &self,
+        mut layouter: impl Layouter<F>,
+        digits: &[AssignedCell<F, F>],
+    ) -> Result<DigitSumDigits<Self::Num>, Error> {
+        let config = self.config();
+        if digits.is_empty() {
+            return Err(Error::Synthesis);
+        }
+
+        layouter.assign_region(
+            || "assigned digits product",
+            |mut region| {
+                let digits = digits
+                    .iter()
+                    .enumerate()
+                    .map(|(i, digit)| {
+                        digit.copy_advice(|| format!("digit {i}"), &mut region, config.advice[0], i)
+                    })
+                    .collect::<Result<Vec<_>, Error>>()?;
+
+                self.assign_prefix_products(&mut region, digits, 0)
+            },
+        )
+    }
+
+    fn constrain_non_zero(
+        &self,
+        mut layouter: impl Layouter<F>,
+        digit: &Self::Num,
+    ) -> Result<(), Error> {
+        let config = self.config();
+
+        layouter.assign_region(
+            || "non-zero digit",
+            |mut region| {
+                config.s_non_zero.enable(&mut region, 0)?;
+
+                let digit = digit
+                    .0
+                    .copy_advice(|| "digit", &mut region, config.advice[0], 0)?;
+                region.assign_advice(
+                    || "inverse",
+                    config.advice[1],
+                    0,
+                    || digit.value().map(|d| d.invert().unwrap_or(F::ZERO)),
+                )?;
+
+                Ok(())
+            },
+        )
+    }
+
+    fn constrain_equal(
+        &self,
+        mut layouter: impl Layouter<F>,
+        lhs: &Self::Num,
+        rhs: &Self::Num,
+    ) -> Result<(), Error> {
+        layouter.assign_region(
+            || "equal digit products",
+            |mut region| region.constrain_equal(lhs.0.cell(), rhs.0.cell()),
+        )
+    }
+
+    fn expose_public(
+        &self,
+        mut layouter: impl Layouter<F>,
+        num: Self::Num,
+        row: usize,
+    ) -> Result<(), Error> {
+        let config = self.config();
+
+        layouter.constrain_instance(num.0.cell(), config.instance, row)
+    }
+}
@@ -0,0 +1,26 @@
+use halo2_proofs::plonk::{Advice, Column, Instance, Selector, TableColumn};
+
+/// The configuration for the digit product circuit
+#[derive(Debug, Clone)]
+pub struct DigitProductConfig {
+    /// Advice columns of the chip
+    pub advice: [Column<Advice>; 3],
+
+    /// Instance column of the chip
+    pub instance: Column<Instance>,
+
+    /// First digit selector of the chip, which starts the digit product
+    pub s_first: Selector,
+
+    /// Product selector of the chip
+    pub s_product: Selector,
+
+    /// Non-zero selector of the chip
+    pub s_non_zero: Selector,
+
+    /// Lookup selector for the digit range check
+    pub s_lookup: Selector,
+
+    /// Table column for the digit range check lookup
+    pub digit_table: TableColumn,
+}
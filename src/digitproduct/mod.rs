@@ -0,0 +1,10 @@
+//! The digit product chip that provably computes the product of the decimal digits of a fixed length number
+//! The module is splitted into two files (the instructions interface is shared with the digit sum chip):
+//! - `chip.rs` contains the implementation of the chip that computes the digit product
+//! - `config.rs` contains the configuration for the digit product circuit
+
+mod chip;
+mod config;
+
+pub use chip::*;
+pub use config::*;
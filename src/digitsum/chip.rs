@@ -2,15 +2,38 @@ use std::marker::PhantomData;
 
 use halo2_proofs::{
     arithmetic::Field,
-    circuit::{AssignedCell, Chip, Layouter, Value},
-    plonk::{Advice, Column, ConstraintSystem, Error, Instance},
+    circuit::{AssignedCell, Chip, Layouter, Region, Value},
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, Instance, TableColumn},
     poly::Rotation,
 };
 
-use crate::{DigitSumConfig, DigitSumInstructions, NUMBER_LENGTH};
+use crate::{DigitSumConfig, DigitSumDigits, DigitSumInstructions};
 
 /// The upper bound (exclusive) for a valid decimal digit
-const DIGIT_RANGE_UPPER_BOUND: usize = 10;
+pub(crate) const DIGIT_RANGE_UPPER_BOUND: usize = 10;
+
+/// Loads the digit range lookup table (i.e. the values `{0, ..., 9}`) into a table column
+pub(crate) fn load_digit_table<F: Field>(
+    mut layouter: impl Layouter<F>,
+    digit_table: TableColumn,
+) -> Result<(), Error> {
+    layouter.assign_table(
+        || "digit range table",
+        |mut table| {
+            let mut value = F::ZERO;
+            for digit in 0..DIGIT_RANGE_UPPER_BOUND {
+                table.assign_cell(
+                    || format!("digit {digit}"),
+                    digit_table,
+                    digit,
+                    || Value::known(value),
+                )?;
+                value += F::ONE;
+            }
+            Ok(())
+        },
+    )
+}
 
 /// The chip that implements the digit sum computation instructions
 pub struct DigitSumChip<F: Field> {
@@ -32,14 +55,29 @@ impl<F: Field> DigitSumChip<F> {
         meta: &mut ConstraintSystem<F>,
         advice: [Column<Advice>; 3],
         instance: Column<Instance>,
+    ) -> <Self as Chip<F>>::Config {
+        let digit_table = meta.lookup_table_column();
+
+        Self::configure_with_table(meta, advice, instance, digit_table)
+    }
+
+    /// Configures the digit sum chip with a digit range table column shared with other chips
+    ///
+    /// The table must be loaded once in the circuit, e.g. with the `load_table` instruction of one of the chips.
+    pub fn configure_with_table(
+        meta: &mut ConstraintSystem<F>,
+        advice: [Column<Advice>; 3],
+        instance: Column<Instance>,
+        digit_table: TableColumn,
     ) -> <Self as Chip<F>>::Config {
         meta.enable_equality(instance);
         for column in &advice {
             meta.enable_equality(*column);
         }
+        let s_first = meta.selector();
         let s_sum = meta.selector();
+        let s_non_zero = meta.selector();
         let s_lookup = meta.complex_selector();
-        let digit_table = meta.lookup_table_column();
 
         // When s_lookup is enabled, the lookup constrains digit values to {0..9}.
         // When s_lookup is disabled, the expression evaluates to 0 which is in the
@@ -50,13 +88,29 @@ impl<F: Field> DigitSumChip<F> {
             vec![(s_lookup * digit, digit_table)]
         });
 
+        meta.create_gate("digit_sum_first", |meta| {
+            // This gate starts the sum of the digits with the first digit
+            // Here is the arrangement of the cells of the gate
+            //
+            // | a0  | a1 | a2   | s_first | s_lookup |
+            // |-----|----|------|---------|----------|
+            // | in0 |    | sum0 | s_first | s_lookup |
+            //
+            let input = meta.query_advice(advice[0], Rotation::cur());
+            let output = meta.query_advice(advice[2], Rotation::cur());
+            let s_first = meta.query_selector(s_first);
+
+            vec![s_first * (input - output)]
+        });
+
         meta.create_gate("digit_sum", |meta| {
             // This gate implements the sum of the digits of the provided number in decimal representation
-            // Here is the arrangement of the cells of the gate
+            // Here is the arrangement of the cells of the gate, the first row being constrained by the
+            // `digit_sum_first` gate
             //
             // | a0  | a1   | a2   | s_sum | s_lookup |
             // |-----|------|------|-------|----------|
-            // | in0 | 0    | sum0 | s_sum | s_lookup |
+            // | in0 |      | sum0 |       | s_lookup |
             // | in1 | sum0 | sum1 | s_sum | s_lookup |
             // | in2 | sum1 | sum2 | s_sum | s_lookup |
             // | in3 | sum2 | sum3 | s_sum | s_lookup |
@@ -73,10 +127,27 @@ impl<F: Field> DigitSumChip<F> {
             vec![s_sum * (input_lhs + input_rhs - output)]
         });
 
+        meta.create_gate("non_zero", |meta| {
+            // This gate constrains a digit to be non-zero with its inverse
+            // Here is the arrangement of the cells of the gate
+            //
+            // | a0    | a1      | s_non_zero |
+            // |-------|---------|------------|
+            // | digit | inverse | s_non_zero |
+            //
+            let digit = meta.query_advice(advice[0], Rotation::cur());
+            let inverse = meta.query_advice(advice[1], Rotation::cur());
+            let s_non_zero = meta.query_selector(s_non_zero);
+
+            vec![s_non_zero * (digit * inverse - Expression::Constant(F::ONE))]
+        });
+
         DigitSumConfig {
             advice,
             instance,
+            s_first,
             s_sum,
+            s_non_zero,
             s_lookup,
             digit_table,
         }
@@ -100,84 +171,237 @@ impl<F: Field> Chip<F> for DigitSumChip<F> {
 #[derive(Clone, Debug)]
 pub struct DigitSumNumber<F: Field>(AssignedCell<F, F>);
 
-impl<F: Field> DigitSumInstructions<F> for DigitSumChip<F> {
-    type Num = DigitSumNumber<F>;
+impl<F: Field> DigitSumNumber<F> {
+    /// The assigned cell of the number, e.g. to constrain it from another chip
+    pub fn cell(&self) -> &AssignedCell<F, F> {
+        &self.0
+    }
+}
+
+impl<F: Field> From<AssignedCell<F, F>> for DigitSumNumber<F> {
+    fn from(cell: AssignedCell<F, F>) -> Self {
+        Self(cell)
+    }
+}
 
-    fn load_table(&self, mut layouter: impl Layouter<F>) -> Result<(), Error> {
+impl<F: Field> DigitSumChip<F> {
+    /// Assigns the prefix sums of the digits once they are assigned in the first advice column, and range checks
+    /// the digits
+    fn assign_prefix_sums(
+        &self,
+        region: &mut Region<'_, F>,
+        digits: Vec<AssignedCell<F, F>>,
+    ) -> Result<DigitSumDigits<DigitSumNumber<F>>, Error> {
         let config = self.config();
 
-        layouter.assign_table(
-            || "digit range table",
-            |mut table| {
-                let mut value = F::ZERO;
-                for digit in 0..DIGIT_RANGE_UPPER_BOUND {
-                    table.assign_cell(
-                        || format!("digit {digit}"),
-                        config.digit_table,
-                        digit,
-                        || Value::known(value),
+        let mut prefix_sums: Vec<AssignedCell<F, F>> = Vec::with_capacity(digits.len());
+        for (i, digit) in digits.iter().enumerate() {
+            config.s_lookup.enable(region, i)?;
+
+            // Second advice column of ith row is the sum of the first i-1 digits, and the third one is the sum of
+            // the first i digits
+            let prefix_sum = match prefix_sums.last() {
+                None => {
+                    config.s_first.enable(region, i)?;
+                    digit.value().copied()
+                }
+                Some(previous_value) => {
+                    config.s_sum.enable(region, i)?;
+                    previous_value.copy_advice(
+                        || format!("digit sum [{}]", i - 1),
+                        region,
+                        config.advice[1],
+                        i,
                     )?;
-                    value += F::ONE;
+                    previous_value.value().copied() + digit.value()
                 }
-                Ok(())
-            },
-        )
+            };
+            prefix_sums.push(region.assign_advice(
+                || format!("digit sum [{i}]"),
+                config.advice[2],
+                i,
+                || prefix_sum,
+            )?);
+        }
+
+        Ok(DigitSumDigits {
+            digits: digits.into_iter().map(DigitSumNumber).collect(),
+            prefix_sums: prefix_sums.into_iter().map(DigitSumNumber).collect(),
+        })
+    }
+
+    /// Loads a private input of any number of digits to the circuit in a decimal format and returns its digits
+    /// along with their sum
+    pub fn load_digits(
+        &self,
+        layouter: impl Layouter<F>,
+        values: &[Value<F>],
+    ) -> Result<(Vec<DigitSumNumber<F>>, DigitSumNumber<F>), Error> {
+        let digits = self.load_digits_with_prefix_sums(layouter, values)?;
+        let sum = digits.sum().clone();
+
+        Ok((digits.digits, sum))
     }
 
-    fn load_private(
+    /// Sums numbers computed by the chip (e.g. the digit sums of several numbers) into a total
+    ///
+    /// The sum gate is reused without the range check lookup, starting from the first number:
+    ///
+    /// | a0   | a1     | a2     | s_sum |
+    /// |------|--------|--------|-------|
+    /// | num1 | num0   | total1 | s_sum |
+    /// | num2 | total1 | total2 | s_sum |
+    /// | ...  | ...    | ...    | s_sum |
+    pub fn aggregate(
         &self,
         mut layouter: impl Layouter<F>,
-        values: [Value<F>; NUMBER_LENGTH],
-    ) -> Result<Self::Num, Error> {
+        nums: &[DigitSumNumber<F>],
+    ) -> Result<DigitSumNumber<F>, Error> {
         let config = self.config();
+        let Some((first, others)) = nums.split_first() else {
+            return Err(Error::Synthesis);
+        };
+        if others.is_empty() {
+            return Ok(first.clone());
+        }
 
-        layouter
-            .assign_region(
-                || "digits sum",
-                |mut region| {
-                    let mut previous_value = region.assign_advice(
-                        || "zero",
+        layouter.assign_region(
+            || "aggregate digit sums",
+            |mut region| {
+                let mut previous_value = first.0.clone();
+                for (i, num) in others.iter().enumerate() {
+                    config.s_sum.enable(&mut region, i)?;
+
+                    let num = num.0.copy_advice(
+                        || format!("digit sum [{}]", i + 1),
+                        &mut region,
+                        config.advice[0],
+                        i,
+                    )?;
+                    previous_value.copy_advice(
+                        || format!("total [{i}]"),
+                        &mut region,
                         config.advice[1],
-                        0,
-                        || Value::known(F::ZERO),
+                        i,
+                    )?;
+                    previous_value = region.assign_advice(
+                        || format!("total [{}]", i + 1),
+                        config.advice[2],
+                        i,
+                        || previous_value.value().copied() + num.value(),
                     )?;
-                    for (i, value) in values.into_iter().enumerate() {
-                        config.s_sum.enable(&mut region, i)?;
-                        config.s_lookup.enable(&mut region, i)?;
-
-                        // First advice column of ith row is the ith witness digit
-                        region
-                            .assign_advice(
-                                || format!("witness {}", i),
-                                config.advice[0],
-                                i,
-                                || value,
-                            )
-                            .map(DigitSumNumber)?;
-
-                        // Second advice column of ith row is the sum of the first i-1 digits
-                        if i > 0 {
-                            previous_value.copy_advice(
-                                || format!("digit sum[{}]", i - 1),
-                                &mut region,
-                                config.advice[1],
-                                i,
-                            )?;
-                        }
-
-                        // Third advice column of ith row is the sum of the first i digits
-                        previous_value = region.assign_advice(
-                            || format!("digit sum [{i}]"),
-                            config.advice[2],
+                }
+
+                Ok(DigitSumNumber(previous_value))
+            },
+        )
+    }
+}
+
+impl<F: Field> DigitSumInstructions<F> for DigitSumChip<F> {
+    type Num = DigitSumNumber<F>;
+
+    fn load_table(&self, layouter: impl Layouter<F>) -> Result<(), Error> {
+        let config = self.config();
+
+        load_digit_table(layouter, config.digit_table)
+    }
+
+    fn load_digits_with_prefix_sums(
+        &self,
+        mut layouter: impl Layouter<F>,
+        values: &[Value<F>],
+    ) -> Result<DigitSumDigits<Self::Num>, Error> {
+        let config = self.config();
+        if values.is_empty() {
+            return Err(Error::Synthesis);
+        }
+
+        layouter.assign_region(
+            || "digits sum",
+            |mut region| {
+                // First advice column of ith row is the ith witness digit
+                let digits = values
+                    .iter()
+                    .enumerate()
+                    .map(|(i, value)| {
+                        region.assign_advice(
+                            || format!("witness {i}"),
+                            config.advice[0],
                             i,
-                            || value + previous_value.value(),
-                        )?;
-                    }
-
-                    Ok(previous_value)
-                },
-            )
-            .map(DigitSumNumber)
+                            || *value,
+                        )
+                    })
+                    .collect::<Result<Vec<_>, Error>>()?;
+
+                self.assign_prefix_sums(&mut region, digits)
+            },
+        )
+    }
+
+    fn sum_assigned_digits(
+        &self,
+        mut layouter: impl Layouter<F>,
+        digits: &[AssignedCell<F, F>],
+    ) -> Result<DigitSumDigits<Self::Num>, Error> {
+        let config = self.config();
+        if digits.is_empty() {
+            return Err(Error::Synthesis);
+        }
+
+        layouter.assign_region(
+            || "assigned digits sum",
+            |mut region| {
+                let digits = digits
+                    .iter()
+                    .enumerate()
+                    .map(|(i, digit)| {
+                        digit.copy_advice(|| format!("digit {i}"), &mut region, config.advice[0], i)
+                    })
+                    .collect::<Result<Vec<_>, Error>>()?;
+
+                self.assign_prefix_sums(&mut region, digits)
+            },
+        )
+    }
+
+    fn constrain_non_zero(
+        &self,
+        mut layouter: impl Layouter<F>,
+        digit: &Self::Num,
+    ) -> Result<(), Error> {
+        let config = self.config();
+
+        layouter.assign_region(
+            || "non-zero digit",
+            |mut region| {
+                config.s_non_zero.enable(&mut region, 0)?;
+
+                let digit = digit
+                    .0
+                    .copy_advice(|| "digit", &mut region, config.advice[0], 0)?;
+                region.assign_advice(
+                    || "inverse",
+                    config.advice[1],
+                    0,
+                    || digit.value().map(|d| d.invert().unwrap_or(F::ZERO)),
+                )?;
+
+                Ok(())
+            },
+        )
+    }
+
+    fn constrain_equal(
+        &self,
+        mut layouter: impl Layouter<F>,
+        lhs: &Self::Num,
+        rhs: &Self::Num,
+    ) -> Result<(), Error> {
+        layouter.assign_region(
+            || "equal digit sums",
+            |mut region| region.constrain_equal(lhs.0.cell(), rhs.0.cell()),
+        )
     }
 
     fn expose_public(
@@ -9,9 +9,15 @@ pub struct DigitSumConfig {
     /// Instance column of the chip
     pub instance: Column<Instance>,
 
+    /// First digit selector of the chip, which starts the digit sum
+    pub s_first: Selector,
+
     /// Sum selector of the chip
     pub s_sum: Selector,
 
+    /// Non-zero selector of the chip
+    pub s_non_zero: Selector,
+
     /// Lookup selector for the digit range check
     pub s_lookup: Selector,
 
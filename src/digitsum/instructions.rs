@@ -1,15 +1,33 @@
 use halo2_proofs::{
     arithmetic::Field,
-    circuit::{Chip, Layouter, Value},
+    circuit::{AssignedCell, Chip, Layouter, Value},
     plonk::Error,
 };
 
 use crate::NUMBER_LENGTH;
 
-/// Traits for the chip that computes digit sum
+/// The digits of a number loaded by a digit aggregate chip along with their prefix aggregates
+#[derive(Clone, Debug)]
+pub struct DigitSumDigits<N> {
+    /// The digits of the number, starting with the most significant digit
+    pub digits: Vec<N>,
+
+    /// The aggregates of the first digits (e.g. the prefix sums for the digit sum chip), i.e. the `i`th prefix
+    /// aggregate is the aggregate of the digits up to the `i`th one
+    pub prefix_sums: Vec<N>,
+}
+
+impl<N> DigitSumDigits<N> {
+    /// The aggregate of all the digits, i.e. the last prefix aggregate
+    pub fn sum(&self) -> &N {
+        self.prefix_sums.last().unwrap() // safe unwrap as a number has at least one digit
+    }
+}
+
+/// Traits for the chips that compute an aggregate of the digits (e.g. the digit sum or the digit product)
 pub trait DigitSumInstructions<F: Field>: Chip<F> {
-    /// The number type associated to the digit sum instruction
-    type Num;
+    /// The number type associated to the digit aggregate instruction
+    type Num: Clone;
 
     /// Loads the digit range lookup table into the circuit.
     fn load_table(&self, layouter: impl Layouter<F>) -> Result<(), Error>;
@@ -19,7 +37,55 @@ pub trait DigitSumInstructions<F: Field>: Chip<F> {
         &self,
         layouter: impl Layouter<F>,
         values: [Value<F>; NUMBER_LENGTH],
-    ) -> Result<Self::Num, Error>;
+    ) -> Result<Self::Num, Error> {
+        self.load_private_digits(layouter, values)
+            .map(|(_digits, aggregate)| aggregate)
+    }
+
+    /// Loads a private input to the circuit in a decimal format and returns its digits along with their aggregate.
+    fn load_private_digits(
+        &self,
+        layouter: impl Layouter<F>,
+        values: [Value<F>; NUMBER_LENGTH],
+    ) -> Result<([Self::Num; NUMBER_LENGTH], Self::Num), Error> {
+        let digits = self.load_digits_with_prefix_sums(layouter, &values)?;
+        let aggregate = digits.sum().clone();
+        let digits = digits.digits.try_into().map_err(|_| Error::Synthesis)?;
+
+        Ok((digits, aggregate))
+    }
+
+    /// Loads a private input of any number of digits to the circuit in a decimal format and returns its digits
+    /// along with their prefix aggregates.
+    fn load_digits_with_prefix_sums(
+        &self,
+        layouter: impl Layouter<F>,
+        values: &[Value<F>],
+    ) -> Result<DigitSumDigits<Self::Num>, Error>;
+
+    /// Aggregates digits already assigned in the circuit (e.g. by another chip), which are copy constrained into
+    /// the chip and range checked, and returns the copied digits along with their prefix aggregates.
+    fn sum_assigned_digits(
+        &self,
+        layouter: impl Layouter<F>,
+        digits: &[AssignedCell<F, F>],
+    ) -> Result<DigitSumDigits<Self::Num>, Error>;
+
+    /// Constrains a digit loaded by the chip to be non-zero (e.g. the most significant digit of a number, so that
+    /// the number has exactly its count of digits).
+    fn constrain_non_zero(
+        &self,
+        layouter: impl Layouter<F>,
+        digit: &Self::Num,
+    ) -> Result<(), Error>;
+
+    /// Constrains two numbers to be equal.
+    fn constrain_equal(
+        &self,
+        layouter: impl Layouter<F>,
+        lhs: &Self::Num,
+        rhs: &Self::Num,
+    ) -> Result<(), Error>;
 
     /// Exposes a number as a public input to the circuit.
     fn expose_public(
@@ -14,3 +14,6 @@ pub use instructions::*;
 
 /// The number of digits in the input number
 pub const NUMBER_LENGTH: usize = 8;
+
+/// The maximum count of numbers whose digit sums are aggregated by the digit sum circuit
+pub const MAX_AGGREGATE_COUNT: usize = 64;
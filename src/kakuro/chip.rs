@@ -0,0 +1,241 @@
+use std::marker::PhantomData;
+
+use halo2_proofs::{
+    arithmetic::Field,
+    circuit::{AssignedCell, Chip, Layouter, Value},
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, Fixed, Instance, TableColumn},
+    poly::Rotation,
+};
+
+use crate::{KakuroConfig, KakuroInstructions};
+
+/// The chip that implements the kakuro solution instructions
+///
+/// The cells of a run are summed with an accumulator, as the digits of a number in the digit sum chip, and
+/// they are distinct if the difference of each pair of cells has an inverse.
+pub struct KakuroChip<F: Field> {
+    config: KakuroConfig,
+    _marker: PhantomData<F>,
+}
+
+impl<F: Field> KakuroChip<F> {
+    /// Creates a new instance of the kakuro chip
+    pub fn construct(config: KakuroConfig) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Configures the kakuro chip
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        advice: [Column<Advice>; 3],
+        instance: Column<Instance>,
+        constant: Column<Fixed>,
+        digit_table: TableColumn,
+    ) -> <Self as Chip<F>>::Config {
+        meta.enable_equality(instance);
+        meta.enable_constant(constant);
+        for column in &advice {
+            meta.enable_equality(*column);
+        }
+        let s_cell = meta.complex_selector();
+        let s_sum = meta.selector();
+        let s_distinct = meta.selector();
+        let one = || Expression::Constant(F::ONE);
+
+        // When s_cell is enabled, the lookup constrains the cell to {1..10}. When s_cell is disabled,
+        // the expression evaluates to 0 which is in the table.
+        meta.lookup(|meta| {
+            let s_cell = meta.query_selector(s_cell);
+            let digit = meta.query_advice(advice[0], Rotation::cur());
+            vec![(s_cell * (digit - one()), digit_table)]
+        });
+
+        meta.create_gate("run_sum", |meta| {
+            // This gate implements the sum of the cells of a run
+            // Here is the arrangement of the cells of the gate
+            //
+            // | a0    | a1   | a2   | s_sum |
+            // |-------|------|------|-------|
+            // | cell0 | 0    | sum0 | s_sum |
+            // | cell1 | sum0 | sum1 | s_sum |
+            // | ...   | ...  | ...  | s_sum |
+            //
+            let cell = meta.query_advice(advice[0], Rotation::cur());
+            let sum_prev = meta.query_advice(advice[1], Rotation::cur());
+            let sum = meta.query_advice(advice[2], Rotation::cur());
+            let s_sum = meta.query_selector(s_sum);
+
+            vec![s_sum * (cell + sum_prev - sum)]
+        });
+
+        meta.create_gate("distinct", |meta| {
+            // This gate checks that two cells are distinct, i.e. that their difference has an inverse
+            // Here is the arrangement of the cells of the gate
+            //
+            // | a0    | a1    | a2  | s_distinct |
+            // |-------|-------|-----|------------|
+            // | cell0 | cell1 | inv | s_distinct |
+            //
+            let lhs = meta.query_advice(advice[0], Rotation::cur());
+            let rhs = meta.query_advice(advice[1], Rotation::cur());
+            let inverse = meta.query_advice(advice[2], Rotation::cur());
+            let s_distinct = meta.query_selector(s_distinct);
+
+            vec![s_distinct * ((lhs - rhs) * inverse - one())]
+        });
+
+        KakuroConfig {
+            advice,
+            instance,
+            s_cell,
+            s_sum,
+            s_distinct,
+            digit_table,
+        }
+    }
+}
+
+impl<F: Field> Chip<F> for KakuroChip<F> {
+    type Config = KakuroConfig;
+    type Loaded = ();
+
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+/// A number represented in the kakuro chip
+#[derive(Clone, Debug)]
+pub struct KakuroNumber<F: Field>(AssignedCell<F, F>);
+
+impl<F: Field> KakuroInstructions<F> for KakuroChip<F> {
+    type Num = KakuroNumber<F>;
+
+    fn load_cells(
+        &self,
+        mut layouter: impl Layouter<F>,
+        cells: &[Value<F>],
+    ) -> Result<Vec<Self::Num>, Error> {
+        let config = self.config();
+
+        layouter.assign_region(
+            || "cells",
+            |mut region| {
+                cells
+                    .iter()
+                    .enumerate()
+                    .map(|(i, cell)| {
+                        config.s_cell.enable(&mut region, i)?;
+                        region
+                            .assign_advice(|| format!("cell {i}"), config.advice[0], i, || *cell)
+                            .map(KakuroNumber)
+                    })
+                    .collect()
+            },
+        )
+    }
+
+    fn sum_run(
+        &self,
+        mut layouter: impl Layouter<F>,
+        cells: &[&Self::Num],
+    ) -> Result<Self::Num, Error> {
+        let config = self.config();
+
+        layouter.assign_region(
+            || "run sum",
+            |mut region| {
+                let mut previous_value =
+                    region.assign_advice_from_constant(|| "zero", config.advice[1], 0, F::ZERO)?;
+                for (i, cell) in cells.iter().enumerate() {
+                    config.s_sum.enable(&mut region, i)?;
+
+                    let cell = cell.0.copy_advice(
+                        || format!("cell {i}"),
+                        &mut region,
+                        config.advice[0],
+                        i,
+                    )?;
+                    if i > 0 {
+                        previous_value.copy_advice(
+                            || format!("run sum [{}]", i - 1),
+                            &mut region,
+                            config.advice[1],
+                            i,
+                        )?;
+                    }
+                    previous_value = region.assign_advice(
+                        || format!("run sum [{i}]"),
+                        config.advice[2],
+                        i,
+                        || previous_value.value().copied() + cell.value(),
+                    )?;
+                }
+
+                Ok(KakuroNumber(previous_value))
+            },
+        )
+    }
+
+    fn constrain_distinct(
+        &self,
+        mut layouter: impl Layouter<F>,
+        cells: &[&Self::Num],
+    ) -> Result<(), Error> {
+        let config = self.config();
+
+        layouter.assign_region(
+            || "distinct cells",
+            |mut region| {
+                let pairs = (0..cells.len())
+                    .flat_map(|i| (i + 1..cells.len()).map(move |j| (i, j)))
+                    .enumerate();
+                for (row, (i, j)) in pairs {
+                    config.s_distinct.enable(&mut region, row)?;
+
+                    let lhs = cells[i].0.copy_advice(
+                        || format!("cell {i}"),
+                        &mut region,
+                        config.advice[0],
+                        row,
+                    )?;
+                    let rhs = cells[j].0.copy_advice(
+                        || format!("cell {j}"),
+                        &mut region,
+                        config.advice[1],
+                        row,
+                    )?;
+                    region.assign_advice(
+                        || format!("inverse {i} {j}"),
+                        config.advice[2],
+                        row,
+                        || {
+                            (lhs.value().copied() - rhs.value())
+                                .map(|d| d.invert().unwrap_or(F::ZERO))
+                        },
+                    )?;
+                }
+
+                Ok(())
+            },
+        )
+    }
+
+    fn expose_public(
+        &self,
+        mut layouter: impl Layouter<F>,
+        num: Self::Num,
+        row: usize,
+    ) -> Result<(), Error> {
+        let config = self.config();
+
+        layouter.constrain_instance(num.0.cell(), config.instance, row)
+    }
+}
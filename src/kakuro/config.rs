@@ -0,0 +1,23 @@
+use halo2_proofs::plonk::{Advice, Column, Instance, Selector, TableColumn};
+
+/// The configuration for the kakuro circuit
+#[derive(Debug, Clone)]
+pub struct KakuroConfig {
+    /// Advice columns of the chip
+    pub advice: [Column<Advice>; 3],
+
+    /// Instance column of the chip
+    pub instance: Column<Instance>,
+
+    /// Cell selector of the chip (digit range check)
+    pub s_cell: Selector,
+
+    /// Run sum selector of the chip
+    pub s_sum: Selector,
+
+    /// Distinct cells selector of the chip
+    pub s_distinct: Selector,
+
+    /// Table column for the digit range check lookup (shared with the digit sum chip)
+    pub digit_table: TableColumn,
+}
@@ -0,0 +1,39 @@
+use halo2_proofs::{
+    arithmetic::Field,
+    circuit::{Chip, Layouter, Value},
+    plonk::Error,
+};
+
+/// Traits for the chip that checks a kakuro solution
+pub trait KakuroInstructions<F: Field>: Chip<F> {
+    /// The number type associated to the kakuro instruction
+    type Num;
+
+    /// Loads the private cells of a solution to the circuit.
+    ///
+    /// Each cell is constrained to be a digit from `1` to `9`.
+    fn load_cells(
+        &self,
+        layouter: impl Layouter<F>,
+        cells: &[Value<F>],
+    ) -> Result<Vec<Self::Num>, Error>;
+
+    /// Computes the sum of the cells of a run.
+    fn sum_run(&self, layouter: impl Layouter<F>, cells: &[&Self::Num])
+        -> Result<Self::Num, Error>;
+
+    /// Constrains the cells of a run to be distinct.
+    fn constrain_distinct(
+        &self,
+        layouter: impl Layouter<F>,
+        cells: &[&Self::Num],
+    ) -> Result<(), Error>;
+
+    /// Exposes a number as a public input to the circuit.
+    fn expose_public(
+        &self,
+        layouter: impl Layouter<F>,
+        num: Self::Num,
+        row: usize,
+    ) -> Result<(), Error>;
+}
@@ -0,0 +1,19 @@
+//! The kakuro chip that provably checks that a secret grid is a solution of a public kakuro puzzle
+//! The module is splitted into four files:
+//! - `puzzle.rs` contains the kakuro puzzle and its text format
+//! - `chip.rs` contains the implementation of the chip that checks the runs of the solution
+//! - `config.rs` contains the configuration for the kakuro circuit
+//! - `instructions.rs` contains the instructions interface for the kakuro chip
+
+mod chip;
+mod config;
+mod instructions;
+mod puzzle;
+
+pub use chip::*;
+pub use config::*;
+pub use instructions::*;
+pub use puzzle::*;
+
+/// The maximum number of cells of a run of a kakuro puzzle (its digits are distinct)
+pub const KAKURO_RUN_MAX_LENGTH: usize = 9;
@@ -0,0 +1,227 @@
+use std::str::FromStr;
+
+use anyhow::anyhow;
+
+use crate::{StdError, KAKURO_RUN_MAX_LENGTH};
+
+/// A run of a kakuro puzzle: consecutive cells which sum to a clue
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KakuroRun {
+    /// The clue of the run
+    pub sum: u64,
+
+    /// The indexes of the cells of the run
+    pub cells: Vec<usize>,
+}
+
+/// A kakuro puzzle, where the empty cells are represented by `0`
+///
+/// The puzzle is described with a text format made of one line per row of the grid (empty lines and
+/// comments starting with `#` are ignored). Each row contains whitespace separated cells which are either:
+/// - a blocked cell `X`,
+/// - a clue cell `down\across`, where `down` is the sum of the run below the clue and `across` is the sum of
+///   the run at the right of the clue (any of them may be omitted),
+/// - a white cell, either empty `.` or filled with a digit from `1` to `9`.
+///
+/// ```text
+/// # A puzzle
+/// X     4\    10\
+/// \3    .     .
+/// \11   .     .
+/// ```
+///
+/// The white cells are indexed row by row, and the runs are ordered by clue cells row by row, the down
+/// run of a clue cell coming before its across run.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Kakuro {
+    cells: Vec<u64>,
+    runs: Vec<KakuroRun>,
+}
+
+impl Kakuro {
+    /// The white cells of the grid, row by row
+    pub fn cells(&self) -> &[u64] {
+        &self.cells
+    }
+
+    /// The runs of the grid
+    pub fn runs(&self) -> &[KakuroRun] {
+        &self.runs
+    }
+
+    /// Checks if the grid is a solution of a puzzle
+    pub fn solves(&self, puzzle: &Kakuro) -> bool {
+        let keeps_layout = self.runs == puzzle.runs;
+        let has_valid_runs = self.runs.iter().all(|run| {
+            let mut digits = run.cells.iter().map(|i| self.cells[*i]).collect::<Vec<_>>();
+            digits.sort_unstable();
+            digits.dedup();
+            digits.len() == run.cells.len()
+                && digits.iter().all(|digit| (1..=9).contains(digit))
+                && digits.iter().sum::<u64>() == run.sum
+        });
+
+        keeps_layout && has_valid_runs
+    }
+}
+
+/// A cell of the text format of a kakuro puzzle
+enum KakuroCell {
+    Blocked,
+    Clue(Option<u64>, Option<u64>),
+    White(usize),
+}
+
+impl FromStr for Kakuro {
+    type Err = StdError;
+
+    fn from_str(description: &str) -> Result<Self, Self::Err> {
+        let parse_clue = |clue: &str, line_number: usize| match clue {
+            "" => Ok(None),
+            _ => clue
+                .parse::<u64>()
+                .map(Some)
+                .map_err(|_| anyhow!("Line {line_number}: invalid clue '{clue}'")),
+        };
+
+        let mut cells = vec![];
+        let mut grid = vec![];
+        for (line_number, line) in description.lines().enumerate() {
+            let line_number = line_number + 1;
+            let line = line.split('#').next().unwrap_or_default(); // safe unwrap
+            let row = line
+                .split_whitespace()
+                .map(|cell| match cell {
+                    "X" => Ok(KakuroCell::Blocked),
+                    "." | "1" | "2" | "3" | "4" | "5" | "6" | "7" | "8" | "9" => {
+                        cells.push(cell.parse().unwrap_or_default());
+                        Ok(KakuroCell::White(cells.len() - 1))
+                    }
+                    _ => match cell.split_once('\\') {
+                        Some((down, across)) => Ok(KakuroCell::Clue(
+                            parse_clue(down, line_number)?,
+                            parse_clue(across, line_number)?,
+                        )),
+                        None => Err(anyhow!("Line {line_number}: invalid cell '{cell}'")),
+                    },
+                })
+                .collect::<Result<Vec<_>, StdError>>()?;
+            if !row.is_empty() {
+                grid.push(row);
+            }
+        }
+
+        let white_cell = |row: usize, column: usize| match grid.get(row)?.get(column)? {
+            KakuroCell::White(index) => Some(*index),
+            _ => None,
+        };
+        let mut runs = vec![];
+        for (row, line) in grid.iter().enumerate() {
+            for (column, cell) in line.iter().enumerate() {
+                if let KakuroCell::Clue(down, across) = cell {
+                    let down_cells = (row + 1..).map_while(|row| white_cell(row, column));
+                    let across_cells = (column + 1..).map_while(|column| white_cell(row, column));
+                    let clues: [(_, Vec<_>); 2] = [
+                        (*down, down_cells.collect()),
+                        (*across, across_cells.collect()),
+                    ];
+                    for (sum, cells) in clues {
+                        let Some(sum) = sum else { continue };
+                        if cells.is_empty() || cells.len() > KAKURO_RUN_MAX_LENGTH {
+                            return Err(anyhow!(
+                                "Row {} column {}: expected a run of 1 to {KAKURO_RUN_MAX_LENGTH} cells, got {}",
+                                row + 1,
+                                column + 1,
+                                cells.len()
+                            ));
+                        }
+                        runs.push(KakuroRun { sum, cells });
+                    }
+                }
+            }
+        }
+        if runs.is_empty() {
+            return Err(anyhow!("Expected at least one run"));
+        }
+
+        Ok(Self { cells, runs })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PUZZLE: &str = r#"
+        # A puzzle
+        X     4\    10\   X
+        \3    .     .     X
+        \11   .     .     X
+    "#;
+
+    const SOLUTION: &str = r#"
+        X     4\    10\   X
+        \3    1     2     X
+        \11   3     8     X
+    "#;
+
+    #[test]
+    fn kakuro_parses_valid_description() {
+        let puzzle: Kakuro = PUZZLE.parse().unwrap();
+
+        assert_eq!([0, 0, 0, 0], puzzle.cells());
+        assert_eq!(
+            vec![
+                KakuroRun {
+                    sum: 4,
+                    cells: vec![0, 2]
+                },
+                KakuroRun {
+                    sum: 10,
+                    cells: vec![1, 3]
+                },
+                KakuroRun {
+                    sum: 3,
+                    cells: vec![0, 1]
+                },
+                KakuroRun {
+                    sum: 11,
+                    cells: vec![2, 3]
+                },
+            ],
+            puzzle.runs()
+        );
+    }
+
+    #[test]
+    fn kakuro_rejects_invalid_descriptions() {
+        for description in [
+            "",
+            "X .",
+            "\\3 . Y",
+            "\\a . .",
+            "\\3 X .",
+            "\\45 . . . . . . . . . .",
+        ] {
+            assert!(
+                description.parse::<Kakuro>().is_err(),
+                "description '{description}' should be invalid"
+            );
+        }
+    }
+
+    #[test]
+    fn kakuro_checks_solution() {
+        let puzzle: Kakuro = PUZZLE.parse().unwrap();
+        let solution: Kakuro = SOLUTION.parse().unwrap();
+        let repeated_digits: Kakuro = SOLUTION
+            .replace("1     2", "2     1")
+            .replace("3     8", "2     9")
+            .parse()
+            .unwrap();
+
+        assert!(solution.solves(&puzzle));
+        assert!(!puzzle.solves(&puzzle));
+        assert!(!repeated_digits.solves(&puzzle));
+    }
+}
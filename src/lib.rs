@@ -9,19 +9,64 @@
 //!
 //! The development of the circuit is split into two modules:
 //! - `digitsum` contains the implementation of the digit sum circuit.
+//! - `digitproduct` contains the implementation of the digit product chip.
+//! - `age` contains the implementation of the age verification chip for a birth date.
+//! - `anagram` contains the implementation of the chip that checks that two numbers are made of the same digits.
+//! - `armstrong` contains the implementation of the chip that checks that a number is the sum of its digit powers.
+//! - `dfa` contains the implementation of the chip that walks a deterministic finite automaton.
+//! - `kakuro` contains the implementation of the chip that checks the runs of a kakuro solution.
+//! - `merkle` contains the implementation of the chip that computes the root of a Merkle tree from an authentication path.
+//! - `minmax` contains the implementation of the chip that computes the maximum and the minimum digits.
+//! - `modulo` contains the implementation of the chip that computes the remainder of the number by a modulus.
+//! - `occurrence` contains the implementation of the chip that counts the occurrences of the digits.
+//! - `palindrome` contains the implementation of the chip that checks that the digits read the same forwards and backwards.
+//! - `sudoku` contains the implementation of the chip that checks the solution of a sudoku puzzle.
+//! - `digitalroot` contains the implementation of the chip that computes the additive persistence of a number.
 //! - `circuit` contains the final circuit that uses one or more chips to implement the desired proof system.
+//! - `registry` contains the circuit families which can be selected by their name from the command line.
+//! - `statement` contains the parser of the statement language, which describes a claim about the digits of a number.
 //! - `model` contains the public input and secret witness types for the digit sum circuit.
 //! - `command` contains the command line interface for the digit sum circuit.
-//! - `proof_system` contains the proof system implementation for the digit sum circuit.
+//! - `proof_system` contains the proof system abstraction and its implementation with the halo2 backend.
 
+mod age;
+mod anagram;
+mod armstrong;
 mod circuit;
 mod commands;
+mod dfa;
+mod digitalroot;
+mod digitproduct;
 mod digitsum;
+mod kakuro;
+mod merkle;
+mod minmax;
 mod model;
+mod modulo;
+mod occurrence;
+mod palindrome;
 mod proof_system;
+mod registry;
+mod statement;
+mod sudoku;
 
+pub use age::*;
+pub use anagram::*;
+pub use armstrong::*;
 pub use circuit::*;
 pub use commands::*;
+pub use dfa::*;
+pub use digitalroot::*;
+pub use digitproduct::*;
 pub use digitsum::*;
+pub use kakuro::*;
+pub use merkle::*;
+pub use minmax::*;
 pub use model::*;
+pub use modulo::*;
+pub use occurrence::*;
+pub use palindrome::*;
 pub use proof_system::*;
+pub use registry::*;
+pub use statement::*;
+pub use sudoku::*;
@@ -0,0 +1,200 @@
+use halo2_gadgets::poseidon::{
+    primitives::{ConstantLength, P128Pow5T3},
+    Hash, Pow5Chip, Pow5Config,
+};
+use halo2_proofs::{
+    circuit::{AssignedCell, Chip, Layouter, Value},
+    pasta::Fp,
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, Fixed, Instance},
+    poly::Rotation,
+};
+
+use crate::{MerkleConfig, MerkleInstructions, MERKLE_DEPTH};
+
+/// The width of the state of the Poseidon permutation
+const POSEIDON_WIDTH: usize = 3;
+
+/// The rate of the Poseidon sponge, i.e. the count of field elements absorbed by each permutation
+const POSEIDON_RATE: usize = 2;
+
+/// The configuration of the Poseidon chip of `halo2_gadgets` which hashes two field elements
+pub type PoseidonConfig = Pow5Config<Fp, POSEIDON_WIDTH, POSEIDON_RATE>;
+
+/// Configures the Poseidon chip of `halo2_gadgets` with the `P128Pow5T3` parameters
+///
+/// The first three advice columns hold the state of the permutation and the last one the partial S-box, and the
+/// round constants are loaded in six new fixed columns.
+pub fn configure_poseidon(
+    meta: &mut ConstraintSystem<Fp>,
+    advice: [Column<Advice>; POSEIDON_WIDTH + 1],
+) -> PoseidonConfig {
+    let round_constants_a = [(); POSEIDON_WIDTH].map(|_| meta.fixed_column());
+    let round_constants_b = [(); POSEIDON_WIDTH].map(|_| meta.fixed_column());
+
+    Pow5Chip::configure::<P128Pow5T3>(
+        meta,
+        advice[..POSEIDON_WIDTH].try_into().unwrap(),
+        advice[POSEIDON_WIDTH],
+        round_constants_a,
+        round_constants_b,
+    )
+}
+
+/// Computes the Poseidon hash of two field elements already assigned in the circuit, with the `P128Pow5T3`
+/// parameters and the constant length domain of `halo2_gadgets` (see [crate::poseidon_hash])
+pub fn hash_poseidon(
+    config: &PoseidonConfig,
+    mut layouter: impl Layouter<Fp>,
+    inputs: [&AssignedCell<Fp, Fp>; 2],
+) -> Result<AssignedCell<Fp, Fp>, Error> {
+    let chip = Pow5Chip::construct(config.clone());
+    let hasher = Hash::<_, _, P128Pow5T3, ConstantLength<2>, POSEIDON_WIDTH, POSEIDON_RATE>::init(
+        chip,
+        layouter.namespace(|| "init"),
+    )?;
+
+    hasher.hash(layouter.namespace(|| "hash"), inputs.map(Clone::clone))
+}
+
+/// The chip that implements the Merkle root instructions
+///
+/// At each level of the tree, the current node and its sibling are swapped according to the position bit,
+/// then hashed with the Poseidon chip into the parent node.
+pub struct MerkleChip {
+    config: MerkleConfig,
+}
+
+impl MerkleChip {
+    /// Creates a new instance of the Merkle chip
+    pub fn construct(config: MerkleConfig) -> Self {
+        Self { config }
+    }
+
+    /// Configures the Merkle chip
+    ///
+    /// The swap gate uses the first three advice columns, and the Poseidon chip the four of them.
+    pub fn configure(
+        meta: &mut ConstraintSystem<Fp>,
+        advice: [Column<Advice>; 4],
+        instance: Column<Instance>,
+        constant: Column<Fixed>,
+    ) -> <Self as Chip<Fp>>::Config {
+        meta.enable_equality(instance);
+        meta.enable_constant(constant);
+        let poseidon = configure_poseidon(meta, advice);
+        let advice: [Column<Advice>; 3] = advice[..3].try_into().unwrap();
+        let s_swap = meta.selector();
+        let one = || Expression::Constant(Fp::one());
+
+        meta.create_gate("swap", |meta| {
+            // This gate orders the current node and its sibling into the left and right children of the parent node:
+            // the children are swapped if the position bit is `1` (i.e. the current node is a right child)
+            // Here is the arrangement of the cells of the gate
+            //
+            // | a0   | a1      | a2  | s_swap |
+            // |------|---------|-----|--------|
+            // | node | sibling | bit | s_swap |
+            // | left | right   |     |        |
+            //
+            let node = meta.query_advice(advice[0], Rotation::cur());
+            let sibling = meta.query_advice(advice[1], Rotation::cur());
+            let bit = meta.query_advice(advice[2], Rotation::cur());
+            let left = meta.query_advice(advice[0], Rotation::next());
+            let right = meta.query_advice(advice[1], Rotation::next());
+            let s_swap = meta.query_selector(s_swap);
+
+            vec![
+                s_swap.clone() * bit.clone() * (one() - bit.clone()),
+                s_swap.clone()
+                    * (node.clone() + bit.clone() * (sibling.clone() - node.clone()) - left),
+                s_swap * (sibling.clone() + bit * (node - sibling) - right),
+            ]
+        });
+
+        MerkleConfig {
+            advice,
+            instance,
+            s_swap,
+            poseidon,
+        }
+    }
+}
+
+impl Chip<Fp> for MerkleChip {
+    type Config = MerkleConfig;
+    type Loaded = ();
+
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+/// A number represented in the Merkle chip, i.e. a node of the tree
+#[derive(Clone, Debug)]
+pub struct MerkleNumber(AssignedCell<Fp, Fp>);
+
+impl MerkleNumber {
+    /// The assigned cell of the number, e.g. to constrain it from another chip
+    pub fn cell(&self) -> &AssignedCell<Fp, Fp> {
+        &self.0
+    }
+}
+
+impl MerkleInstructions<Fp> for MerkleChip {
+    type Num = MerkleNumber;
+
+    fn compute_root(
+        &self,
+        mut layouter: impl Layouter<Fp>,
+        leaf: &AssignedCell<Fp, Fp>,
+        siblings: [Value<Fp>; MERKLE_DEPTH],
+        position_bits: [Value<Fp>; MERKLE_DEPTH],
+    ) -> Result<Self::Num, Error> {
+        let config = self.config();
+
+        let mut node = leaf.clone();
+        for (level, (sibling, bit)) in siblings.into_iter().zip(position_bits).enumerate() {
+            let children = layouter.assign_region(
+                || format!("swap level {level}"),
+                |mut region| {
+                    config.s_swap.enable(&mut region, 0)?;
+
+                    let node = node.copy_advice(|| "node", &mut region, config.advice[0], 0)?;
+                    region.assign_advice(|| "sibling", config.advice[1], 0, || sibling)?;
+                    region.assign_advice(|| "position bit", config.advice[2], 0, || bit)?;
+
+                    let node = node.value().copied();
+                    let left = node + bit * (sibling - node);
+                    let right = sibling + bit * (node - sibling);
+
+                    Ok([
+                        region.assign_advice(|| "left", config.advice[0], 1, || left)?,
+                        region.assign_advice(|| "right", config.advice[1], 1, || right)?,
+                    ])
+                },
+            )?;
+            node = hash_poseidon(
+                &config.poseidon,
+                layouter.namespace(|| format!("hash level {level}")),
+                [&children[0], &children[1]],
+            )?;
+        }
+
+        Ok(MerkleNumber(node))
+    }
+
+    fn expose_public(
+        &self,
+        mut layouter: impl Layouter<Fp>,
+        num: Self::Num,
+        row: usize,
+    ) -> Result<(), Error> {
+        let config = self.config();
+
+        layouter.constrain_instance(num.0.cell(), config.instance, row)
+    }
+}
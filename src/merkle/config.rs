@@ -0,0 +1,19 @@
+use halo2_proofs::plonk::{Advice, Column, Instance, Selector};
+
+use crate::PoseidonConfig;
+
+/// The configuration for the Merkle circuit
+#[derive(Debug, Clone)]
+pub struct MerkleConfig {
+    /// Advice columns of the chip
+    pub advice: [Column<Advice>; 3],
+
+    /// Instance column of the chip
+    pub instance: Column<Instance>,
+
+    /// Swap selector of the chip
+    pub s_swap: Selector,
+
+    /// Configuration of the Poseidon chip of `halo2_gadgets` which hashes the nodes of the tree
+    pub poseidon: PoseidonConfig,
+}
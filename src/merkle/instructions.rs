@@ -0,0 +1,31 @@
+use halo2_proofs::{
+    arithmetic::Field,
+    circuit::{AssignedCell, Chip, Layouter, Value},
+    plonk::Error,
+};
+
+use crate::MERKLE_DEPTH;
+
+/// Traits for the chip that computes the root of a Merkle tree
+pub trait MerkleInstructions<F: Field>: Chip<F> {
+    /// The number type associated to the Merkle instruction
+    type Num;
+
+    /// Computes the root of the tree from a leaf already assigned in the circuit (e.g. by another chip) and
+    /// from the private siblings and position bits (`1` if the node is a right child) of its authentication path.
+    fn compute_root(
+        &self,
+        layouter: impl Layouter<F>,
+        leaf: &AssignedCell<F, F>,
+        siblings: [Value<F>; MERKLE_DEPTH],
+        position_bits: [Value<F>; MERKLE_DEPTH],
+    ) -> Result<Self::Num, Error>;
+
+    /// Exposes a number as a public input to the circuit.
+    fn expose_public(
+        &self,
+        layouter: impl Layouter<F>,
+        num: Self::Num,
+        row: usize,
+    ) -> Result<(), Error>;
+}
@@ -0,0 +1,26 @@
+//! The Merkle chip that provably computes the root of a Merkle tree from a leaf and its authentication path
+//! The module is splitted into four files:
+//! - `chip.rs` contains the implementation of the chip that hashes the path up to the root
+//! - `config.rs` contains the configuration for the Merkle circuit
+//! - `instructions.rs` contains the instructions interface for the Merkle chip
+//! - `tree.rs` contains the Merkle tree of an allow-list of numbers and its authentication paths
+//!
+//! The nodes of the tree are hashed with the Poseidon hash of `halo2_gadgets`, with the `P128Pow5T3` parameters and
+//! the constant length domain of two field elements, so that the roots are interoperable with other Poseidon
+//! implementations using the same parameters.
+
+mod chip;
+mod config;
+mod instructions;
+mod tree;
+
+pub use chip::*;
+pub use config::*;
+pub use instructions::*;
+pub use tree::*;
+
+/// The depth of the Merkle tree, i.e. the length of the authentication paths
+pub const MERKLE_DEPTH: usize = 8;
+
+/// The maximum number of leaves of the Merkle tree
+pub const MERKLE_MAX_LEAVES: usize = 1 << MERKLE_DEPTH;
@@ -0,0 +1,254 @@
+use std::{fmt, str::FromStr};
+
+use anyhow::anyhow;
+use halo2_proofs::pasta::Fp;
+
+use crate::{
+    field_from_hex, field_to_hex, poseidon_hash, StdError, StdResult, MERKLE_DEPTH,
+    MERKLE_MAX_LEAVES, NUMBER_LENGTH,
+};
+
+/// The leaf of the empty slots of the tree, which is not the leaf of any number of `NUMBER_LENGTH` digits
+fn padding_leaf() -> Fp {
+    -Fp::one()
+}
+
+/// The root of a Merkle tree
+///
+/// It is represented as the hexadecimal encoding of its field element representation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MerkleRoot(pub Fp);
+
+impl fmt::Display for MerkleRoot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", field_to_hex(&self.0))
+    }
+}
+
+impl FromStr for MerkleRoot {
+    type Err = StdError;
+
+    fn from_str(root: &str) -> Result<Self, Self::Err> {
+        field_from_hex(root).map(Self)
+    }
+}
+
+/// The authentication path of a leaf of a Merkle tree
+///
+/// It is represented as the index of the leaf followed by the hexadecimal encodings of the siblings of the
+/// path from the leaf to the root, separated by whitespaces.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MerklePath {
+    /// The index of the leaf in the tree
+    pub index: usize,
+
+    /// The siblings of the nodes of the path, from the leaf to the root
+    pub siblings: [Fp; MERKLE_DEPTH],
+}
+
+impl MerklePath {
+    /// The position bits of the nodes of the path (`true` if the node is a right child), from the leaf to the root
+    pub fn position_bits(&self) -> [bool; MERKLE_DEPTH] {
+        std::array::from_fn(|level| (self.index >> level) & 1 == 1)
+    }
+
+    /// Computes the root of the tree from the leaf of a number and its authentication path
+    pub fn root(&self, number: u64) -> MerkleRoot {
+        let root = self.siblings.iter().zip(self.position_bits()).fold(
+            Fp::from(number),
+            |node, (sibling, is_right)| {
+                if is_right {
+                    poseidon_hash([*sibling, node])
+                } else {
+                    poseidon_hash([node, *sibling])
+                }
+            },
+        );
+
+        MerkleRoot(root)
+    }
+}
+
+impl fmt::Display for MerklePath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.index)?;
+        self.siblings
+            .iter()
+            .try_for_each(|sibling| write!(f, " {}", field_to_hex(sibling)))
+    }
+}
+
+impl FromStr for MerklePath {
+    type Err = StdError;
+
+    fn from_str(path: &str) -> Result<Self, Self::Err> {
+        let mut tokens = path.split_whitespace();
+        let index = tokens
+            .next()
+            .ok_or_else(|| anyhow!("Invalid Merkle path '{path}', missing index"))?
+            .parse::<usize>()?;
+        if index >= MERKLE_MAX_LEAVES {
+            return Err(anyhow!(
+                "Invalid Merkle path '{path}', index must be less than {MERKLE_MAX_LEAVES}"
+            ));
+        }
+        let siblings: Vec<Fp> = tokens.map(field_from_hex).collect::<StdResult<_>>()?;
+        let siblings = siblings.try_into().map_err(|_| {
+            anyhow!("Invalid Merkle path '{path}', expected {MERKLE_DEPTH} siblings")
+        })?;
+
+        Ok(Self { index, siblings })
+    }
+}
+
+/// The authentication paths of the numbers of an allow-list
+///
+/// It is represented with one line per number, made of the number followed by its authentication path.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MerklePaths {
+    paths: Vec<(u64, MerklePath)>,
+}
+
+impl MerklePaths {
+    /// Gets the authentication path of a number
+    pub fn get(&self, number: u64) -> Option<&MerklePath> {
+        self.paths
+            .iter()
+            .find(|(leaf, _)| *leaf == number)
+            .map(|(_, path)| path)
+    }
+}
+
+impl fmt::Display for MerklePaths {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.paths
+            .iter()
+            .try_for_each(|(number, path)| writeln!(f, "{number} {path}"))
+    }
+}
+
+impl FromStr for MerklePaths {
+    type Err = StdError;
+
+    fn from_str(paths: &str) -> Result<Self, Self::Err> {
+        let paths = paths
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                let (number, path) = line
+                    .trim()
+                    .split_once(char::is_whitespace)
+                    .ok_or_else(|| anyhow!("Invalid Merkle path line '{line}'"))?;
+
+                Ok((number.parse()?, path.parse()?))
+            })
+            .collect::<StdResult<_>>()?;
+
+        Ok(Self { paths })
+    }
+}
+
+/// A Merkle tree of depth `MERKLE_DEPTH` built from an allow-list of numbers
+///
+/// The leaves are the numbers themselves (i.e. the numbers recomposed from their digits in the circuit), and
+/// the empty slots are filled with a padding leaf which is not a valid number.
+#[derive(Clone, Debug)]
+pub struct MerkleTree {
+    numbers: Vec<u64>,
+    levels: Vec<Vec<Fp>>,
+}
+
+impl MerkleTree {
+    /// Builds the Merkle tree of an allow-list of numbers
+    pub fn build(numbers: &[u64]) -> StdResult<Self> {
+        if numbers.is_empty() || numbers.len() > MERKLE_MAX_LEAVES {
+            return Err(anyhow!(
+                "The allow-list must contain between 1 and {MERKLE_MAX_LEAVES} numbers"
+            ));
+        }
+        if let Some(number) = numbers
+            .iter()
+            .find(|number| number.to_string().len() > NUMBER_LENGTH)
+        {
+            return Err(anyhow!(
+                "The number {number} has more than {NUMBER_LENGTH} digits"
+            ));
+        }
+
+        let mut leaves: Vec<Fp> = numbers.iter().map(|number| Fp::from(*number)).collect();
+        leaves.resize(MERKLE_MAX_LEAVES, padding_leaf());
+        let mut levels = vec![leaves];
+        for _ in 0..MERKLE_DEPTH {
+            let parents = levels
+                .last()
+                .unwrap() // safe unwrap
+                .chunks(2)
+                .map(|children| poseidon_hash([children[0], children[1]]))
+                .collect();
+            levels.push(parents);
+        }
+
+        Ok(Self {
+            numbers: numbers.to_vec(),
+            levels,
+        })
+    }
+
+    /// The root of the tree
+    pub fn root(&self) -> MerkleRoot {
+        MerkleRoot(self.levels[MERKLE_DEPTH][0])
+    }
+
+    /// Computes the authentication path of a number, if it is in the allow-list
+    pub fn path(&self, number: u64) -> Option<MerklePath> {
+        let index = self.numbers.iter().position(|leaf| *leaf == number)?;
+        let siblings = std::array::from_fn(|level| self.levels[level][(index >> level) ^ 1]);
+
+        Some(MerklePath { index, siblings })
+    }
+
+    /// Computes the authentication paths of all the numbers of the allow-list
+    pub fn paths(&self) -> MerklePaths {
+        let paths = self
+            .numbers
+            .iter()
+            .filter_map(|number| self.path(*number).map(|path| (*number, path)))
+            .collect();
+
+        MerklePaths { paths }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merkle_paths_lead_to_the_root_of_the_tree() {
+        let tree = MerkleTree::build(&[12345678, 11111111, 42]).unwrap();
+
+        for number in [12345678, 11111111, 42] {
+            let path = tree.path(number).unwrap();
+            assert_eq!(tree.root(), path.root(number));
+            assert_ne!(tree.root(), path.root(number + 1));
+        }
+        assert!(tree.path(87654321).is_none());
+    }
+
+    #[test]
+    fn merkle_tree_rejects_invalid_allow_lists() {
+        assert!(MerkleTree::build(&[]).is_err());
+        assert!(MerkleTree::build(&[123456789]).is_err());
+        assert!(MerkleTree::build(&vec![1; MERKLE_MAX_LEAVES + 1]).is_err());
+    }
+
+    #[test]
+    fn merkle_paths_should_parse_their_display() {
+        let paths = MerkleTree::build(&[12345678, 42]).unwrap().paths();
+
+        assert_eq!(paths, paths.to_string().parse::<MerklePaths>().unwrap());
+        for path in ["", "256", "0 00"] {
+            assert!(path.parse::<MerklePath>().is_err());
+        }
+    }
+}
@@ -0,0 +1,230 @@
+use std::marker::PhantomData;
+
+use halo2_proofs::{
+    circuit::{AssignedCell, Chip, Layouter},
+    pasta::group::ff::PrimeField,
+    plonk::{Advice, Column, ConstraintSystem, Error, Fixed, Instance, Selector, TableColumn},
+    poly::Rotation,
+};
+
+use crate::{MinMaxConfig, MinMaxInstructions};
+
+/// The largest decimal digit, i.e. the initial value of the running minimum
+const MAX_DIGIT: u64 = 9;
+
+/// The chip that implements the maximum and minimum digits instructions
+///
+/// The running maximum `m_i` of the digits is constrained by `m_i - d_i` and `m_i - m_{i-1}` being in the digit
+/// range `[0, 9]` (i.e. `m_i` is not less than both) and by one of them being zero (i.e. `m_i` is one of them).
+/// The running minimum is constrained the same way with the opposite differences.
+pub struct MinMaxChip<F: PrimeField> {
+    config: MinMaxConfig,
+    _marker: PhantomData<F>,
+}
+
+impl<F: PrimeField> MinMaxChip<F> {
+    /// Creates a new instance of the min-max chip
+    pub fn construct(config: MinMaxConfig) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Configures the min-max chip
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        advice: [Column<Advice>; 3],
+        instance: Column<Instance>,
+        constant: Column<Fixed>,
+        digit_table: TableColumn,
+    ) -> <Self as Chip<F>>::Config {
+        meta.enable_equality(instance);
+        meta.enable_constant(constant);
+        for column in &advice {
+            meta.enable_equality(*column);
+        }
+        let s_max = meta.complex_selector();
+        let s_min = meta.complex_selector();
+
+        // Here is the arrangement of the cells of the running comparison
+        //
+        // | a0     | a1     | a2     | s_max | s_min |
+        // |--------|--------|--------|-------|-------|
+        // | digit0 | init   | ext0   | s_max |       |
+        // | digit1 | ext0   | ext1   | s_max |       |
+        // | ...    | ...    | ...    | s_max |       |
+        //
+        // where `init` is `0` for the maximum and `9` for the minimum
+        // The differences are signed by the selectors: they are `ext - value` for the maximum and `value - ext`
+        // for the minimum, and `0` (which is in the table) when both selectors are disabled
+        for column in [advice[0], advice[1]] {
+            meta.lookup(|meta| {
+                let sign = meta.query_selector(s_max) - meta.query_selector(s_min);
+                let value = meta.query_advice(column, Rotation::cur());
+                let extremum = meta.query_advice(advice[2], Rotation::cur());
+                vec![(sign * (extremum - value), digit_table)]
+            });
+        }
+
+        meta.create_gate("running_extremum", |meta| {
+            let selector = meta.query_selector(s_max) + meta.query_selector(s_min);
+            let digit = meta.query_advice(advice[0], Rotation::cur());
+            let previous = meta.query_advice(advice[1], Rotation::cur());
+            let extremum = meta.query_advice(advice[2], Rotation::cur());
+
+            vec![selector * (extremum.clone() - digit) * (extremum - previous)]
+        });
+
+        MinMaxConfig {
+            advice,
+            instance,
+            s_max,
+            s_min,
+            digit_table,
+        }
+    }
+
+    /// Assigns the running comparison of the digits from an initial value
+    fn running_extremum(
+        &self,
+        mut layouter: impl Layouter<F>,
+        digits: &[AssignedCell<F, F>],
+        selector: Selector,
+        initial: u64,
+        compare: fn(u64, u64) -> u64,
+    ) -> Result<MinMaxNumber<F>, Error> {
+        let config = self.config();
+        if digits.is_empty() {
+            return Err(Error::Synthesis);
+        }
+
+        layouter.assign_region(
+            || "running extremum",
+            |mut region| {
+                let mut extremum = region.assign_advice_from_constant(
+                    || "initial extremum",
+                    config.advice[1],
+                    0,
+                    F::from(initial),
+                )?;
+                for (i, digit) in digits.iter().enumerate() {
+                    selector.enable(&mut region, i)?;
+
+                    let digit = digit.copy_advice(
+                        || format!("digit {i}"),
+                        &mut region,
+                        config.advice[0],
+                        i,
+                    )?;
+                    if i > 0 {
+                        extremum.copy_advice(
+                            || format!("extremum [{}]", i - 1),
+                            &mut region,
+                            config.advice[1],
+                            i,
+                        )?;
+                    }
+                    let value = digit
+                        .value()
+                        .zip(extremum.value())
+                        .map(|(digit, extremum)| {
+                            let (digit, extremum) = (to_u64(digit), to_u64(extremum));
+                            F::from(compare(digit, extremum))
+                        });
+                    extremum = region.assign_advice(
+                        || format!("extremum [{i}]"),
+                        config.advice[2],
+                        i,
+                        || value,
+                    )?;
+                }
+
+                Ok(MinMaxNumber(extremum))
+            },
+        )
+    }
+}
+
+/// Converts a digit to a `u64` (`u64::MAX` if the value is not a digit)
+fn to_u64<F: PrimeField>(value: &F) -> u64 {
+    (0..=MAX_DIGIT)
+        .find(|digit| F::from(*digit) == *value)
+        .unwrap_or(u64::MAX)
+}
+
+impl<F: PrimeField> Chip<F> for MinMaxChip<F> {
+    type Config = MinMaxConfig;
+    type Loaded = ();
+
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+/// A number represented in the min-max chip
+#[derive(Clone, Debug)]
+pub struct MinMaxNumber<F: PrimeField>(AssignedCell<F, F>);
+
+impl<F: PrimeField> MinMaxNumber<F> {
+    /// The assigned cell of the number, e.g. to constrain it from another chip
+    pub fn cell(&self) -> &AssignedCell<F, F> {
+        &self.0
+    }
+}
+
+impl<F: PrimeField> MinMaxInstructions<F> for MinMaxChip<F> {
+    type Num = MinMaxNumber<F>;
+
+    fn max_digit(
+        &self,
+        layouter: impl Layouter<F>,
+        digits: &[AssignedCell<F, F>],
+    ) -> Result<Self::Num, Error> {
+        let selector = self.config().s_max;
+
+        self.running_extremum(layouter, digits, selector, 0, u64::max)
+    }
+
+    fn min_digit(
+        &self,
+        layouter: impl Layouter<F>,
+        digits: &[AssignedCell<F, F>],
+    ) -> Result<Self::Num, Error> {
+        let selector = self.config().s_min;
+
+        self.running_extremum(layouter, digits, selector, MAX_DIGIT, u64::min)
+    }
+
+    fn constrain_zero(
+        &self,
+        mut layouter: impl Layouter<F>,
+        digits: &[AssignedCell<F, F>],
+    ) -> Result<(), Error> {
+        layouter.assign_region(
+            || "zero digits",
+            |mut region| {
+                for digit in digits {
+                    region.constrain_constant(digit.cell(), F::ZERO)?;
+                }
+
+                Ok(())
+            },
+        )
+    }
+
+    fn expose_public(
+        &self,
+        mut layouter: impl Layouter<F>,
+        num: Self::Num,
+        row: usize,
+    ) -> Result<(), Error> {
+        let config = self.config();
+
+        layouter.constrain_instance(num.0.cell(), config.instance, row)
+    }
+}
@@ -0,0 +1,20 @@
+use halo2_proofs::plonk::{Advice, Column, Instance, Selector, TableColumn};
+
+/// The configuration for the min-max circuit
+#[derive(Debug, Clone)]
+pub struct MinMaxConfig {
+    /// Advice columns of the chip
+    pub advice: [Column<Advice>; 3],
+
+    /// Instance column of the chip
+    pub instance: Column<Instance>,
+
+    /// Running maximum selector of the chip
+    pub s_max: Selector,
+
+    /// Running minimum selector of the chip
+    pub s_min: Selector,
+
+    /// Table column for the digit range check lookup, shared with the digit sum chip
+    pub digit_table: TableColumn,
+}
@@ -0,0 +1,41 @@
+use halo2_proofs::{
+    arithmetic::Field,
+    circuit::{AssignedCell, Chip, Layouter},
+    plonk::Error,
+};
+
+/// Traits for the chip that computes the maximum and the minimum digits of a number
+pub trait MinMaxInstructions<F: Field>: Chip<F> {
+    /// The number type associated to the min-max instruction
+    type Num;
+
+    /// Computes the maximum of digits already assigned and range checked in the circuit (e.g. by the digit sum chip).
+    fn max_digit(
+        &self,
+        layouter: impl Layouter<F>,
+        digits: &[AssignedCell<F, F>],
+    ) -> Result<Self::Num, Error>;
+
+    /// Computes the minimum of digits already assigned and range checked in the circuit (e.g. by the digit sum chip).
+    fn min_digit(
+        &self,
+        layouter: impl Layouter<F>,
+        digits: &[AssignedCell<F, F>],
+    ) -> Result<Self::Num, Error>;
+
+    /// Constrains digits already assigned in the circuit to be zero (e.g. the leading zeros of a number with fewer
+    /// digits than its decimal representation, which are not compared).
+    fn constrain_zero(
+        &self,
+        layouter: impl Layouter<F>,
+        digits: &[AssignedCell<F, F>],
+    ) -> Result<(), Error>;
+
+    /// Exposes a number as a public input to the circuit.
+    fn expose_public(
+        &self,
+        layouter: impl Layouter<F>,
+        num: Self::Num,
+        row: usize,
+    ) -> Result<(), Error>;
+}
@@ -0,0 +1,13 @@
+//! The min-max chip that provably computes the maximum and the minimum digits of a number
+//! The module is splitted into three files:
+//! - `chip.rs` contains the implementation of the chip that computes the running maximum and minimum of the digits
+//! - `config.rs` contains the configuration for the min-max circuit
+//! - `instructions.rs` contains the instructions interface for the min-max chip
+
+mod chip;
+mod config;
+mod instructions;
+
+pub use chip::*;
+pub use config::*;
+pub use instructions::*;
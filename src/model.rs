@@ -1,13 +1,16 @@
-use std::marker::PhantomData;
+use std::{fmt, marker::PhantomData, str::FromStr};
 
 use anyhow::anyhow;
+use halo2_gadgets::poseidon::primitives::{self as poseidon, ConstantLength, P128Pow5T3};
 use halo2_proofs::{
     arithmetic::Field,
     circuit::Value,
-    pasta::{group::ff::PrimeField, Fp},
+    pasta::{group::ff::PrimeField, EqAffine, Fp},
+    transcript::{Blake2bWrite, Challenge255, Transcript},
 };
+use num_bigint::BigUint;
 
-use crate::NUMBER_LENGTH;
+use crate::{Kakuro, MerkleRoot, SudokuGrid, NUMBER_LENGTH};
 
 /// Generic error type
 pub type StdError = anyhow::Error;
@@ -18,18 +21,145 @@ pub type StdResult<T> = anyhow::Result<T, StdError>;
 /// A type alias for bytes
 pub type Bytes = Vec<u8>;
 
+/// Computes the modulus of a prime field
+pub fn field_modulus<F: PrimeField>() -> BigUint {
+    BigUint::parse_bytes(F::MODULUS.trim_start_matches("0x").as_bytes(), 16).unwrap()
+    // safe unwrap
+}
+
+/// Computes the maximum number of decimal digits of the numbers which are always less than the field modulus,
+/// i.e. whose recomposition from their digits in the circuit can not overflow the field modulus
+pub fn field_decimal_capacity<F: PrimeField>() -> usize {
+    field_modulus::<F>().to_string().len() - 1
+}
+
+/// A natural number of arbitrary length, e.g. a number with more digits than a `u64`
+///
+/// The number keeps the digits it is parsed from, including its leading zeros (e.g. `00123` is a number of `5`
+/// digits while `123` is a number of `3` digits, although they have the same value).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DecimalNumber {
+    digits: Vec<u8>,
+}
+
+impl DecimalNumber {
+    /// The digits of the number in decimal representation, from the most significant digit
+    pub fn digits(&self) -> Vec<u64> {
+        self.digits.iter().map(|digit| *digit as u64).collect()
+    }
+
+    /// The digits of the number in decimal representation padded with leading zeros to a number of digits
+    pub fn padded_digits(&self, length: usize) -> StdResult<Vec<u64>> {
+        let digits = self.digits();
+        if digits.len() > length {
+            return Err(anyhow!(
+                "Number is too long. Expected {} digits, got {}.",
+                length,
+                digits.len()
+            ));
+        }
+
+        Ok(vec![0; length - digits.len()]
+            .into_iter()
+            .chain(digits)
+            .collect())
+    }
+
+    /// The value of the number
+    pub fn value(&self) -> BigUint {
+        BigUint::from_radix_be(&self.digits, 10).unwrap() // safe unwrap
+    }
+
+    /// Converts the number to a `u64`
+    pub fn to_u64(&self) -> StdResult<u64> {
+        u64::try_from(&self.value()).map_err(|_| anyhow!("Number {self} does not fit in 64 bits"))
+    }
+
+    /// Converts the number to a field element, the number must be less than the field modulus
+    pub fn to_field<F: PrimeField>(&self) -> StdResult<F> {
+        if self.value() >= field_modulus::<F>() {
+            return Err(anyhow!(
+                "Number {self} is not less than the field modulus {}",
+                F::MODULUS
+            ));
+        }
+
+        Ok(self.digits().into_iter().fold(F::ZERO, |number, digit| {
+            number * F::from(10) + F::from(digit)
+        }))
+    }
+}
+
+impl From<u64> for DecimalNumber {
+    fn from(number: u64) -> Self {
+        Self::from(BigUint::from(number))
+    }
+}
+
+impl From<u128> for DecimalNumber {
+    fn from(number: u128) -> Self {
+        Self::from(BigUint::from(number))
+    }
+}
+
+impl From<BigUint> for DecimalNumber {
+    fn from(number: BigUint) -> Self {
+        Self {
+            digits: number.to_radix_be(10),
+        }
+    }
+}
+
+impl fmt::Display for DecimalNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.digits
+            .iter()
+            .try_for_each(|digit| write!(f, "{digit}"))
+    }
+}
+
+impl FromStr for DecimalNumber {
+    type Err = StdError;
+
+    fn from_str(number: &str) -> Result<Self, Self::Err> {
+        let number = number.trim();
+        if number.is_empty() || !number.chars().all(|c| c.is_ascii_digit()) {
+            return Err(anyhow!("Invalid decimal number '{number}'"));
+        }
+
+        Ok(Self {
+            digits: number.bytes().map(|digit| digit - b'0').collect(),
+        })
+    }
+}
+
 /// The secret witness for the digit sum circuit
 #[derive(Clone, Debug)]
 pub struct DigitSumSecretWitness<F: Field> {
-    number: u64,
+    number: DecimalNumber,
     _marker: PhantomData<F>,
 }
 
 impl<F: Field> DigitSumSecretWitness<F> {
     /// Creates a new secret witness
-    pub fn new(number: u64) -> Self {
+    pub fn new(number: impl Into<DecimalNumber>) -> Self {
         let _marker = PhantomData;
-        Self { number, _marker }
+        Self {
+            number: number.into(),
+            _marker,
+        }
+    }
+}
+
+impl<F: PrimeField> DigitSumSecretWitness<F> {
+    /// The digits of the witness padded with leading zeros to a number of digits
+    pub fn padded_digits(&self, length: usize) -> StdResult<Vec<Value<F>>> {
+        Ok(self
+            .number
+            .padded_digits(length)?
+            .into_iter()
+            .map(|digit| Value::known(F::from(digit)))
+            .collect())
     }
 }
 
@@ -37,23 +167,7 @@ impl<F: Field> TryFrom<DigitSumSecretWitness<F>> for [u64; NUMBER_LENGTH] {
     type Error = StdError;
 
     fn try_from(other: DigitSumSecretWitness<F>) -> Result<[u64; NUMBER_LENGTH], Self::Error> {
-        let values = other
-            .number
-            .to_string()
-            .chars()
-            .map(|d| d.to_digit(10).unwrap() as u64) // safe unwrap
-            .collect::<Vec<_>>();
-        if values.len() > NUMBER_LENGTH {
-            return Err(anyhow!(
-                "Number is too long. Expected {} digits, got {}.",
-                NUMBER_LENGTH,
-                values.len()
-            ));
-        }
-        let padded_values = std::iter::repeat(0_u64)
-            .take(NUMBER_LENGTH - values.len())
-            .chain(values)
-            .collect::<Vec<_>>();
+        let padded_values = other.number.padded_digits(NUMBER_LENGTH)?;
 
         padded_values.try_into().map_err(|_| {
             anyhow!(format!(
@@ -67,17 +181,11 @@ impl<F: PrimeField> TryFrom<DigitSumSecretWitness<F>> for [Value<F>; NUMBER_LENG
     type Error = StdError;
 
     fn try_from(other: DigitSumSecretWitness<F>) -> Result<[Value<F>; NUMBER_LENGTH], Self::Error> {
-        let values_u64: [u64; NUMBER_LENGTH] = other.try_into()?;
-        values_u64
-            .into_iter()
-            .map(|v| Value::known(F::from(v)))
-            .collect::<Vec<_>>()
-            .try_into()
-            .map_err(|_| {
-                anyhow!(format!(
-                    "Failed to convert witness to {NUMBER_LENGTH} digits"
-                ))
-            })
+        other.padded_digits(NUMBER_LENGTH)?.try_into().map_err(|_| {
+            anyhow!(format!(
+                "Failed to convert witness to {NUMBER_LENGTH} digits"
+            ))
+        })
     }
 }
 
@@ -85,12 +193,35 @@ impl<F: PrimeField> TryFrom<DigitSumSecretWitness<F>> for [Value<F>; NUMBER_LENG
 #[derive(Clone, Debug)]
 pub struct DigitSumPublicInput {
     number: u64,
+    disclosed_digits: Vec<DisclosedDigit>,
 }
 
 impl DigitSumPublicInput {
     /// Creates a new public input
     pub fn new(number: u64) -> Self {
-        Self { number }
+        Self {
+            number,
+            disclosed_digits: vec![],
+        }
+    }
+
+    /// Adds the digits disclosed by the prover to the public input
+    pub fn with_disclosed_digits(mut self, disclosed_digits: Vec<DisclosedDigit>) -> Self {
+        self.disclosed_digits = disclosed_digits;
+        self
+    }
+
+    /// The digits disclosed by the prover
+    pub fn disclosed_digits(&self) -> &[DisclosedDigit] {
+        &self.disclosed_digits
+    }
+
+    /// The positions of the disclosed digits
+    pub fn disclosed_positions(&self) -> Vec<usize> {
+        self.disclosed_digits
+            .iter()
+            .map(|disclosed_digit| disclosed_digit.position)
+            .collect()
     }
 }
 
@@ -100,13 +231,413 @@ impl From<DigitSumPublicInput> for Fp {
     }
 }
 
+impl From<DigitSumPublicInput> for Vec<Fp> {
+    fn from(other: DigitSumPublicInput) -> Vec<Fp> {
+        let mut disclosed_digits = other.disclosed_digits;
+        disclosed_digits.sort_by_key(|disclosed_digit| disclosed_digit.position);
+
+        [other.number]
+            .into_iter()
+            .chain(
+                disclosed_digits
+                    .iter()
+                    .map(|disclosed_digit| disclosed_digit.digit),
+            )
+            .map(Fp::from)
+            .collect()
+    }
+}
+
+/// A digit disclosed at a position of the decimal representation of a number padded with leading zeros
+///
+/// The positions start at `0` for the most significant digit, including the leading zeros.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DisclosedDigit {
+    /// The position of the digit
+    pub position: usize,
+
+    /// The digit value
+    pub digit: u64,
+}
+
+impl FromStr for DisclosedDigit {
+    type Err = StdError;
+
+    fn from_str(disclosed_digit: &str) -> Result<Self, Self::Err> {
+        let (position, digit) = disclosed_digit.split_once('=').ok_or_else(|| {
+            anyhow!("Invalid disclosed digit '{disclosed_digit}', expected POSITION=DIGIT")
+        })?;
+        let position = position
+            .trim()
+            .parse::<usize>()
+            .map_err(|_| anyhow!("Invalid position '{position}'"))?;
+        let digit = digit
+            .trim()
+            .parse::<u64>()
+            .ok()
+            .filter(|digit| *digit < 10)
+            .ok_or_else(|| anyhow!("Invalid digit '{digit}'"))?;
+
+        Ok(Self { position, digit })
+    }
+}
+
+/// The public input for the modulo circuit
+#[derive(Clone, Debug)]
+pub struct ModuloPublicInput {
+    digit_sum: u64,
+    modulus: u64,
+    residue: u64,
+}
+
+impl ModuloPublicInput {
+    /// Creates a new public input
+    pub fn new(digit_sum: u64, modulus: u64, residue: u64) -> Self {
+        Self {
+            digit_sum,
+            modulus,
+            residue,
+        }
+    }
+}
+
+impl From<ModuloPublicInput> for Vec<Fp> {
+    fn from(other: ModuloPublicInput) -> Vec<Fp> {
+        vec![
+            Fp::from(other.digit_sum),
+            Fp::from(other.modulus),
+            Fp::from(other.residue),
+        ]
+    }
+}
+
+/// The public input for the age circuit
+#[derive(Clone, Debug)]
+pub struct AgePublicInput {
+    today: u64,
+    min_age: u64,
+}
+
+impl AgePublicInput {
+    /// Creates a new public input
+    pub fn new(today: u64, min_age: u64) -> Self {
+        Self { today, min_age }
+    }
+}
+
+impl From<AgePublicInput> for Vec<Fp> {
+    fn from(other: AgePublicInput) -> Vec<Fp> {
+        vec![Fp::from(other.today), Fp::from(other.min_age)]
+    }
+}
+
+/// The public input for the anagram circuit
+#[derive(Clone, Debug)]
+pub struct AnagramPublicInput {
+    number: u64,
+}
+
+impl AnagramPublicInput {
+    /// Creates a new public input
+    pub fn new(number: u64) -> Self {
+        Self { number }
+    }
+
+    /// Computes the challenge of the anagram check by hashing the public digits.
+    ///
+    /// The secret digits are range checked, so a prover can only choose among a small number of digit
+    /// multisets: a challenge that the prover does not control is enough to make the check sound.
+    fn challenge(digits: &[u64]) -> StdResult<Fp> {
+        let mut transcript = Blake2bWrite::<_, EqAffine, Challenge255<_>>::init(vec![]);
+        for digit in digits {
+            transcript.common_scalar(Fp::from(*digit))?;
+        }
+
+        Ok(*transcript.squeeze_challenge_scalar::<()>())
+    }
+}
+
+impl TryFrom<AnagramPublicInput> for Vec<Fp> {
+    type Error = StdError;
+
+    fn try_from(other: AnagramPublicInput) -> Result<Vec<Fp>, Self::Error> {
+        let digits: [u64; NUMBER_LENGTH] =
+            DigitSumSecretWitness::<Fp>::new(other.number).try_into()?;
+        let challenge = AnagramPublicInput::challenge(&digits)?;
+
+        Ok(digits
+            .into_iter()
+            .map(Fp::from)
+            .chain([challenge])
+            .collect())
+    }
+}
+
+/// The number of occurrences of a digit in a number, parsed from the `DIGIT=COUNT` format
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DigitOccurrence {
+    /// The digit value
+    pub digit: u64,
+
+    /// The number of occurrences of the digit
+    pub count: u64,
+}
+
+impl FromStr for DigitOccurrence {
+    type Err = StdError;
+
+    fn from_str(occurrence: &str) -> Result<Self, Self::Err> {
+        let (digit, count) = occurrence
+            .split_once('=')
+            .ok_or_else(|| anyhow!("Invalid occurrence '{occurrence}', expected DIGIT=COUNT"))?;
+        let digit = digit
+            .trim()
+            .parse::<u64>()
+            .ok()
+            .filter(|digit| *digit < 10)
+            .ok_or_else(|| anyhow!("Invalid digit '{digit}'"))?;
+        let count = count.trim().parse::<u64>()?;
+
+        Ok(Self { digit, count })
+    }
+}
+
+/// The public input for the occurrence circuit
+#[derive(Clone, Debug)]
+pub struct OccurrencePublicInput {
+    occurrences: Vec<DigitOccurrence>,
+    distinct: Option<bool>,
+}
+
+impl OccurrencePublicInput {
+    /// Creates a new public input
+    pub fn new(occurrences: Vec<DigitOccurrence>, distinct: Option<bool>) -> Self {
+        Self {
+            occurrences,
+            distinct,
+        }
+    }
+
+    /// The digits which occurrence counts are public
+    pub fn counted_digits(&self) -> Vec<u64> {
+        self.occurrences.iter().map(|o| o.digit).collect()
+    }
+
+    /// Whether the distinct digits boolean is public
+    pub fn has_distinct(&self) -> bool {
+        self.distinct.is_some()
+    }
+}
+
+impl From<OccurrencePublicInput> for Vec<Fp> {
+    fn from(other: OccurrencePublicInput) -> Vec<Fp> {
+        other
+            .occurrences
+            .into_iter()
+            .map(|o| Fp::from(o.count))
+            .chain(other.distinct.map(|distinct| Fp::from(distinct as u64)))
+            .collect()
+    }
+}
+
+/// The public input for the sudoku circuit
+#[derive(Clone, Debug)]
+pub struct SudokuPublicInput {
+    puzzle: SudokuGrid,
+}
+
+impl SudokuPublicInput {
+    /// Creates a new public input
+    pub fn new(puzzle: SudokuGrid) -> Self {
+        Self { puzzle }
+    }
+}
+
+impl From<SudokuPublicInput> for Vec<Fp> {
+    fn from(other: SudokuPublicInput) -> Vec<Fp> {
+        other.puzzle.cells().into_iter().map(Fp::from).collect()
+    }
+}
+
+/// The public input for the kakuro circuit
+#[derive(Clone, Debug)]
+pub struct KakuroPublicInput {
+    puzzle: Kakuro,
+}
+
+impl KakuroPublicInput {
+    /// Creates a new public input
+    pub fn new(puzzle: Kakuro) -> Self {
+        Self { puzzle }
+    }
+}
+
+impl From<KakuroPublicInput> for Vec<Fp> {
+    fn from(other: KakuroPublicInput) -> Vec<Fp> {
+        other
+            .puzzle
+            .runs()
+            .iter()
+            .map(|run| Fp::from(run.sum))
+            .collect()
+    }
+}
+
+/// Encodes a field element as the hexadecimal encoding of its representation
+pub(crate) fn field_to_hex(value: &Fp) -> String {
+    hex::encode(value.to_repr())
+}
+
+/// Decodes a field element from the hexadecimal encoding of its representation
+pub(crate) fn field_from_hex(value: &str) -> StdResult<Fp> {
+    let bytes: [u8; 32] = hex::decode(value.trim())?
+        .try_into()
+        .map_err(|_| anyhow!("Invalid field element '{value}', expected 32 bytes"))?;
+
+    Option::from(Fp::from_repr(bytes))
+        .ok_or_else(|| anyhow!("Invalid field element '{value}', not in the field"))
+}
+
+/// Computes the Poseidon hash of two field elements, with the `P128Pow5T3` parameters and the constant length domain
+/// of `halo2_gadgets`
+pub fn poseidon_hash(inputs: [Fp; 2]) -> Fp {
+    poseidon::Hash::<_, P128Pow5T3, ConstantLength<2>, 3, 2>::init().hash(inputs)
+}
+
+/// A nullifier, i.e. the Poseidon hash of a secret number and of a public domain tag
+///
+/// It is represented as the hexadecimal encoding of its field element representation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Nullifier(Fp);
+
+impl Nullifier {
+    /// Computes the nullifier of a secret number for a domain tag
+    ///
+    /// The number is limited to the digits the nullifier circuit can recompose, like in the circuit.
+    pub fn new(number: impl Into<DecimalNumber>, domain: u64) -> StdResult<Self> {
+        let number = number.into();
+        number.padded_digits(field_decimal_capacity::<Fp>())?;
+        let number = number.to_field()?;
+
+        Ok(Self(poseidon_hash([number, Fp::from(domain)])))
+    }
+}
+
+impl fmt::Display for Nullifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", field_to_hex(&self.0))
+    }
+}
+
+impl FromStr for Nullifier {
+    type Err = StdError;
+
+    fn from_str(nullifier: &str) -> Result<Self, Self::Err> {
+        field_from_hex(nullifier).map(Self)
+    }
+}
+
+/// The public input for the nullifier circuit
+#[derive(Clone, Debug)]
+pub struct NullifierPublicInput {
+    digit_sum: u64,
+    domain: u64,
+    nullifier: Nullifier,
+}
+
+impl NullifierPublicInput {
+    /// Creates a new public input
+    pub fn new(digit_sum: u64, domain: u64, nullifier: Nullifier) -> Self {
+        Self {
+            digit_sum,
+            domain,
+            nullifier,
+        }
+    }
+}
+
+impl From<NullifierPublicInput> for Vec<Fp> {
+    fn from(other: NullifierPublicInput) -> Vec<Fp> {
+        vec![
+            Fp::from(other.digit_sum),
+            Fp::from(other.domain),
+            other.nullifier.0,
+        ]
+    }
+}
+
+/// The set of the nullifiers already seen by a verifier, represented with one nullifier per line
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NullifierSet {
+    nullifiers: Vec<Nullifier>,
+}
+
+impl NullifierSet {
+    /// Checks if a nullifier is in the set
+    pub fn contains(&self, nullifier: &Nullifier) -> bool {
+        self.nullifiers.contains(nullifier)
+    }
+
+    /// Inserts a nullifier in the set, returns `false` if it was already in the set
+    pub fn insert(&mut self, nullifier: Nullifier) -> bool {
+        if self.contains(&nullifier) {
+            return false;
+        }
+        self.nullifiers.push(nullifier);
+
+        true
+    }
+}
+
+impl fmt::Display for NullifierSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.nullifiers
+            .iter()
+            .try_for_each(|nullifier| writeln!(f, "{nullifier}"))
+    }
+}
+
+impl FromStr for NullifierSet {
+    type Err = StdError;
+
+    fn from_str(nullifiers: &str) -> Result<Self, Self::Err> {
+        let nullifiers = nullifiers
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(Nullifier::from_str)
+            .collect::<StdResult<_>>()?;
+
+        Ok(Self { nullifiers })
+    }
+}
+
+/// The public input for the Merkle membership circuit
+#[derive(Clone, Debug)]
+pub struct MerklePublicInput {
+    digit_sum: u64,
+    root: MerkleRoot,
+}
+
+impl MerklePublicInput {
+    /// Creates a new public input
+    pub fn new(digit_sum: u64, root: MerkleRoot) -> Self {
+        Self { digit_sum, root }
+    }
+}
+
+impl From<MerklePublicInput> for Vec<Fp> {
+    fn from(other: MerklePublicInput) -> Vec<Fp> {
+        vec![Fp::from(other.digit_sum), other.root.0]
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn secret_witness_should_convert_to_array_with_valid_number_exact_digits() {
-        let secret_number = 12345678;
+        let secret_number = 12345678_u64;
         assert!(secret_number.to_string().len() == NUMBER_LENGTH);
         let secret_witness = DigitSumSecretWitness::new(secret_number);
         let expected_known_values = [1, 2, 3, 4, 5, 6, 7, 8];
@@ -118,7 +649,7 @@ mod tests {
 
     #[test]
     fn secret_witness_should_convert_to_array_with_valid_number_less_digits() {
-        let secret_number = 123456;
+        let secret_number = 123456_u64;
         assert!(secret_number.to_string().len() < NUMBER_LENGTH);
         let secret_witness = DigitSumSecretWitness::new(secret_number);
         let expected_known_values = [0, 0, 1, 2, 3, 4, 5, 6];
@@ -130,11 +661,178 @@ mod tests {
 
     #[test]
     fn secret_witness_should_convert_to_array_with_valid_number_more_digits() {
-        let secret_number = 123456789;
+        let secret_number = 123456789_u64;
         assert!(secret_number.to_string().len() > NUMBER_LENGTH);
         let secret_witness = DigitSumSecretWitness::new(secret_number);
         let known_values: Result<[Value<Fp>; NUMBER_LENGTH], _> = secret_witness.try_into();
 
         assert!(known_values.is_err());
     }
+
+    #[test]
+    fn decimal_number_should_parse_numbers_beyond_u64() {
+        let number = "1234567890".repeat(4).parse::<DecimalNumber>().unwrap();
+
+        assert_eq!(40, number.digits().len());
+        assert_eq!("1234567890".repeat(4), number.to_string());
+        assert!(number.to_u64().is_err());
+        assert_eq!(
+            vec![0, 0, 1, 2, 3],
+            DecimalNumber::from(123_u64).padded_digits(5).unwrap()
+        );
+        assert!(number.padded_digits(39).is_err());
+        for number in ["", "12a", "-1", "1.5"] {
+            assert!(number.parse::<DecimalNumber>().is_err());
+        }
+    }
+
+    #[test]
+    fn decimal_number_should_preserve_leading_zeros() {
+        let number = "00123".parse::<DecimalNumber>().unwrap();
+
+        assert_eq!(vec![0, 0, 1, 2, 3], number.digits());
+        assert_eq!("00123", number.to_string());
+        assert_ne!(DecimalNumber::from(123_u64), number);
+        assert_eq!(DecimalNumber::from(123_u64).value(), number.value());
+        assert!(number.padded_digits(4).is_err());
+        assert_eq!(vec![0], DecimalNumber::from(0_u64).digits());
+    }
+
+    #[test]
+    fn decimal_number_should_convert_to_field_below_modulus_only() {
+        let modulus = field_modulus::<Fp>();
+
+        assert_eq!(
+            -Fp::one(),
+            DecimalNumber::from(&modulus - 1_u64)
+                .to_field::<Fp>()
+                .unwrap()
+        );
+        assert!(DecimalNumber::from(modulus.clone())
+            .to_field::<Fp>()
+            .is_err());
+        assert!(DecimalNumber::from(modulus + 1_u64)
+            .to_field::<Fp>()
+            .is_err());
+    }
+
+    #[test]
+    fn field_decimal_capacity_should_be_below_modulus() {
+        let capacity = field_decimal_capacity::<Fp>();
+        let largest = "9".repeat(capacity).parse::<DecimalNumber>().unwrap();
+        let smallest_beyond = format!("1{}", "0".repeat(capacity))
+            .parse::<DecimalNumber>()
+            .unwrap();
+
+        assert_eq!(76, capacity);
+        assert!(largest.to_field::<Fp>().is_ok());
+        assert!(smallest_beyond.to_field::<Fp>().is_ok());
+        assert!("9"
+            .repeat(capacity + 1)
+            .parse::<DecimalNumber>()
+            .unwrap()
+            .to_field::<Fp>()
+            .is_err());
+    }
+
+    #[test]
+    fn digit_occurrence_should_parse_valid_format() {
+        assert_eq!(
+            DigitOccurrence { digit: 7, count: 2 },
+            "7=2".parse::<DigitOccurrence>().unwrap()
+        );
+        for occurrence in ["7", "10=1", "a=1", "7=-1"] {
+            assert!(occurrence.parse::<DigitOccurrence>().is_err());
+        }
+    }
+
+    #[test]
+    fn disclosed_digit_should_parse_valid_format() {
+        assert_eq!(
+            DisclosedDigit {
+                position: 0,
+                digit: 1
+            },
+            "0=1".parse::<DisclosedDigit>().unwrap()
+        );
+        assert_eq!(
+            DisclosedDigit {
+                position: 39,
+                digit: 0
+            },
+            "39=0".parse::<DisclosedDigit>().unwrap()
+        );
+        for disclosed_digit in ["0", "0=10", "a=1", "-1=1"] {
+            assert!(disclosed_digit.parse::<DisclosedDigit>().is_err());
+        }
+    }
+
+    #[test]
+    fn digit_sum_public_input_should_convert_to_sum_then_disclosed_digits_by_position() {
+        let public_input = DigitSumPublicInput::new(10)
+            .with_disclosed_digits(vec!["7=4".parse().unwrap(), "4=1".parse().unwrap()]);
+        let public_input: Vec<Fp> = public_input.into();
+
+        assert_eq!(vec![Fp::from(10), Fp::from(1), Fp::from(4)], public_input);
+    }
+
+    #[test]
+    fn poseidon_hash_should_match_reference_test_vector() {
+        // First test vector of the Orchard Poseidon hash over the Pallas base field
+        let hash = poseidon_hash([Fp::zero(), Fp::one()]);
+
+        assert_eq!(
+            "8358d711a0329d38becd54fba7c283ed3e089a39c91b6a9d10efb02bc3f12f06",
+            field_to_hex(&hash)
+        );
+    }
+
+    #[test]
+    fn nullifier_should_round_trip_through_its_hexadecimal_representation() {
+        let nullifier = Nullifier::new(12345678_u64, 1).unwrap();
+
+        assert_eq!(nullifier, nullifier.to_string().parse().unwrap());
+        assert_ne!(nullifier, Nullifier::new(12345678_u64, 2).unwrap());
+        for nullifier in ["", "00", "zz", &"ff".repeat(32)] {
+            assert!(nullifier.parse::<Nullifier>().is_err());
+        }
+    }
+
+    #[test]
+    fn nullifier_should_reject_number_longer_than_the_circuit_capacity() {
+        let capacity = field_decimal_capacity::<Fp>();
+        let number = format!("1{}", "0".repeat(capacity))
+            .parse::<DecimalNumber>()
+            .unwrap();
+
+        assert!(number.value() < field_modulus::<Fp>());
+        assert!(Nullifier::new(number, 1).is_err());
+    }
+
+    #[test]
+    fn nullifier_set_should_reject_nullifier_used_twice() {
+        let mut nullifier_set = NullifierSet::default();
+
+        assert!(nullifier_set.insert(Nullifier::new(12345678_u64, 1).unwrap()));
+        assert!(nullifier_set.insert(Nullifier::new(12345678_u64, 2).unwrap()));
+        assert!(!nullifier_set.insert(Nullifier::new(12345678_u64, 1).unwrap()));
+        assert_eq!(
+            nullifier_set,
+            nullifier_set.to_string().parse::<NullifierSet>().unwrap()
+        );
+    }
+
+    #[test]
+    fn occurrence_public_input_should_convert_to_counts_then_distinct() {
+        let public_input = OccurrencePublicInput::new(
+            vec![
+                DigitOccurrence { digit: 7, count: 2 },
+                DigitOccurrence { digit: 0, count: 5 },
+            ],
+            Some(false),
+        );
+        let public_input: Vec<Fp> = public_input.into();
+
+        assert_eq!(vec![Fp::from(2), Fp::from(5), Fp::from(0)], public_input);
+    }
 }
@@ -0,0 +1,304 @@
+use std::marker::PhantomData;
+
+use halo2_proofs::{
+    circuit::{AssignedCell, Chip, Layouter, Region, Value},
+    pasta::group::ff::PrimeField,
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, Fixed, Instance, TableColumn},
+    poly::Rotation,
+};
+
+use crate::{ModuloConfig, ModuloInstructions, NUMBER_LENGTH};
+
+/// The chip that implements the euclidean division instructions
+///
+/// The quotient and the difference `modulus - 1 - residue` are loaded in decimal representation with range
+/// checked digits, so that they are non negative integers below `10^NUMBER_LENGTH`. The division equation
+/// therefore holds over the integers, as none of its terms can wrap around the field modulus.
+pub struct ModuloChip<F: PrimeField> {
+    config: ModuloConfig,
+    _marker: PhantomData<F>,
+}
+
+impl<F: PrimeField> ModuloChip<F> {
+    /// Creates a new instance of the modulo chip
+    pub fn construct(config: ModuloConfig) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Configures the modulo chip
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        advice: [Column<Advice>; 4],
+        instance: Column<Instance>,
+        constant: Column<Fixed>,
+        digit_table: TableColumn,
+    ) -> <Self as Chip<F>>::Config {
+        meta.enable_equality(instance);
+        meta.enable_constant(constant);
+        for column in &advice {
+            meta.enable_equality(*column);
+        }
+        let s_decimal = meta.selector();
+        let s_lookup = meta.complex_selector();
+        let s_modulo = meta.selector();
+
+        meta.lookup(|meta| {
+            let s_lookup = meta.query_selector(s_lookup);
+            let digit = meta.query_advice(advice[0], Rotation::cur());
+            vec![(s_lookup * digit, digit_table)]
+        });
+
+        meta.create_gate("decimal", |meta| {
+            // This gate recomposes a number from its digits in decimal representation
+            // Here is the arrangement of the cells of the gate
+            //
+            // | a0  | a1   | a2   | s_decimal |
+            // |-----|------|------|-----------|
+            // | in0 | 0    | acc0 | s_decimal |
+            // | in1 | acc0 | acc1 | s_decimal |
+            // | ... | ...  | ...  | s_decimal |
+            // | in7 | acc6 | acc7 | s_decimal |
+            //
+            let digit = meta.query_advice(advice[0], Rotation::cur());
+            let previous = meta.query_advice(advice[1], Rotation::cur());
+            let accumulator = meta.query_advice(advice[2], Rotation::cur());
+            let s_decimal = meta.query_selector(s_decimal);
+
+            vec![s_decimal * (previous * Expression::Constant(F::from(10)) + digit - accumulator)]
+        });
+
+        meta.create_gate("modulo", |meta| {
+            // This gate checks the euclidean division of the number by the modulus
+            // Here is the arrangement of the cells of the gate
+            //
+            // | a0         | a1       | a2      | a3      | s_modulo |
+            // |------------|----------|---------|---------|----------|
+            // | number     | quotient | modulus | residue | s_modulo |
+            // | difference |          |         |         |          |
+            //
+            let number = meta.query_advice(advice[0], Rotation::cur());
+            let quotient = meta.query_advice(advice[1], Rotation::cur());
+            let modulus = meta.query_advice(advice[2], Rotation::cur());
+            let residue = meta.query_advice(advice[3], Rotation::cur());
+            let difference = meta.query_advice(advice[0], Rotation::next());
+            let s_modulo = meta.query_selector(s_modulo);
+
+            vec![
+                s_modulo.clone() * (quotient * modulus.clone() + residue.clone() - number),
+                s_modulo * (modulus - Expression::Constant(F::ONE) - residue - difference),
+            ]
+        });
+
+        ModuloConfig {
+            advice,
+            instance,
+            s_decimal,
+            s_lookup,
+            s_modulo,
+            digit_table,
+        }
+    }
+
+    /// Assigns the decimal recomposition of the digits once they are assigned in the first advice column
+    fn assign_recomposition(
+        &self,
+        region: &mut Region<'_, F>,
+        digits: Vec<AssignedCell<F, F>>,
+    ) -> Result<AssignedCell<F, F>, Error> {
+        let config = self.config();
+
+        let mut previous_value =
+            region.assign_advice_from_constant(|| "zero", config.advice[1], 0, F::ZERO)?;
+        for (i, digit) in digits.into_iter().enumerate() {
+            config.s_decimal.enable(region, i)?;
+
+            if i > 0 {
+                previous_value.copy_advice(
+                    || format!("decimal [{}]", i - 1),
+                    region,
+                    config.advice[1],
+                    i,
+                )?;
+            }
+            previous_value = region.assign_advice(
+                || format!("decimal [{i}]"),
+                config.advice[2],
+                i,
+                || previous_value.value().copied() * Value::known(F::from(10)) + digit.value(),
+            )?;
+        }
+
+        Ok(previous_value)
+    }
+}
+
+impl<F: PrimeField> Chip<F> for ModuloChip<F> {
+    type Config = ModuloConfig;
+    type Loaded = ();
+
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+/// A number represented in the modulo chip
+#[derive(Clone, Debug)]
+pub struct ModuloNumber<F: PrimeField>(AssignedCell<F, F>);
+
+impl<F: PrimeField> ModuloNumber<F> {
+    /// The assigned cell of the number, e.g. to constrain it from another chip
+    pub fn cell(&self) -> &AssignedCell<F, F> {
+        &self.0
+    }
+}
+
+impl<F: PrimeField> From<AssignedCell<F, F>> for ModuloNumber<F> {
+    fn from(cell: AssignedCell<F, F>) -> Self {
+        Self(cell)
+    }
+}
+
+impl<F: PrimeField> ModuloInstructions<F> for ModuloChip<F> {
+    type Num = ModuloNumber<F>;
+
+    fn recompose(
+        &self,
+        mut layouter: impl Layouter<F>,
+        digits: &[AssignedCell<F, F>],
+    ) -> Result<Self::Num, Error> {
+        let config = self.config();
+
+        layouter
+            .assign_region(
+                || "recompose number",
+                |mut region| {
+                    let digits = digits
+                        .iter()
+                        .enumerate()
+                        .map(|(i, digit)| {
+                            digit.copy_advice(
+                                || format!("digit {i}"),
+                                &mut region,
+                                config.advice[0],
+                                i,
+                            )
+                        })
+                        .collect::<Result<Vec<_>, Error>>()?;
+
+                    self.assign_recomposition(&mut region, digits)
+                },
+            )
+            .map(ModuloNumber)
+    }
+
+    fn load_private(
+        &self,
+        mut layouter: impl Layouter<F>,
+        values: [Value<F>; NUMBER_LENGTH],
+    ) -> Result<Self::Num, Error> {
+        let config = self.config();
+
+        layouter
+            .assign_region(
+                || "private number",
+                |mut region| {
+                    let digits = values
+                        .into_iter()
+                        .enumerate()
+                        .map(|(i, value)| {
+                            config.s_lookup.enable(&mut region, i)?;
+                            region.assign_advice(
+                                || format!("witness {i}"),
+                                config.advice[0],
+                                i,
+                                || value,
+                            )
+                        })
+                        .collect::<Result<Vec<_>, Error>>()?;
+
+                    self.assign_recomposition(&mut region, digits)
+                },
+            )
+            .map(ModuloNumber)
+    }
+
+    fn load_public(&self, mut layouter: impl Layouter<F>, row: usize) -> Result<Self::Num, Error> {
+        let config = self.config();
+
+        layouter
+            .assign_region(
+                || "public input",
+                |mut region| {
+                    region.assign_advice_from_instance(
+                        || "public input",
+                        config.instance,
+                        row,
+                        config.advice[0],
+                        0,
+                    )
+                },
+            )
+            .map(ModuloNumber)
+    }
+
+    fn load_constant(
+        &self,
+        mut layouter: impl Layouter<F>,
+        constant: F,
+    ) -> Result<Self::Num, Error> {
+        let config = self.config();
+
+        layouter
+            .assign_region(
+                || "constant",
+                |mut region| {
+                    region.assign_advice_from_constant(|| "constant", config.advice[0], 0, constant)
+                },
+            )
+            .map(ModuloNumber)
+    }
+
+    fn constrain_modulo(
+        &self,
+        mut layouter: impl Layouter<F>,
+        number: Self::Num,
+        quotient: Self::Num,
+        modulus: Self::Num,
+        residue: Self::Num,
+        difference: Self::Num,
+    ) -> Result<(), Error> {
+        let config = self.config();
+
+        layouter.assign_region(
+            || "modulo",
+            |mut region| {
+                config.s_modulo.enable(&mut region, 0)?;
+
+                number
+                    .0
+                    .copy_advice(|| "number", &mut region, config.advice[0], 0)?;
+                quotient
+                    .0
+                    .copy_advice(|| "quotient", &mut region, config.advice[1], 0)?;
+                modulus
+                    .0
+                    .copy_advice(|| "modulus", &mut region, config.advice[2], 0)?;
+                residue
+                    .0
+                    .copy_advice(|| "residue", &mut region, config.advice[3], 0)?;
+                difference
+                    .0
+                    .copy_advice(|| "difference", &mut region, config.advice[0], 1)?;
+
+                Ok(())
+            },
+        )
+    }
+}
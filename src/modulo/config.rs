@@ -0,0 +1,23 @@
+use halo2_proofs::plonk::{Advice, Column, Instance, Selector, TableColumn};
+
+/// The configuration for the modulo circuit
+#[derive(Debug, Clone)]
+pub struct ModuloConfig {
+    /// Advice columns of the chip
+    pub advice: [Column<Advice>; 4],
+
+    /// Instance column of the chip
+    pub instance: Column<Instance>,
+
+    /// Decimal recomposition selector of the chip
+    pub s_decimal: Selector,
+
+    /// Lookup selector for the digit range check
+    pub s_lookup: Selector,
+
+    /// Euclidean division selector of the chip
+    pub s_modulo: Selector,
+
+    /// Table column for the digit range check lookup (shared with the digit sum chip)
+    pub digit_table: TableColumn,
+}
@@ -0,0 +1,49 @@
+use halo2_proofs::{
+    arithmetic::Field,
+    circuit::{AssignedCell, Chip, Layouter, Value},
+    plonk::Error,
+};
+
+use crate::NUMBER_LENGTH;
+
+/// Traits for the chip that checks the euclidean division of a number by a modulus
+pub trait ModuloInstructions<F: Field>: Chip<F> {
+    /// The number type associated to the modulo instruction
+    type Num;
+
+    /// Recomposes a number from its digits in decimal representation, which are already range checked.
+    ///
+    /// The recomposition is computed modulo the field modulus: the number of digits must not exceed the decimal
+    /// capacity of the field for the recomposition to be unique.
+    fn recompose(
+        &self,
+        layouter: impl Layouter<F>,
+        digits: &[AssignedCell<F, F>],
+    ) -> Result<Self::Num, Error>;
+
+    /// Loads a private input to the circuit in a decimal format, range checks its digits and recomposes it.
+    fn load_private(
+        &self,
+        layouter: impl Layouter<F>,
+        values: [Value<F>; NUMBER_LENGTH],
+    ) -> Result<Self::Num, Error>;
+
+    /// Loads a public input to the circuit.
+    fn load_public(&self, layouter: impl Layouter<F>, row: usize) -> Result<Self::Num, Error>;
+
+    /// Loads a constant to the circuit.
+    fn load_constant(&self, layouter: impl Layouter<F>, constant: F) -> Result<Self::Num, Error>;
+
+    /// Constrains `number = quotient * modulus + residue` with `residue < modulus`.
+    ///
+    /// The `difference` is the number `modulus - 1 - residue`, which is non negative as it is loaded in decimal representation.
+    fn constrain_modulo(
+        &self,
+        layouter: impl Layouter<F>,
+        number: Self::Num,
+        quotient: Self::Num,
+        modulus: Self::Num,
+        residue: Self::Num,
+        difference: Self::Num,
+    ) -> Result<(), Error>;
+}
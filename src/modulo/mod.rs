@@ -0,0 +1,16 @@
+//! The modulo chip that provably computes the remainder of the euclidean division of a fixed length number by a public modulus
+//! The module is splitted into three files:
+//! - `chip.rs` contains the implementation of the chip that checks the euclidean division
+//! - `config.rs` contains the configuration for the modulo circuit
+//! - `instructions.rs` contains the instructions interface for the modulo chip
+
+mod chip;
+mod config;
+mod instructions;
+
+pub use chip::*;
+pub use config::*;
+pub use instructions::*;
+
+/// The upper bound (inclusive) of the modulus: the quotient and the remainder then fit in `NUMBER_LENGTH` digits
+pub const MODULUS_UPPER_BOUND: u64 = 100_000_000;
@@ -0,0 +1,318 @@
+use std::marker::PhantomData;
+
+use halo2_proofs::{
+    circuit::{AssignedCell, Chip, Layouter, Value},
+    pasta::group::ff::PrimeField,
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, Fixed, Instance, TableColumn},
+    poly::Rotation,
+};
+
+use crate::{OccurrenceConfig, OccurrenceInstructions, DIGIT_VALUES, NUMBER_LENGTH};
+
+/// The chip that implements the digit occurrences counting instructions
+///
+/// The occurrences are counted on the decimal representation of the number with `NUMBER_LENGTH` digits,
+/// including its leading zeros (e.g. the number `123` is represented as `00000123`).
+pub struct OccurrenceChip<F: PrimeField> {
+    config: OccurrenceConfig,
+    _marker: PhantomData<F>,
+}
+
+impl<F: PrimeField> OccurrenceChip<F> {
+    /// Creates a new instance of the occurrence chip
+    pub fn construct(config: OccurrenceConfig) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Configures the occurrence chip
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        advice: [Column<Advice>; 4],
+        instance: Column<Instance>,
+        constant: Column<Fixed>,
+        digit_table: TableColumn,
+    ) -> <Self as Chip<F>>::Config {
+        meta.enable_equality(instance);
+        meta.enable_constant(constant);
+        for column in &advice {
+            meta.enable_equality(*column);
+        }
+        let digit_value = meta.fixed_column();
+        let s_lookup = meta.complex_selector();
+        let s_count = meta.selector();
+        let s_repetition = meta.selector();
+        let s_distinct = meta.selector();
+        let one = || Expression::Constant(F::ONE);
+
+        meta.lookup(|meta| {
+            let s_lookup = meta.query_selector(s_lookup);
+            let digit = meta.query_advice(advice[0], Rotation::cur());
+            vec![(s_lookup * digit, digit_table)]
+        });
+
+        meta.create_gate("occurrence_count", |meta| {
+            // This gate counts the occurrences of a digit value with an is-equal gadget:
+            // `eq = 1 - (in - value) * inv` is `1` if the digit is equal to the value, `0` otherwise
+            // Here is the arrangement of the cells of the gate
+            //
+            // | a0  | a1   | a2     | a3     | digit_value | s_count |
+            // |-----|------|--------|--------|-------------|---------|
+            // | in0 | inv0 | 0      | count0 | value       | s_count |
+            // | in1 | inv1 | count0 | count1 | value       | s_count |
+            // | ... | ...  | ...    | ...    | value       | s_count |
+            // | in7 | inv7 | count6 | count7 | value       | s_count |
+            //
+            let digit = meta.query_advice(advice[0], Rotation::cur());
+            let inverse = meta.query_advice(advice[1], Rotation::cur());
+            let count_prev = meta.query_advice(advice[2], Rotation::cur());
+            let count = meta.query_advice(advice[3], Rotation::cur());
+            let value = meta.query_fixed(digit_value);
+            let s_count = meta.query_selector(s_count);
+
+            let difference = digit - value;
+            let is_equal = one() - difference.clone() * inverse;
+
+            vec![
+                s_count.clone() * difference * is_equal.clone(),
+                s_count * (count_prev + is_equal - count),
+            ]
+        });
+
+        meta.create_gate("repetition", |meta| {
+            // This gate accumulates `count * (count - 1)` over the digit values, which is zero
+            // if and only if each digit value occurs at most once
+            // Here is the arrangement of the cells of the gate
+            //
+            // | a0     | a1   | a2   | s_repetition |
+            // |--------|------|------|--------------|
+            // | count0 | 0    | rep0 | s_repetition |
+            // | count1 | rep0 | rep1 | s_repetition |
+            // | ...    | ...  | ...  | s_repetition |
+            // | count9 | rep8 | rep9 | s_repetition |
+            //
+            let count = meta.query_advice(advice[0], Rotation::cur());
+            let repetition_prev = meta.query_advice(advice[1], Rotation::cur());
+            let repetition = meta.query_advice(advice[2], Rotation::cur());
+            let s_repetition = meta.query_selector(s_repetition);
+
+            vec![s_repetition * (repetition_prev + count.clone() * (count - one()) - repetition)]
+        });
+
+        meta.create_gate("distinct", |meta| {
+            // This gate computes the distinct digits boolean with an is-zero gadget
+            // Here is the arrangement of the cells of the gate
+            //
+            // | a0   | a1  | a2       | s_distinct |
+            // |------|-----|----------|------------|
+            // | rep9 | inv | distinct | s_distinct |
+            //
+            let repetition = meta.query_advice(advice[0], Rotation::cur());
+            let inverse = meta.query_advice(advice[1], Rotation::cur());
+            let distinct = meta.query_advice(advice[2], Rotation::cur());
+            let s_distinct = meta.query_selector(s_distinct);
+
+            vec![
+                s_distinct.clone() * repetition.clone() * distinct.clone(),
+                s_distinct * (one() - repetition * inverse - distinct),
+            ]
+        });
+
+        OccurrenceConfig {
+            advice,
+            digit_value,
+            instance,
+            s_lookup,
+            s_count,
+            s_repetition,
+            s_distinct,
+            digit_table,
+        }
+    }
+}
+
+impl<F: PrimeField> Chip<F> for OccurrenceChip<F> {
+    type Config = OccurrenceConfig;
+    type Loaded = ();
+
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+/// A number represented in the occurrence chip
+#[derive(Clone, Debug)]
+pub struct OccurrenceNumber<F: PrimeField>(AssignedCell<F, F>);
+
+impl<F: PrimeField> OccurrenceInstructions<F> for OccurrenceChip<F> {
+    type Num = OccurrenceNumber<F>;
+
+    fn load_private(
+        &self,
+        mut layouter: impl Layouter<F>,
+        values: [Value<F>; NUMBER_LENGTH],
+    ) -> Result<[Self::Num; NUMBER_LENGTH], Error> {
+        let config = self.config();
+
+        let digits = layouter.assign_region(
+            || "digits",
+            |mut region| {
+                values
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, value)| {
+                        config.s_lookup.enable(&mut region, i)?;
+                        region
+                            .assign_advice(|| format!("witness {i}"), config.advice[0], i, || value)
+                            .map(OccurrenceNumber)
+                    })
+                    .collect::<Result<Vec<_>, Error>>()
+            },
+        )?;
+
+        Ok(digits.try_into().unwrap()) // safe unwrap
+    }
+
+    fn count_occurrences(
+        &self,
+        mut layouter: impl Layouter<F>,
+        digits: &[Self::Num; NUMBER_LENGTH],
+    ) -> Result<[Self::Num; DIGIT_VALUES], Error> {
+        let config = self.config();
+
+        let counts = (0..DIGIT_VALUES as u64)
+            .map(|digit_value| {
+                layouter.assign_region(
+                    || format!("occurrences of digit {digit_value}"),
+                    |mut region| {
+                        let value = F::from(digit_value);
+                        let mut count = region.assign_advice_from_constant(
+                            || "zero",
+                            config.advice[2],
+                            0,
+                            F::ZERO,
+                        )?;
+                        for (i, digit) in digits.iter().enumerate() {
+                            config.s_count.enable(&mut region, i)?;
+
+                            let digit = digit.0.copy_advice(
+                                || format!("digit {i}"),
+                                &mut region,
+                                config.advice[0],
+                                i,
+                            )?;
+                            region.assign_fixed(
+                                || "digit value",
+                                config.digit_value,
+                                i,
+                                || Value::known(value),
+                            )?;
+                            let difference = digit.value().map(|d| *d - value);
+                            region.assign_advice(
+                                || format!("inverse {i}"),
+                                config.advice[1],
+                                i,
+                                || difference.map(|d| d.invert().unwrap_or(F::ZERO)),
+                            )?;
+                            if i > 0 {
+                                count.copy_advice(
+                                    || format!("occurrences [{}]", i - 1),
+                                    &mut region,
+                                    config.advice[2],
+                                    i,
+                                )?;
+                            }
+                            let is_equal = difference.map(|d| F::from((d == F::ZERO) as u64));
+                            count = region.assign_advice(
+                                || format!("occurrences [{i}]"),
+                                config.advice[3],
+                                i,
+                                || count.value().copied() + is_equal,
+                            )?;
+                        }
+
+                        Ok(OccurrenceNumber(count))
+                    },
+                )
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        Ok(counts.try_into().unwrap()) // safe unwrap
+    }
+
+    fn distinct(
+        &self,
+        mut layouter: impl Layouter<F>,
+        counts: &[Self::Num; DIGIT_VALUES],
+    ) -> Result<Self::Num, Error> {
+        let config = self.config();
+
+        layouter.assign_region(
+            || "distinct digits",
+            |mut region| {
+                let mut repetition =
+                    region.assign_advice_from_constant(|| "zero", config.advice[1], 0, F::ZERO)?;
+                for (i, count) in counts.iter().enumerate() {
+                    config.s_repetition.enable(&mut region, i)?;
+
+                    let count = count.0.copy_advice(
+                        || format!("occurrences of digit {i}"),
+                        &mut region,
+                        config.advice[0],
+                        i,
+                    )?;
+                    if i > 0 {
+                        repetition.copy_advice(
+                            || format!("repetitions [{}]", i - 1),
+                            &mut region,
+                            config.advice[1],
+                            i,
+                        )?;
+                    }
+                    repetition = region.assign_advice(
+                        || format!("repetitions [{i}]"),
+                        config.advice[2],
+                        i,
+                        || repetition.value().copied() + count.value().map(|c| *c * (*c - F::ONE)),
+                    )?;
+                }
+
+                let row = DIGIT_VALUES;
+                config.s_distinct.enable(&mut region, row)?;
+                let repetition =
+                    repetition.copy_advice(|| "repetitions", &mut region, config.advice[0], row)?;
+                region.assign_advice(
+                    || "inverse",
+                    config.advice[1],
+                    row,
+                    || repetition.value().map(|r| r.invert().unwrap_or(F::ZERO)),
+                )?;
+                region
+                    .assign_advice(
+                        || "distinct",
+                        config.advice[2],
+                        row,
+                        || repetition.value().map(|r| F::from((*r == F::ZERO) as u64)),
+                    )
+                    .map(OccurrenceNumber)
+            },
+        )
+    }
+
+    fn expose_public(
+        &self,
+        mut layouter: impl Layouter<F>,
+        num: Self::Num,
+        row: usize,
+    ) -> Result<(), Error> {
+        let config = self.config();
+
+        layouter.constrain_instance(num.0.cell(), config.instance, row)
+    }
+}
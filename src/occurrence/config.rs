@@ -0,0 +1,29 @@
+use halo2_proofs::plonk::{Advice, Column, Fixed, Instance, Selector, TableColumn};
+
+/// The configuration for the occurrence circuit
+#[derive(Debug, Clone)]
+pub struct OccurrenceConfig {
+    /// Advice columns of the chip
+    pub advice: [Column<Advice>; 4],
+
+    /// Fixed column holding the digit value which occurrences are counted
+    pub digit_value: Column<Fixed>,
+
+    /// Instance column of the chip
+    pub instance: Column<Instance>,
+
+    /// Lookup selector for the digit range check
+    pub s_lookup: Selector,
+
+    /// Occurrence count selector of the chip
+    pub s_count: Selector,
+
+    /// Repetition accumulation selector of the chip
+    pub s_repetition: Selector,
+
+    /// Distinct digits selector of the chip
+    pub s_distinct: Selector,
+
+    /// Table column for the digit range check lookup (shared with the digit sum chip)
+    pub digit_table: TableColumn,
+}
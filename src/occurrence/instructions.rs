@@ -0,0 +1,42 @@
+use halo2_proofs::{
+    arithmetic::Field,
+    circuit::{Chip, Layouter, Value},
+    plonk::Error,
+};
+
+use crate::{DIGIT_VALUES, NUMBER_LENGTH};
+
+/// Traits for the chip that counts digit occurrences
+pub trait OccurrenceInstructions<F: Field>: Chip<F> {
+    /// The number type associated to the occurrence instruction
+    type Num;
+
+    /// Loads a private input to the circuit in a decimal format and returns its range checked digits.
+    fn load_private(
+        &self,
+        layouter: impl Layouter<F>,
+        values: [Value<F>; NUMBER_LENGTH],
+    ) -> Result<[Self::Num; NUMBER_LENGTH], Error>;
+
+    /// Counts the occurrences of each digit value (from `0` to `9`) in the digits.
+    fn count_occurrences(
+        &self,
+        layouter: impl Layouter<F>,
+        digits: &[Self::Num; NUMBER_LENGTH],
+    ) -> Result<[Self::Num; DIGIT_VALUES], Error>;
+
+    /// Computes a boolean which is set if and only if all the digits are distinct from the occurrence counts.
+    fn distinct(
+        &self,
+        layouter: impl Layouter<F>,
+        counts: &[Self::Num; DIGIT_VALUES],
+    ) -> Result<Self::Num, Error>;
+
+    /// Exposes a number as a public input to the circuit.
+    fn expose_public(
+        &self,
+        layouter: impl Layouter<F>,
+        num: Self::Num,
+        row: usize,
+    ) -> Result<(), Error>;
+}
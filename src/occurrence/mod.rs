@@ -0,0 +1,16 @@
+//! The occurrence chip that provably counts how many times each decimal digit appears in a fixed length number
+//! The module is splitted into three files:
+//! - `chip.rs` contains the implementation of the chip that counts the digit occurrences
+//! - `config.rs` contains the configuration for the occurrence circuit
+//! - `instructions.rs` contains the instructions interface for the occurrence chip
+
+mod chip;
+mod config;
+mod instructions;
+
+pub use chip::*;
+pub use config::*;
+pub use instructions::*;
+
+/// The number of distinct decimal digit values
+pub const DIGIT_VALUES: usize = 10;
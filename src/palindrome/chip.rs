@@ -0,0 +1,114 @@
+use std::marker::PhantomData;
+
+use halo2_proofs::{
+    arithmetic::Field,
+    circuit::{AssignedCell, Chip, Layouter},
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, Fixed, TableColumn},
+    poly::Rotation,
+};
+
+use crate::{PalindromeConfig, PalindromeInstructions, NUMBER_LENGTH};
+
+/// The chip that implements the palindrome check instructions
+///
+/// The digit symmetry is enforced with copy constraints only: the mirrored digit cells are constrained
+/// to be equal and the digits before the checked ones are constrained to the constant `0`.
+/// A single lookup is used to check that the most significant digit is not a leading zero.
+pub struct PalindromeChip<F: Field> {
+    config: PalindromeConfig,
+    _marker: PhantomData<F>,
+}
+
+impl<F: Field> PalindromeChip<F> {
+    /// Creates a new instance of the palindrome chip
+    pub fn construct(config: PalindromeConfig) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Configures the palindrome chip
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        advice: Column<Advice>,
+        constant: Column<Fixed>,
+        digit_table: TableColumn,
+    ) -> <Self as Chip<F>>::Config {
+        meta.enable_constant(constant);
+        meta.enable_equality(advice);
+        let s_significant = meta.complex_selector();
+
+        // When s_significant is enabled, the lookup constrains the (already range checked) digit to {1..10}.
+        // When s_significant is disabled, the expression evaluates to 0 which is in the table.
+        meta.lookup(|meta| {
+            let s_significant = meta.query_selector(s_significant);
+            let digit = meta.query_advice(advice, Rotation::cur());
+            vec![(
+                s_significant * (digit - Expression::Constant(F::ONE)),
+                digit_table,
+            )]
+        });
+
+        PalindromeConfig {
+            advice,
+            s_significant,
+            digit_table,
+        }
+    }
+}
+
+impl<F: Field> Chip<F> for PalindromeChip<F> {
+    type Config = PalindromeConfig;
+    type Loaded = ();
+
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+impl<F: Field> PalindromeInstructions<F> for PalindromeChip<F> {
+    fn constrain_palindrome(
+        &self,
+        mut layouter: impl Layouter<F>,
+        digits: &[AssignedCell<F, F>; NUMBER_LENGTH],
+        digit_count: usize,
+        allow_leading_zeros: bool,
+    ) -> Result<(), Error> {
+        let config = self.config();
+        let first = NUMBER_LENGTH - digit_count;
+
+        layouter.assign_region(
+            || "palindrome",
+            |mut region| {
+                // The digits before the checked ones are zero
+                for digit in &digits[..first] {
+                    region.constrain_constant(digit.cell(), F::ZERO)?;
+                }
+
+                // The mirrored digits are equal
+                for i in first..first + digit_count / 2 {
+                    let mirror = NUMBER_LENGTH - 1 - (i - first);
+                    region.constrain_equal(digits[i].cell(), digits[mirror].cell())?;
+                }
+
+                // The most significant digit is not a leading zero
+                if !allow_leading_zeros {
+                    config.s_significant.enable(&mut region, 0)?;
+                    digits[first].copy_advice(
+                        || "most significant digit",
+                        &mut region,
+                        config.advice,
+                        0,
+                    )?;
+                }
+
+                Ok(())
+            },
+        )
+    }
+}
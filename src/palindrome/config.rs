@@ -0,0 +1,14 @@
+use halo2_proofs::plonk::{Advice, Column, Selector, TableColumn};
+
+/// The configuration for the palindrome circuit
+#[derive(Debug, Clone)]
+pub struct PalindromeConfig {
+    /// Advice column of the chip
+    pub advice: Column<Advice>,
+
+    /// Lookup selector for the non zero most significant digit check
+    pub s_significant: Selector,
+
+    /// Table column for the digit range check lookup (shared with the digit sum chip)
+    pub digit_table: TableColumn,
+}
@@ -0,0 +1,21 @@
+use halo2_proofs::{
+    arithmetic::Field,
+    circuit::{AssignedCell, Chip, Layouter},
+    plonk::Error,
+};
+
+use crate::NUMBER_LENGTH;
+
+/// Traits for the chip that checks the digit symmetry of a number
+pub trait PalindromeInstructions<F: Field>: Chip<F> {
+    /// Constrains the last `digit_count` digits to read the same forwards and backwards, and the other digits to be zero.
+    ///
+    /// The most significant of the `digit_count` digits is constrained to be non zero unless `allow_leading_zeros` is set.
+    fn constrain_palindrome(
+        &self,
+        layouter: impl Layouter<F>,
+        digits: &[AssignedCell<F, F>; NUMBER_LENGTH],
+        digit_count: usize,
+        allow_leading_zeros: bool,
+    ) -> Result<(), Error>;
+}
@@ -0,0 +1,13 @@
+//! The palindrome chip that provably checks that the decimal digits of a fixed length number read the same forwards and backwards
+//! The module is splitted into three files:
+//! - `chip.rs` contains the implementation of the chip that checks the digit symmetry
+//! - `config.rs` contains the configuration for the palindrome circuit
+//! - `instructions.rs` contains the instructions interface for the palindrome chip
+
+mod chip;
+mod config;
+mod instructions;
+
+pub use chip::*;
+pub use config::*;
+pub use instructions::*;
@@ -1,31 +1,271 @@
-//! The traits that must be implemented by the prover and the verifier.
+//! The proof system abstraction, and its implementation with the halo2 backend.
+//!
+//! A [ProofSystem] generates the keys of a circuit from its shape only (i.e. without witness), proves a witness
+//! with the proving key, and verifies a proof with the verifying key and the public input only. The generic code
+//! written against the trait can then swap the backend and the circuit.
+
+use std::marker::PhantomData;
 
 use halo2_proofs::{
-    arithmetic::CurveAffine,
-    pasta::group::ff::PrimeField,
-    plonk::{ProvingKey, VerifyingKey},
+    pasta::{group::ff::PrimeField, EqAffine, Fp},
+    plonk::{
+        create_proof, keygen_pk, keygen_vk, verify_proof, Circuit, ProvingKey, SingleVerifier,
+        VerifyingKey,
+    },
     poly::commitment::Params,
+    transcript::{Blake2bRead, Blake2bWrite, Challenge255, Transcript},
 };
+use rand_core::OsRng;
 
 use crate::{Bytes, StdResult};
 
-/// A trait for a circuit proving and verification key generator.
-pub trait CircuitKeyGenerator<C: CurveAffine> {
-    /// Generate the public parameters of the circuit.
-    fn generate_setup_params(&self) -> StdResult<Params<C>>;
+/// A trait for a circuit that knows the size of the layout it requires.
+pub trait CircuitSizeParameter {
+    /// The size parameter of the circuit: the circuit must fit into 2^k rows.
+    fn size_parameter(&self) -> u32;
+}
+
+/// A trait for a proof system which proves and verifies the circuits of one kind.
+pub trait ProofSystem {
+    /// The public parameters of the proof system for a circuit.
+    type Params;
+
+    /// The circuit, of which only the shape (i.e. not the witness) is used to generate the keys.
+    type Circuit;
+
+    /// The proving key of a circuit.
+    type ProvingKey;
+
+    /// The verifying key of a circuit.
+    type VerifyingKey;
+
+    /// The secret witness which is proven.
+    type Witness;
+
+    /// The public input of a proof.
+    type PublicInput: ?Sized;
+
+    /// The proof.
+    type Proof;
+
+    /// Generates the public parameters for a circuit.
+    fn setup(&self, circuit: &Self::Circuit) -> StdResult<Self::Params>;
+
+    /// Generates the verifying key of a circuit.
+    fn keygen_vk(
+        &self,
+        params: &Self::Params,
+        circuit: &Self::Circuit,
+    ) -> StdResult<Self::VerifyingKey>;
+
+    /// Generates the proving key of a circuit from its verifying key.
+    fn keygen_pk(
+        &self,
+        verifying_key: &Self::VerifyingKey,
+        circuit: &Self::Circuit,
+    ) -> StdResult<Self::ProvingKey>;
+
+    /// Proves a secret witness for a public input.
+    fn prove(
+        &self,
+        proving_key: &Self::ProvingKey,
+        witness: &Self::Witness,
+        public_input: &Self::PublicInput,
+    ) -> StdResult<Self::Proof>;
+
+    /// Verifies a proof for a public input.
+    fn verify(
+        &self,
+        verifying_key: &Self::VerifyingKey,
+        public_input: &Self::PublicInput,
+        proof: &Self::Proof,
+    ) -> StdResult<()>;
+
+    /// Generates the public parameters and the verifying key of a circuit.
+    fn verifying_key(&self, circuit: &Self::Circuit) -> StdResult<Self::VerifyingKey> {
+        let params = self.setup(circuit)?;
+
+        self.keygen_vk(&params, circuit)
+    }
+
+    /// Generates the public parameters and the proving key of a circuit.
+    fn proving_key(&self, circuit: &Self::Circuit) -> StdResult<Self::ProvingKey> {
+        let verifying_key = self.verifying_key(circuit)?;
+
+        self.keygen_pk(&verifying_key, circuit)
+    }
+}
+
+/// The proof system of the halo2 backend, with the inner product argument commitment scheme over the Pasta curves.
+///
+/// The circuit is also the witness, as halo2 synthesizes the witness with the circuit.
+#[derive(Debug)]
+pub struct Halo2ProofSystem<C> {
+    _marker: PhantomData<C>,
+}
+
+impl<C> Default for Halo2ProofSystem<C> {
+    fn default() -> Self {
+        Self {
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// The verifying key of a circuit for the halo2 backend, along with the public parameters it was generated with.
+#[derive(Clone, Debug)]
+pub struct Halo2VerifyingKey {
+    /// The public parameters of the circuit
+    pub params: Params<EqAffine>,
+
+    /// The verifying key of the circuit
+    pub key: VerifyingKey<EqAffine>,
+}
+
+impl Halo2VerifyingKey {
+    /// Computes the fingerprint of the verifying key, i.e. the hexadecimal encoding of its hash.
+    pub fn fingerprint(&self) -> StdResult<String> {
+        let mut transcript = Blake2bWrite::<_, EqAffine, Challenge255<_>>::init(vec![]);
+        self.key.hash_into(&mut transcript)?;
+        let fingerprint = transcript.squeeze_challenge_scalar::<()>();
 
-    /// Generate the proving and verifying keys for the circuit.
-    fn generate_keys(&self) -> StdResult<(ProvingKey<C>, VerifyingKey<C>)>;
+        Ok(hex::encode(fingerprint.to_repr()))
+    }
 }
 
-/// A trait for a circuit prover.
-pub trait CircuitProver<C: CurveAffine, F: PrimeField>: CircuitKeyGenerator<C> {
-    /// Prove the circuit for the public inputs.
-    fn prove(self, public_inputs: &[F]) -> StdResult<Bytes>;
+/// The proving key of a circuit for the halo2 backend, along with the public parameters it was generated with.
+#[derive(Debug)]
+pub struct Halo2ProvingKey {
+    /// The public parameters of the circuit
+    pub params: Params<EqAffine>,
+
+    /// The proving key of the circuit
+    pub key: ProvingKey<EqAffine>,
+}
+
+impl<C: Circuit<Fp> + CircuitSizeParameter> ProofSystem for Halo2ProofSystem<C> {
+    type Params = Params<EqAffine>;
+    type Circuit = C;
+    type ProvingKey = Halo2ProvingKey;
+    type VerifyingKey = Halo2VerifyingKey;
+    type Witness = C;
+    type PublicInput = [Fp];
+    type Proof = Bytes;
+
+    fn setup(&self, circuit: &C) -> StdResult<Self::Params> {
+        Ok(Params::<EqAffine>::new(circuit.size_parameter()))
+    }
+
+    fn keygen_vk(&self, params: &Self::Params, circuit: &C) -> StdResult<Self::VerifyingKey> {
+        // The keys only depend on the shape of the circuit, never on its witness
+        let key = keygen_vk(params, &circuit.without_witnesses())?;
+
+        Ok(Halo2VerifyingKey {
+            params: params.clone(),
+            key,
+        })
+    }
+
+    fn keygen_pk(
+        &self,
+        verifying_key: &Self::VerifyingKey,
+        circuit: &C,
+    ) -> StdResult<Self::ProvingKey> {
+        let key = keygen_pk(
+            &verifying_key.params,
+            verifying_key.key.clone(),
+            &circuit.without_witnesses(),
+        )?;
+
+        Ok(Halo2ProvingKey {
+            params: verifying_key.params.clone(),
+            key,
+        })
+    }
+
+    fn prove(
+        &self,
+        proving_key: &Self::ProvingKey,
+        witness: &C,
+        public_input: &[Fp],
+    ) -> StdResult<Bytes> {
+        let mut transcript = Blake2bWrite::<_, _, Challenge255<_>>::init(vec![]);
+        create_proof(
+            &proving_key.params,
+            &proving_key.key,
+            std::slice::from_ref(witness),
+            &[&[public_input]],
+            OsRng,
+            &mut transcript,
+        )?;
+
+        Ok(transcript.finalize())
+    }
+
+    fn verify(
+        &self,
+        verifying_key: &Self::VerifyingKey,
+        public_input: &[Fp],
+        proof: &Bytes,
+    ) -> StdResult<()> {
+        let strategy = SingleVerifier::new(&verifying_key.params);
+        let mut transcript = Blake2bRead::<_, _, Challenge255<_>>::init(proof.as_slice());
+        verify_proof(
+            &verifying_key.params,
+            &verifying_key.key,
+            strategy,
+            &[&[public_input]],
+            &mut transcript,
+        )?;
+
+        Ok(())
+    }
 }
 
-/// A trait for a circuit verifier.
-pub trait CircuitVerifier<C: CurveAffine, F: PrimeField>: CircuitKeyGenerator<C> {
-    /// Verify the circuit with a proof and the public inputs.
-    fn verify(self, public_inputs: &[F], proof: &Bytes) -> StdResult<()>;
+#[cfg(test)]
+mod tests {
+    use crate::{DigitSumCircuit, DigitSumCircuitShape, DigitSumPublicInput};
+
+    use super::*;
+
+    /// Proves and verifies a witness with any proof system, the verifier only knowing the shape of the circuit
+    fn prove_and_verify<S: ProofSystem>(
+        proof_system: &S,
+        circuit_without_witness: &S::Circuit,
+        witness: &S::Witness,
+        public_input: &S::PublicInput,
+        verifier_public_input: &S::PublicInput,
+    ) -> StdResult<()> {
+        let proving_key = proof_system.proving_key(circuit_without_witness)?;
+        let proof = proof_system.prove(&proving_key, witness, public_input)?;
+        let verifying_key = proof_system.verifying_key(circuit_without_witness)?;
+
+        proof_system.verify(&verifying_key, verifier_public_input, &proof)
+    }
+
+    #[test]
+    fn halo2_proof_system_verifies_with_verifying_key_and_public_input_only() {
+        let proof_system = Halo2ProofSystem::<DigitSumCircuit<Fp>>::default();
+        let shape = DigitSumCircuitShape::default();
+        let witness = DigitSumCircuit::<Fp>::new(12340000_u64).unwrap();
+        let public_input: Fp = DigitSumPublicInput::new(10).into();
+        let invalid_public_input: Fp = DigitSumPublicInput::new(11).into();
+
+        prove_and_verify(
+            &proof_system,
+            &DigitSumCircuit::from_shape(shape.clone()),
+            &witness,
+            &[public_input],
+            &[public_input],
+        )
+        .expect("the proof should be valid");
+        prove_and_verify(
+            &proof_system,
+            &DigitSumCircuit::from_shape(shape),
+            &witness,
+            &[public_input],
+            &[invalid_public_input],
+        )
+        .expect_err("the proof should be invalid");
+    }
 }
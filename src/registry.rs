@@ -0,0 +1,2524 @@
+//! The registry of the circuit families, which can be selected by their name from the command line.
+//!
+//! A circuit family describes how to parse the secret witness and the public input of its circuits from text, and how
+//! to create its circuits with and without witness. The keygen, prove, verify, check and layout hooks are then
+//! provided for every family by the [RegisteredCircuit] trait, so that a new circuit is made available to the
+//! commands by adding its family to [CIRCUIT_REGISTRY].
+
+use anyhow::anyhow;
+use halo2_proofs::{
+    dev::{CircuitLayout, MockProver},
+    pasta::Fp,
+    plonk::Circuit,
+};
+use plotters::{coord::Shift, prelude::*};
+
+use crate::{
+    AgeCircuit, AgePublicInput, AnagramCircuit, AnagramPublicInput, ArmstrongCircuit, Automaton,
+    AutomatonCircuit, Bytes, CircuitSizeParameter, DecimalNumber, DigitProductCircuit,
+    DigitSumCircuit, DigitSumCircuitShape, DigitSumPublicInput, DigitSumSecretWitness,
+    DigitalRootCircuit, DisclosedDigit, EqualDigitSumCircuit, Halo2ProofSystem, Halo2ProvingKey,
+    Halo2VerifyingKey, HarshadCircuit, Kakuro, KakuroCircuit, KakuroPublicInput, MerkleCircuit,
+    MerklePath, MerklePublicInput, MerkleRoot, MinMaxDigitCircuit, ModuloCircuit,
+    ModuloPublicInput, Nullifier, NullifierCircuit, NullifierPublicInput, OccurrenceCircuit,
+    OccurrencePublicInput, PalindromeCircuit, ProofSystem, Statement, StatementCircuit,
+    StatementParameter, StatementPublicInput, StdResult, SudokuCircuit, SudokuGrid,
+    SudokuPublicInput, MODULUS_UPPER_BOUND, NUMBER_LENGTH,
+};
+
+/// The description of a circuit family, which is displayed by the commands
+pub trait CircuitDescriptor {
+    /// The name of the family, e.g. `digitsum`
+    fn name(&self) -> &'static str;
+
+    /// The description of the statement proven by the circuits of the family
+    fn description(&self) -> &'static str;
+
+    /// The format of the secret witness, e.g. `NUMBER`
+    fn witness_format(&self) -> &'static str;
+
+    /// The format of the public input, e.g. `SUM,MODULUS,RESIDUE`
+    fn public_input_format(&self) -> &'static str;
+}
+
+/// The family specific options of the commands, which give some values of the public input of a circuit family by
+/// name instead of through its public input format, e.g. the modulus and the residue of the `modulo` circuit family
+#[derive(Debug, Clone, Default)]
+pub struct CircuitOptions {
+    /// The public modulus of the euclidean division of the secret number
+    pub modulus: Option<u64>,
+
+    /// The public residue of the euclidean division of the secret number by the modulus
+    pub residue: Option<u64>,
+
+    /// The positions of the digits disclosed by the prover, whose values are derived from the secret witness
+    pub reveal: Vec<usize>,
+
+    /// The digits of the secret number disclosed to the verifier, with their positions
+    pub revealed: Vec<DisclosedDigit>,
+
+    /// The public count of digits of the secret number, including its leading zeros
+    pub digits: Option<usize>,
+
+    /// Whether the most significant digit of the secret number is non-zero
+    pub non_zero_leading_digit: bool,
+
+    /// The public domain tag of the nullifier of the secret number
+    pub nullifier_domain: Option<u64>,
+
+    /// The public nullifier of the secret number, which is disclosed by the prover
+    pub nullifier: Option<Nullifier>,
+
+    /// The statement about the digits of the secret number, whose `$p` parameter is bound by the public input
+    pub statement: Option<Statement>,
+
+    /// The values of the other public parameters of the statement
+    pub params: Vec<StatementParameter>,
+}
+
+impl CircuitOptions {
+    /// The name of the circuit family selected by the options when none is given, e.g. `modulo` with a modulus
+    pub fn circuit_name(&self) -> &'static str {
+        if self.statement.is_some() {
+            "statement"
+        } else if self.modulus.is_some() || self.residue.is_some() {
+            "modulo"
+        } else if self.nullifier_domain.is_some() || self.nullifier.is_some() {
+            "nullifier"
+        } else if self.non_zero_leading_digit {
+            "digitsum-exact"
+        } else {
+            "digitsum"
+        }
+    }
+
+    /// The names of the options which are given
+    fn names(&self) -> Vec<&'static str> {
+        [
+            ("modulus", self.modulus.is_some()),
+            ("residue", self.residue.is_some()),
+            ("reveal", !self.reveal.is_empty()),
+            ("revealed", !self.revealed.is_empty()),
+            ("digits", self.digits.is_some()),
+            ("non-zero-leading-digit", self.non_zero_leading_digit),
+            ("nullifier-domain", self.nullifier_domain.is_some()),
+            ("nullifier", self.nullifier.is_some()),
+            ("statement", self.statement.is_some()),
+            ("param", !self.params.is_empty()),
+        ]
+        .into_iter()
+        .filter_map(|(name, given)| given.then_some(name))
+        .collect()
+    }
+}
+
+/// A family of circuits which can be selected by its name from the command line
+///
+/// The shape of a circuit may depend on its public input (e.g. the count of digits of the witness), so the circuits
+/// without witness are created from the public input, or from the default public input of the family when there is
+/// none (e.g. to print the size parameter of a circuit).
+pub trait CircuitFamily: CircuitDescriptor {
+    /// The secret witness of the circuits of the family
+    type Witness;
+
+    /// The public input of the circuits of the family
+    type PublicInput;
+
+    /// The circuit of the family
+    type Circuit: Circuit<Fp> + CircuitSizeParameter;
+
+    /// Parses the secret witness
+    fn parse_witness(&self, witness: &str) -> StdResult<Self::Witness>;
+
+    /// Parses the public input
+    fn parse_public_input(&self, public_input: &str) -> StdResult<Self::PublicInput>;
+
+    /// The public input used when none is provided, which gives the default shape of the circuit
+    fn default_public_input(&self) -> StdResult<Self::PublicInput>;
+
+    /// The names of the options supported by the family, see [CircuitOptions]
+    fn supported_options(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Parses the public input, some values of which may be given by the options of the family instead
+    ///
+    /// The secret witness is given to the prover, for the options whose values are derived from it (e.g. the digits
+    /// disclosed at some positions).
+    fn parse_public_input_with_options(
+        &self,
+        public_input: &str,
+        _options: &CircuitOptions,
+        _witness: Option<&Self::Witness>,
+    ) -> StdResult<Self::PublicInput> {
+        self.parse_public_input(public_input)
+    }
+
+    /// Completes the public input of the prover with the public values derived from the secret witness (e.g. a
+    /// nullifier), and returns the completed public input which is disclosed to the verifier
+    fn complete_public_input(
+        &self,
+        _witness: &Self::Witness,
+        _public_input: &mut Self::PublicInput,
+    ) -> StdResult<Option<String>> {
+        Ok(None)
+    }
+
+    /// The nullifier of the secret witness bound by the public input, if any
+    fn nullifier(&self, _public_input: &Self::PublicInput) -> Option<Nullifier> {
+        None
+    }
+
+    /// The public inputs of the circuit, as field elements
+    fn public_inputs(&self, public_input: &Self::PublicInput) -> StdResult<Vec<Fp>>;
+
+    /// Creates the circuit for a secret witness and its public input
+    fn circuit(
+        &self,
+        witness: Self::Witness,
+        public_input: &Self::PublicInput,
+    ) -> StdResult<Self::Circuit>;
+
+    /// Creates the circuit without witness for a public input, which is used by the key generation and the verifier
+    fn circuit_without_witness(&self, public_input: &Self::PublicInput)
+        -> StdResult<Self::Circuit>;
+}
+
+/// A circuit family registered for the commands, with its inputs as text
+///
+/// The public input is optional when only the shape of the circuit is needed, in which case the default public
+/// input of the family is used.
+pub trait RegisteredCircuit: CircuitDescriptor {
+    /// The size parameter of the circuit: the circuit must fit into 2^k rows.
+    fn size_parameter(&self, public_input: Option<&str>) -> StdResult<u32>;
+
+    /// Generates the proving and verifying keys of the circuit.
+    fn keygen(&self, public_input: Option<&str>)
+        -> StdResult<(Halo2ProvingKey, Halo2VerifyingKey)>;
+
+    /// Computes the fingerprint of the verifying key of the circuit.
+    fn verifying_key_fingerprint(&self, public_input: Option<&str>) -> StdResult<String>;
+
+    /// Proves the circuit for a secret witness and a public input completed by the options.
+    ///
+    /// Returns the proof, along with the public input completed with the values derived from the witness when the
+    /// circuit discloses some (e.g. a nullifier), which the verifier needs.
+    fn prove(
+        &self,
+        witness: &str,
+        public_input: &str,
+        options: &CircuitOptions,
+    ) -> StdResult<(Bytes, Option<String>)>;
+
+    /// Verifies a proof of the circuit for a public input completed by the options.
+    fn verify(&self, public_input: &str, options: &CircuitOptions, proof: &Bytes) -> StdResult<()>;
+
+    /// The nullifier bound by a public input completed by the options, if the circuit has one.
+    fn nullifier(
+        &self,
+        public_input: &str,
+        options: &CircuitOptions,
+    ) -> StdResult<Option<Nullifier>>;
+
+    /// Checks that a secret witness and a public input completed by the options satisfy the circuit, without
+    /// generating a proof.
+    fn check(&self, witness: &str, public_input: &str, options: &CircuitOptions) -> StdResult<()>;
+
+    /// Renders the layout of the circuit into a drawing area.
+    fn render_layout(
+        &self,
+        public_input: Option<&str>,
+        layout: CircuitLayout,
+        root: &DrawingArea<BitMapBackend<'_>, Shift>,
+    ) -> StdResult<()>;
+}
+
+/// Creates the circuit without witness of a family for an optional public input
+fn circuit_without_witness<T: CircuitFamily>(
+    family: &T,
+    public_input: Option<&str>,
+) -> StdResult<T::Circuit> {
+    let public_input = match public_input {
+        Some(public_input) => family.parse_public_input(public_input)?,
+        None => family.default_public_input()?,
+    };
+
+    family.circuit_without_witness(&public_input)
+}
+
+/// Parses the public input of a family completed by the options, which must be supported by the family
+fn parse_public_input<T: CircuitFamily>(
+    family: &T,
+    public_input: &str,
+    options: &CircuitOptions,
+    witness: Option<&T::Witness>,
+) -> StdResult<T::PublicInput> {
+    let unsupported_options = options
+        .names()
+        .into_iter()
+        .filter(|name| !family.supported_options().contains(name))
+        .map(|name| format!("--{name}"))
+        .collect::<Vec<_>>();
+    if !unsupported_options.is_empty() {
+        return Err(anyhow!(
+            "The circuit {} does not support the option(s) {}",
+            family.name(),
+            unsupported_options.join(", ")
+        ));
+    }
+
+    family.parse_public_input_with_options(public_input, options, witness)
+}
+
+impl<T: CircuitFamily> RegisteredCircuit for T {
+    fn size_parameter(&self, public_input: Option<&str>) -> StdResult<u32> {
+        Ok(circuit_without_witness(self, public_input)?.size_parameter())
+    }
+
+    fn keygen(
+        &self,
+        public_input: Option<&str>,
+    ) -> StdResult<(Halo2ProvingKey, Halo2VerifyingKey)> {
+        let proof_system = Halo2ProofSystem::default();
+        let circuit = circuit_without_witness(self, public_input)?;
+        let verifying_key = proof_system.verifying_key(&circuit)?;
+        let proving_key = proof_system.keygen_pk(&verifying_key, &circuit)?;
+
+        Ok((proving_key, verifying_key))
+    }
+
+    fn verifying_key_fingerprint(&self, public_input: Option<&str>) -> StdResult<String> {
+        let proof_system = Halo2ProofSystem::default();
+
+        proof_system
+            .verifying_key(&circuit_without_witness(self, public_input)?)?
+            .fingerprint()
+    }
+
+    fn prove(
+        &self,
+        witness: &str,
+        public_input: &str,
+        options: &CircuitOptions,
+    ) -> StdResult<(Bytes, Option<String>)> {
+        let witness = self.parse_witness(witness)?;
+        let mut public_input = parse_public_input(self, public_input, options, Some(&witness))?;
+        let completed_public_input = self.complete_public_input(&witness, &mut public_input)?;
+        let public_inputs = self.public_inputs(&public_input)?;
+        let proof_system = Halo2ProofSystem::default();
+        let proving_key =
+            proof_system.proving_key(&self.circuit_without_witness(&public_input)?)?;
+        let circuit = self.circuit(witness, &public_input)?;
+        let proof = proof_system.prove(&proving_key, &circuit, &public_inputs)?;
+
+        Ok((proof, completed_public_input))
+    }
+
+    fn verify(&self, public_input: &str, options: &CircuitOptions, proof: &Bytes) -> StdResult<()> {
+        let public_input = parse_public_input(self, public_input, options, None)?;
+        let proof_system = Halo2ProofSystem::default();
+        let verifying_key =
+            proof_system.verifying_key(&self.circuit_without_witness(&public_input)?)?;
+
+        proof_system.verify(&verifying_key, &self.public_inputs(&public_input)?, proof)
+    }
+
+    fn nullifier(
+        &self,
+        public_input: &str,
+        options: &CircuitOptions,
+    ) -> StdResult<Option<Nullifier>> {
+        Ok(CircuitFamily::nullifier(
+            self,
+            &parse_public_input(self, public_input, options, None)?,
+        ))
+    }
+
+    fn check(&self, witness: &str, public_input: &str, options: &CircuitOptions) -> StdResult<()> {
+        let witness = self.parse_witness(witness)?;
+        let mut public_input = parse_public_input(self, public_input, options, Some(&witness))?;
+        self.complete_public_input(&witness, &mut public_input)?;
+        let public_inputs = self.public_inputs(&public_input)?;
+        let circuit = self.circuit(witness, &public_input)?;
+
+        MockProver::run(circuit.size_parameter(), &circuit, vec![public_inputs])?
+            .verify()
+            .map_err(|failures| {
+                anyhow!(
+                    "The circuit {} is not satisfied: {} failure(s)",
+                    self.name(),
+                    failures.len()
+                )
+            })
+    }
+
+    fn render_layout(
+        &self,
+        public_input: Option<&str>,
+        layout: CircuitLayout,
+        root: &DrawingArea<BitMapBackend<'_>, Shift>,
+    ) -> StdResult<()> {
+        let circuit = circuit_without_witness(self, public_input)?;
+        layout.render(circuit.size_parameter(), &circuit, root)?;
+
+        Ok(())
+    }
+}
+
+/// The circuit families available from the command line
+pub static CIRCUIT_REGISTRY: &[&(dyn RegisteredCircuit + Sync)] = &[
+    &DigitSumFamily {
+        non_zero_leading_digit: false,
+    },
+    &DigitSumFamily {
+        non_zero_leading_digit: true,
+    },
+    &AgeFamily,
+    &AggregateFamily,
+    &AnagramFamily,
+    &ArmstrongFamily,
+    &AutomatonFamily,
+    &DigitalRootFamily,
+    &EqualDigitSumsFamily,
+    &HarshadFamily,
+    &KakuroFamily,
+    &MerkleFamily,
+    &MinMaxFamily,
+    &ModuloFamily,
+    &NullifierFamily,
+    &OccurrencesFamily,
+    &PalindromeFamily,
+    &ProductFamily,
+    &StatementFamily,
+    &SudokuFamily,
+];
+
+/// The minimum age of the age circuit family when the public input does not give one
+const DEFAULT_MIN_AGE: u64 = 18;
+
+/// Finds a registered circuit family by its name
+pub fn find_circuit(name: &str) -> StdResult<&'static (dyn RegisteredCircuit + Sync)> {
+    CIRCUIT_REGISTRY
+        .iter()
+        .copied()
+        .find(|circuit| circuit.name() == name)
+        .ok_or_else(|| {
+            anyhow!("Unknown circuit '{name}', see the `list-circuits` command for the available circuits")
+        })
+}
+
+/// Parses comma separated numbers, where the optional numbers may be left empty or omitted at the end
+fn parse_optional_numbers<const N: usize>(
+    numbers: &str,
+    format: &str,
+) -> StdResult<[Option<u64>; N]> {
+    let error = || anyhow!("Invalid input '{numbers}', expected {format}");
+    let numbers = numbers
+        .split(',')
+        .map(str::trim)
+        .map(|number| match number {
+            "" => Ok(None),
+            _ => number.parse::<u64>().map(Some),
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| error())?;
+    if numbers.len() > N {
+        return Err(error());
+    }
+
+    Ok(std::array::from_fn(|i| numbers.get(i).copied().flatten()))
+}
+
+/// Parses a fixed count of comma separated numbers
+fn parse_numbers<const N: usize>(numbers: &str, format: &str) -> StdResult<[u64; N]> {
+    let optional_numbers = parse_optional_numbers::<N>(numbers, format)?;
+    if optional_numbers.iter().any(Option::is_none) {
+        return Err(anyhow!("Invalid input '{numbers}', expected {format}"));
+    }
+
+    Ok(optional_numbers.map(Option::unwrap_or_default))
+}
+
+/// The digit sum circuit family, which may disclose some digits of the secret number
+///
+/// The count of digits of the secret number defaults to `NUMBER_LENGTH`, and the most significant digit may be
+/// constrained to be non-zero, i.e. the secret number has exactly its count of digits.
+pub struct DigitSumFamily {
+    /// Whether the most significant digit of the secret number is constrained to be non-zero
+    pub non_zero_leading_digit: bool,
+}
+
+impl DigitSumFamily {
+    /// The shape of the circuit for a count of digits and the disclosed digits of the public input
+    fn shape(
+        &self,
+        (number_length, public_input): &(usize, DigitSumPublicInput),
+    ) -> StdResult<DigitSumCircuitShape> {
+        let shape = DigitSumCircuitShape::new(1, *number_length)?
+            .with_disclosure(&public_input.disclosed_positions())?;
+
+        Ok(if self.non_zero_leading_digit {
+            shape.with_non_zero_leading_digit()
+        } else {
+            shape
+        })
+    }
+}
+
+impl CircuitDescriptor for DigitSumFamily {
+    fn name(&self) -> &'static str {
+        if self.non_zero_leading_digit {
+            "digitsum-exact"
+        } else {
+            "digitsum"
+        }
+    }
+
+    fn description(&self) -> &'static str {
+        if self.non_zero_leading_digit {
+            "Sum of the digits of a secret number with exactly DIGITS digits"
+        } else {
+            "Sum of the digits of a secret number padded with leading zeros to DIGITS digits"
+        }
+    }
+
+    fn witness_format(&self) -> &'static str {
+        "NUMBER"
+    }
+
+    fn public_input_format(&self) -> &'static str {
+        "SUM[,DIGITS][,POSITION=DIGIT...]"
+    }
+}
+
+impl CircuitFamily for DigitSumFamily {
+    type Witness = DecimalNumber;
+    type PublicInput = (usize, DigitSumPublicInput);
+    type Circuit = DigitSumCircuit<Fp>;
+
+    fn parse_witness(&self, witness: &str) -> StdResult<Self::Witness> {
+        witness.trim().parse()
+    }
+
+    fn parse_public_input(&self, public_input: &str) -> StdResult<Self::PublicInput> {
+        let error = || {
+            anyhow!(
+                "Invalid input '{public_input}', expected {}",
+                self.public_input_format()
+            )
+        };
+        let mut values = public_input.split(',').map(str::trim);
+        let digit_sum = values
+            .next()
+            .and_then(|value| value.parse::<u64>().ok())
+            .ok_or_else(error)?;
+        let mut number_length = None;
+        let mut disclosed_digits = vec![];
+        for value in values {
+            if value.contains('=') {
+                disclosed_digits.push(value.parse()?);
+            } else if number_length.is_none() && disclosed_digits.is_empty() {
+                number_length = Some(value.parse().map_err(|_| error())?);
+            } else {
+                return Err(error());
+            }
+        }
+
+        Ok((
+            number_length.unwrap_or(NUMBER_LENGTH),
+            DigitSumPublicInput::new(digit_sum).with_disclosed_digits(disclosed_digits),
+        ))
+    }
+
+    fn default_public_input(&self) -> StdResult<Self::PublicInput> {
+        Ok((NUMBER_LENGTH, DigitSumPublicInput::new(0)))
+    }
+
+    fn supported_options(&self) -> &'static [&'static str] {
+        if self.non_zero_leading_digit {
+            &["reveal", "revealed", "digits", "non-zero-leading-digit"]
+        } else {
+            &["reveal", "revealed", "digits"]
+        }
+    }
+
+    fn parse_public_input_with_options(
+        &self,
+        public_input: &str,
+        options: &CircuitOptions,
+        witness: Option<&Self::Witness>,
+    ) -> StdResult<Self::PublicInput> {
+        let (number_length, public_input) = self.parse_public_input(public_input)?;
+        let number_length = options.digits.unwrap_or(number_length);
+        let mut disclosed_digits = public_input.disclosed_digits().to_vec();
+        disclosed_digits.extend(options.revealed.iter().cloned());
+        if !options.reveal.is_empty() {
+            let digits = witness
+                .ok_or_else(|| anyhow!("The revealed digits are derived from the witness, which only the prover knows"))?
+                .padded_digits(number_length)?;
+            disclosed_digits.extend(options.reveal.iter().map(|position| DisclosedDigit {
+                position: *position,
+                digit: digits.get(*position).copied().unwrap_or_default(),
+            }));
+        }
+
+        Ok((
+            number_length,
+            public_input.with_disclosed_digits(disclosed_digits),
+        ))
+    }
+
+    fn public_inputs(&self, (_, public_input): &Self::PublicInput) -> StdResult<Vec<Fp>> {
+        Ok(public_input.clone().into())
+    }
+
+    fn circuit(
+        &self,
+        witness: Self::Witness,
+        public_input: &Self::PublicInput,
+    ) -> StdResult<Self::Circuit> {
+        let (number_length, digit_sum_public_input) = public_input;
+        let digits = witness.padded_digits(*number_length)?;
+        for disclosed_digit in digit_sum_public_input.disclosed_digits() {
+            if digits.get(disclosed_digit.position) != Some(&disclosed_digit.digit) {
+                return Err(anyhow!(
+                    "The disclosed digit at position {} is not the digit of the witness",
+                    disclosed_digit.position
+                ));
+            }
+        }
+
+        Ok(DigitSumCircuit {
+            shape: self.shape(public_input)?,
+            ..DigitSumCircuit::new_with_length(witness, *number_length)?
+        })
+    }
+
+    fn circuit_without_witness(
+        &self,
+        public_input: &Self::PublicInput,
+    ) -> StdResult<Self::Circuit> {
+        Ok(DigitSumCircuit::from_shape(self.shape(public_input)?))
+    }
+}
+
+/// The age circuit family, for a birth date at least a minimum age before a public date
+pub struct AgeFamily;
+
+impl CircuitDescriptor for AgeFamily {
+    fn name(&self) -> &'static str {
+        "age"
+    }
+
+    fn description(&self) -> &'static str {
+        "Secret birth date at least MIN_AGE years (18 by default) before a public date"
+    }
+
+    fn witness_format(&self) -> &'static str {
+        "YYYYMMDD"
+    }
+
+    fn public_input_format(&self) -> &'static str {
+        "TODAY[,MIN_AGE]"
+    }
+}
+
+impl CircuitFamily for AgeFamily {
+    type Witness = u64;
+    type PublicInput = (u64, u64);
+    type Circuit = AgeCircuit<Fp>;
+
+    fn parse_witness(&self, witness: &str) -> StdResult<Self::Witness> {
+        parse_numbers::<1>(witness, self.witness_format()).map(|[birth_date]| birth_date)
+    }
+
+    fn parse_public_input(&self, public_input: &str) -> StdResult<Self::PublicInput> {
+        match parse_optional_numbers::<2>(public_input, self.public_input_format())? {
+            [Some(today), min_age] => Ok((today, min_age.unwrap_or(DEFAULT_MIN_AGE))),
+            _ => Err(anyhow!(
+                "Invalid input '{public_input}', expected {}",
+                self.public_input_format()
+            )),
+        }
+    }
+
+    fn default_public_input(&self) -> StdResult<Self::PublicInput> {
+        Ok((0, DEFAULT_MIN_AGE))
+    }
+
+    fn public_inputs(&self, (today, min_age): &Self::PublicInput) -> StdResult<Vec<Fp>> {
+        Ok(AgePublicInput::new(*today, *min_age).into())
+    }
+
+    fn circuit(
+        &self,
+        witness: Self::Witness,
+        (today, min_age): &Self::PublicInput,
+    ) -> StdResult<Self::Circuit> {
+        AgeCircuit::new(witness, *today, *min_age)
+    }
+
+    fn circuit_without_witness(
+        &self,
+        _public_input: &Self::PublicInput,
+    ) -> StdResult<Self::Circuit> {
+        Ok(AgeCircuit::default())
+    }
+}
+
+/// The aggregate digit sum circuit family, for the total of the digit sums of COUNT secret numbers
+///
+/// The numbers are padded with leading zeros to DIGITS digits (`NUMBER_LENGTH` by default).
+pub struct AggregateFamily;
+
+impl CircuitDescriptor for AggregateFamily {
+    fn name(&self) -> &'static str {
+        "aggregate"
+    }
+
+    fn description(&self) -> &'static str {
+        "Total of the sums of the digits of COUNT secret numbers"
+    }
+
+    fn witness_format(&self) -> &'static str {
+        "NUMBER,..."
+    }
+
+    fn public_input_format(&self) -> &'static str {
+        "TOTAL,COUNT[,DIGITS]"
+    }
+}
+
+impl CircuitFamily for AggregateFamily {
+    type Witness = Vec<DecimalNumber>;
+    type PublicInput = (u64, DigitSumCircuitShape);
+    type Circuit = DigitSumCircuit<Fp>;
+
+    fn parse_witness(&self, witness: &str) -> StdResult<Self::Witness> {
+        witness
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|number| !number.is_empty())
+            .map(str::parse)
+            .collect()
+    }
+
+    fn parse_public_input(&self, public_input: &str) -> StdResult<Self::PublicInput> {
+        match parse_optional_numbers::<3>(public_input, self.public_input_format())? {
+            [Some(total), Some(count), number_length] => Ok((
+                total,
+                DigitSumCircuitShape::new(
+                    count.try_into()?,
+                    number_length.map_or(Ok(NUMBER_LENGTH), usize::try_from)?,
+                )?,
+            )),
+            _ => Err(anyhow!(
+                "Invalid input '{public_input}', expected {}",
+                self.public_input_format()
+            )),
+        }
+    }
+
+    fn default_public_input(&self) -> StdResult<Self::PublicInput> {
+        Ok((0, DigitSumCircuitShape::default()))
+    }
+
+    fn public_inputs(&self, (total, _): &Self::PublicInput) -> StdResult<Vec<Fp>> {
+        Ok(vec![DigitSumPublicInput::new(*total).into()])
+    }
+
+    fn circuit(
+        &self,
+        witness: Self::Witness,
+        (_, shape): &Self::PublicInput,
+    ) -> StdResult<Self::Circuit> {
+        if witness.len() != shape.count {
+            return Err(anyhow!(
+                "The witness has {} numbers instead of the {} numbers of the public input",
+                witness.len(),
+                shape.count
+            ));
+        }
+
+        Ok(DigitSumCircuit {
+            numbers: witness
+                .into_iter()
+                .map(|number| DigitSumSecretWitness::new(number).padded_digits(shape.number_length))
+                .collect::<StdResult<_>>()?,
+            ..DigitSumCircuit::from_shape(shape.clone())
+        })
+    }
+
+    fn circuit_without_witness(&self, (_, shape): &Self::PublicInput) -> StdResult<Self::Circuit> {
+        Ok(DigitSumCircuit::from_shape(shape.clone()))
+    }
+}
+
+/// The anagram circuit family, for a secret number made of the same digits as a public number
+pub struct AnagramFamily;
+
+impl CircuitDescriptor for AnagramFamily {
+    fn name(&self) -> &'static str {
+        "anagram"
+    }
+
+    fn description(&self) -> &'static str {
+        "Secret number made of the same digits as a public number"
+    }
+
+    fn witness_format(&self) -> &'static str {
+        "NUMBER"
+    }
+
+    fn public_input_format(&self) -> &'static str {
+        "NUMBER"
+    }
+}
+
+impl CircuitFamily for AnagramFamily {
+    type Witness = u64;
+    type PublicInput = u64;
+    type Circuit = AnagramCircuit<Fp>;
+
+    fn parse_witness(&self, witness: &str) -> StdResult<Self::Witness> {
+        parse_numbers::<1>(witness, self.witness_format()).map(|[number]| number)
+    }
+
+    fn parse_public_input(&self, public_input: &str) -> StdResult<Self::PublicInput> {
+        parse_numbers::<1>(public_input, self.public_input_format()).map(|[number]| number)
+    }
+
+    fn default_public_input(&self) -> StdResult<Self::PublicInput> {
+        Ok(0)
+    }
+
+    fn public_inputs(&self, public_input: &Self::PublicInput) -> StdResult<Vec<Fp>> {
+        AnagramPublicInput::new(*public_input).try_into()
+    }
+
+    fn circuit(
+        &self,
+        witness: Self::Witness,
+        _public_input: &Self::PublicInput,
+    ) -> StdResult<Self::Circuit> {
+        AnagramCircuit::new(witness)
+    }
+
+    fn circuit_without_witness(
+        &self,
+        _public_input: &Self::PublicInput,
+    ) -> StdResult<Self::Circuit> {
+        Ok(AnagramCircuit::default())
+    }
+}
+
+/// The Armstrong number circuit family, for a secret number of DIGITS significant digits, which may reveal the digit
+/// sum
+pub struct ArmstrongFamily;
+
+impl CircuitDescriptor for ArmstrongFamily {
+    fn name(&self) -> &'static str {
+        "armstrong"
+    }
+
+    fn description(&self) -> &'static str {
+        "Secret number of DIGITS digits equal to the sum of its digits raised to the power DIGITS"
+    }
+
+    fn witness_format(&self) -> &'static str {
+        "NUMBER"
+    }
+
+    fn public_input_format(&self) -> &'static str {
+        "DIGITS[,SUM]"
+    }
+}
+
+impl CircuitFamily for ArmstrongFamily {
+    type Witness = u64;
+    type PublicInput = (usize, Option<u64>);
+    type Circuit = ArmstrongCircuit<Fp>;
+
+    fn parse_witness(&self, witness: &str) -> StdResult<Self::Witness> {
+        parse_numbers::<1>(witness, self.witness_format()).map(|[number]| number)
+    }
+
+    fn parse_public_input(&self, public_input: &str) -> StdResult<Self::PublicInput> {
+        match parse_optional_numbers::<2>(public_input, self.public_input_format())? {
+            [Some(digit_count), digit_sum] => Ok((digit_count.try_into()?, digit_sum)),
+            _ => Err(anyhow!(
+                "Invalid input '{public_input}', expected {}",
+                self.public_input_format()
+            )),
+        }
+    }
+
+    fn default_public_input(&self) -> StdResult<Self::PublicInput> {
+        Ok((NUMBER_LENGTH, None))
+    }
+
+    fn public_inputs(&self, (_, digit_sum): &Self::PublicInput) -> StdResult<Vec<Fp>> {
+        Ok(digit_sum.iter().copied().map(Fp::from).collect())
+    }
+
+    fn circuit(
+        &self,
+        witness: Self::Witness,
+        (digit_count, digit_sum): &Self::PublicInput,
+    ) -> StdResult<Self::Circuit> {
+        ArmstrongCircuit::new(witness, *digit_count, digit_sum.is_some())
+    }
+
+    fn circuit_without_witness(
+        &self,
+        (digit_count, digit_sum): &Self::PublicInput,
+    ) -> StdResult<Self::Circuit> {
+        ArmstrongCircuit::new_without_witness(*digit_count, digit_sum.is_some())
+    }
+}
+
+/// The automaton circuit family, for a secret digit string accepted by a public automaton
+///
+/// The public input is the text description of the automaton (see [Automaton]) with an additional `length N`
+/// statement giving the length of the digit string, which is part of the shape of the circuit. The prover may omit
+/// it, in which case it is the length of the secret digit string.
+pub struct AutomatonFamily;
+
+impl CircuitDescriptor for AutomatonFamily {
+    fn name(&self) -> &'static str {
+        "automaton"
+    }
+
+    fn description(&self) -> &'static str {
+        "Secret digit string of LENGTH digits accepted by a public automaton"
+    }
+
+    fn witness_format(&self) -> &'static str {
+        "DIGITS"
+    }
+
+    fn public_input_format(&self) -> &'static str {
+        "AUTOMATON (with a `length LENGTH` line)"
+    }
+}
+
+impl CircuitFamily for AutomatonFamily {
+    type Witness = String;
+    type PublicInput = (Automaton, Option<usize>);
+    type Circuit = AutomatonCircuit<Fp>;
+
+    fn parse_witness(&self, witness: &str) -> StdResult<Self::Witness> {
+        Ok(witness.trim().to_string())
+    }
+
+    fn parse_public_input(&self, public_input: &str) -> StdResult<Self::PublicInput> {
+        let mut length = None;
+        let mut description = String::new();
+        for line in public_input.lines() {
+            let statement = line.split('#').next().unwrap_or_default(); // safe unwrap
+            match statement.split_whitespace().collect::<Vec<_>>()[..] {
+                ["length", value] => {
+                    let value = value
+                        .parse()
+                        .map_err(|_| anyhow!("Invalid length '{value}' of the digit string"))?;
+                    if length.replace(value).is_some() {
+                        return Err(anyhow!("The length of the digit string is declared twice"));
+                    }
+                    // the line is kept empty so that the line numbers of the errors are unchanged
+                    description.push('\n');
+                }
+                _ => {
+                    description.push_str(line);
+                    description.push('\n');
+                }
+            }
+        }
+
+        Ok((description.parse()?, length))
+    }
+
+    fn default_public_input(&self) -> StdResult<Self::PublicInput> {
+        Ok(("start s\naccept s\ns 0-9 s".parse()?, Some(NUMBER_LENGTH)))
+    }
+
+    fn complete_public_input(
+        &self,
+        witness: &Self::Witness,
+        (_, length): &mut Self::PublicInput,
+    ) -> StdResult<Option<String>> {
+        let witness_length = witness.chars().count();
+        if length.is_some_and(|length| length != witness_length) {
+            return Err(anyhow!(
+                "The digit string has {witness_length} digits instead of the length of the public input"
+            ));
+        }
+        *length = Some(witness_length);
+
+        Ok(None)
+    }
+
+    fn public_inputs(&self, _public_input: &Self::PublicInput) -> StdResult<Vec<Fp>> {
+        Ok(vec![])
+    }
+
+    fn circuit(
+        &self,
+        witness: Self::Witness,
+        (automaton, _): &Self::PublicInput,
+    ) -> StdResult<Self::Circuit> {
+        AutomatonCircuit::new(automaton.clone(), &witness)
+    }
+
+    fn circuit_without_witness(
+        &self,
+        (automaton, length): &Self::PublicInput,
+    ) -> StdResult<Self::Circuit> {
+        let length = length.ok_or_else(|| {
+            anyhow!(
+                "Missing length of the digit string, expected {}",
+                self.public_input_format()
+            )
+        })?;
+
+        Ok(AutomatonCircuit::new_without_witness(
+            automaton.clone(),
+            length,
+        ))
+    }
+}
+
+/// The digital root circuit family, which may reveal the additive persistence
+pub struct DigitalRootFamily;
+
+impl CircuitDescriptor for DigitalRootFamily {
+    fn name(&self) -> &'static str {
+        "digital-root"
+    }
+
+    fn description(&self) -> &'static str {
+        "Digital root and additive persistence of a secret number"
+    }
+
+    fn witness_format(&self) -> &'static str {
+        "NUMBER"
+    }
+
+    fn public_input_format(&self) -> &'static str {
+        "ROOT[,PERSISTENCE]"
+    }
+}
+
+impl CircuitFamily for DigitalRootFamily {
+    type Witness = u64;
+    type PublicInput = (u64, Option<u64>);
+    type Circuit = DigitalRootCircuit<Fp>;
+
+    fn parse_witness(&self, witness: &str) -> StdResult<Self::Witness> {
+        parse_numbers::<1>(witness, self.witness_format()).map(|[number]| number)
+    }
+
+    fn parse_public_input(&self, public_input: &str) -> StdResult<Self::PublicInput> {
+        match parse_optional_numbers::<2>(public_input, self.public_input_format())? {
+            [Some(root), persistence] => Ok((root, persistence)),
+            _ => Err(anyhow!(
+                "Invalid input '{public_input}', expected {}",
+                self.public_input_format()
+            )),
+        }
+    }
+
+    fn default_public_input(&self) -> StdResult<Self::PublicInput> {
+        Ok((0, None))
+    }
+
+    fn public_inputs(&self, (root, persistence): &Self::PublicInput) -> StdResult<Vec<Fp>> {
+        Ok([*root]
+            .into_iter()
+            .chain(*persistence)
+            .map(Fp::from)
+            .collect())
+    }
+
+    fn circuit(
+        &self,
+        witness: Self::Witness,
+        (_, persistence): &Self::PublicInput,
+    ) -> StdResult<Self::Circuit> {
+        DigitalRootCircuit::new(witness, persistence.is_some())
+    }
+
+    fn circuit_without_witness(
+        &self,
+        (_, persistence): &Self::PublicInput,
+    ) -> StdResult<Self::Circuit> {
+        Ok(DigitalRootCircuit::new_without_witness(
+            persistence.is_some(),
+        ))
+    }
+}
+
+/// The equal digit sums circuit family, for two secret numbers, which reveals the digit sum when it is given in the
+/// public input
+pub struct EqualDigitSumsFamily;
+
+impl CircuitDescriptor for EqualDigitSumsFamily {
+    fn name(&self) -> &'static str {
+        "equal-digit-sums"
+    }
+
+    fn description(&self) -> &'static str {
+        "Two secret numbers with the same sum of digits"
+    }
+
+    fn witness_format(&self) -> &'static str {
+        "NUMBER,NUMBER"
+    }
+
+    fn public_input_format(&self) -> &'static str {
+        "[SUM]"
+    }
+}
+
+impl CircuitFamily for EqualDigitSumsFamily {
+    type Witness = [u64; 2];
+    type PublicInput = Option<u64>;
+    type Circuit = EqualDigitSumCircuit<Fp>;
+
+    fn parse_witness(&self, witness: &str) -> StdResult<Self::Witness> {
+        parse_numbers(witness, self.witness_format())
+    }
+
+    fn parse_public_input(&self, public_input: &str) -> StdResult<Self::PublicInput> {
+        parse_optional_numbers::<1>(public_input, self.public_input_format()).map(|[sum]| sum)
+    }
+
+    fn default_public_input(&self) -> StdResult<Self::PublicInput> {
+        Ok(None)
+    }
+
+    fn public_inputs(&self, public_input: &Self::PublicInput) -> StdResult<Vec<Fp>> {
+        Ok(public_input.iter().copied().map(Fp::from).collect())
+    }
+
+    fn circuit(
+        &self,
+        [number, other_number]: Self::Witness,
+        public_input: &Self::PublicInput,
+    ) -> StdResult<Self::Circuit> {
+        EqualDigitSumCircuit::new(number, other_number, public_input.is_some())
+    }
+
+    fn circuit_without_witness(
+        &self,
+        public_input: &Self::PublicInput,
+    ) -> StdResult<Self::Circuit> {
+        Ok(EqualDigitSumCircuit::new_without_witness(
+            public_input.is_some(),
+        ))
+    }
+}
+
+/// The Harshad number circuit family, which reveals the digit sum when it is given in the public input
+pub struct HarshadFamily;
+
+impl CircuitDescriptor for HarshadFamily {
+    fn name(&self) -> &'static str {
+        "harshad"
+    }
+
+    fn description(&self) -> &'static str {
+        "Secret number divisible by the sum of its digits"
+    }
+
+    fn witness_format(&self) -> &'static str {
+        "NUMBER"
+    }
+
+    fn public_input_format(&self) -> &'static str {
+        "[SUM]"
+    }
+}
+
+impl CircuitFamily for HarshadFamily {
+    type Witness = u64;
+    type PublicInput = Option<u64>;
+    type Circuit = HarshadCircuit<Fp>;
+
+    fn parse_witness(&self, witness: &str) -> StdResult<Self::Witness> {
+        parse_numbers::<1>(witness, self.witness_format()).map(|[number]| number)
+    }
+
+    fn parse_public_input(&self, public_input: &str) -> StdResult<Self::PublicInput> {
+        parse_optional_numbers::<1>(public_input, self.public_input_format()).map(|[sum]| sum)
+    }
+
+    fn default_public_input(&self) -> StdResult<Self::PublicInput> {
+        Ok(None)
+    }
+
+    fn public_inputs(&self, public_input: &Self::PublicInput) -> StdResult<Vec<Fp>> {
+        Ok(public_input.iter().copied().map(Fp::from).collect())
+    }
+
+    fn circuit(
+        &self,
+        witness: Self::Witness,
+        public_input: &Self::PublicInput,
+    ) -> StdResult<Self::Circuit> {
+        HarshadCircuit::new(witness, public_input.is_some())
+    }
+
+    fn circuit_without_witness(
+        &self,
+        public_input: &Self::PublicInput,
+    ) -> StdResult<Self::Circuit> {
+        Ok(HarshadCircuit::new_without_witness(public_input.is_some()))
+    }
+}
+
+/// The kakuro circuit family, for a secret solution of a public kakuro puzzle
+///
+/// The grids are given in the text format of [Kakuro], and the layout of the puzzle is part of the shape of the
+/// circuit.
+pub struct KakuroFamily;
+
+impl CircuitDescriptor for KakuroFamily {
+    fn name(&self) -> &'static str {
+        "kakuro"
+    }
+
+    fn description(&self) -> &'static str {
+        "Secret solution of a public kakuro puzzle"
+    }
+
+    fn witness_format(&self) -> &'static str {
+        "GRID"
+    }
+
+    fn public_input_format(&self) -> &'static str {
+        "GRID"
+    }
+}
+
+impl CircuitFamily for KakuroFamily {
+    type Witness = Kakuro;
+    type PublicInput = Kakuro;
+    type Circuit = KakuroCircuit<Fp>;
+
+    fn parse_witness(&self, witness: &str) -> StdResult<Self::Witness> {
+        witness.parse()
+    }
+
+    fn parse_public_input(&self, public_input: &str) -> StdResult<Self::PublicInput> {
+        public_input.parse()
+    }
+
+    fn default_public_input(&self) -> StdResult<Self::PublicInput> {
+        self.parse_public_input("X 4\\ 10\\\n\\3 . .\n\\11 . .")
+    }
+
+    fn public_inputs(&self, public_input: &Self::PublicInput) -> StdResult<Vec<Fp>> {
+        Ok(KakuroPublicInput::new(public_input.clone()).into())
+    }
+
+    fn circuit(
+        &self,
+        witness: Self::Witness,
+        public_input: &Self::PublicInput,
+    ) -> StdResult<Self::Circuit> {
+        KakuroCircuit::new(public_input, &witness)
+    }
+
+    fn circuit_without_witness(
+        &self,
+        public_input: &Self::PublicInput,
+    ) -> StdResult<Self::Circuit> {
+        Ok(KakuroCircuit::new_without_witness(public_input))
+    }
+}
+
+/// The Merkle membership circuit family, for the digit sum of a secret number of an allow-list with a public Merkle
+/// root
+///
+/// The witness is the line of the secret number in the Merkle paths file exported by the `merkle build` command,
+/// i.e. the number followed by its authentication path. The Merkle root is derived from it by the prover.
+pub struct MerkleFamily;
+
+impl CircuitDescriptor for MerkleFamily {
+    fn name(&self) -> &'static str {
+        "merkle"
+    }
+
+    fn description(&self) -> &'static str {
+        "Sum of the digits of a secret number of an allow-list with a public Merkle root"
+    }
+
+    fn witness_format(&self) -> &'static str {
+        "NUMBER INDEX SIBLINGS..."
+    }
+
+    fn public_input_format(&self) -> &'static str {
+        "SUM[,ROOT]"
+    }
+}
+
+impl CircuitFamily for MerkleFamily {
+    type Witness = (u64, MerklePath);
+    type PublicInput = (u64, Option<MerkleRoot>);
+    type Circuit = MerkleCircuit<Fp>;
+
+    fn parse_witness(&self, witness: &str) -> StdResult<Self::Witness> {
+        let (number, path) = witness
+            .trim()
+            .split_once(char::is_whitespace)
+            .ok_or_else(|| {
+                anyhow!(
+                    "Invalid input '{witness}', expected {}",
+                    self.witness_format()
+                )
+            })?;
+
+        Ok((number.parse()?, path.parse()?))
+    }
+
+    fn parse_public_input(&self, public_input: &str) -> StdResult<Self::PublicInput> {
+        let (digit_sum, root) = match public_input.split_once(',') {
+            Some((digit_sum, root)) => (digit_sum, Some(root.trim().parse()?)),
+            None => (public_input, None),
+        };
+        let [digit_sum] = parse_numbers(digit_sum, self.public_input_format())?;
+
+        Ok((digit_sum, root))
+    }
+
+    fn default_public_input(&self) -> StdResult<Self::PublicInput> {
+        Ok((0, None))
+    }
+
+    fn complete_public_input(
+        &self,
+        (number, path): &Self::Witness,
+        (digit_sum, root): &mut Self::PublicInput,
+    ) -> StdResult<Option<String>> {
+        let witness_root = path.root(*number);
+        if root.is_some_and(|root| root != witness_root) {
+            return Err(anyhow!(
+                "The Merkle root is not the root of the authentication path of the witness"
+            ));
+        }
+        *root = Some(witness_root);
+
+        Ok(Some(format!("{digit_sum},{witness_root}")))
+    }
+
+    fn public_inputs(&self, (digit_sum, root): &Self::PublicInput) -> StdResult<Vec<Fp>> {
+        let root = root.ok_or_else(|| {
+            anyhow!(
+                "Missing Merkle root, expected {}",
+                self.public_input_format()
+            )
+        })?;
+
+        Ok(MerklePublicInput::new(*digit_sum, root).into())
+    }
+
+    fn circuit(
+        &self,
+        (number, path): Self::Witness,
+        _public_input: &Self::PublicInput,
+    ) -> StdResult<Self::Circuit> {
+        MerkleCircuit::new(number, &path)
+    }
+
+    fn circuit_without_witness(
+        &self,
+        _public_input: &Self::PublicInput,
+    ) -> StdResult<Self::Circuit> {
+        Ok(MerkleCircuit::default())
+    }
+}
+
+/// The maximum and minimum digits circuit family, which reveals the maximum and/or the minimum digit given in the
+/// public input along with the digit sum
+pub struct MinMaxFamily;
+
+impl CircuitDescriptor for MinMaxFamily {
+    fn name(&self) -> &'static str {
+        "min-max"
+    }
+
+    fn description(&self) -> &'static str {
+        "Sum, maximum and/or minimum of the digits of a secret number of at most DIGITS digits, without its leading zeros"
+    }
+
+    fn witness_format(&self) -> &'static str {
+        "NUMBER"
+    }
+
+    fn public_input_format(&self) -> &'static str {
+        "SUM[,max=MAX][,min=MIN][,digits=DIGITS]"
+    }
+}
+
+impl CircuitFamily for MinMaxFamily {
+    type Witness = u64;
+    type PublicInput = (u64, Option<u64>, Option<u64>, usize);
+    type Circuit = MinMaxDigitCircuit<Fp>;
+
+    fn parse_witness(&self, witness: &str) -> StdResult<Self::Witness> {
+        parse_numbers::<1>(witness, self.witness_format()).map(|[number]| number)
+    }
+
+    fn parse_public_input(&self, public_input: &str) -> StdResult<Self::PublicInput> {
+        let error = || {
+            anyhow!(
+                "Invalid input '{public_input}', expected {}",
+                self.public_input_format()
+            )
+        };
+        let mut values = public_input.split(',').map(str::trim);
+        let digit_sum = values
+            .next()
+            .and_then(|value| value.parse::<u64>().ok())
+            .ok_or_else(error)?;
+        let (mut max_digit, mut min_digit, mut digit_count) = (None, None, None);
+        for value in values {
+            let (name, value) = value.split_once('=').ok_or_else(error)?;
+            let field = match name.trim() {
+                "max" => &mut max_digit,
+                "min" => &mut min_digit,
+                "digits" => &mut digit_count,
+                _ => return Err(error()),
+            };
+            let value = value.trim().parse::<u64>().map_err(|_| error())?;
+            if field.replace(value).is_some() {
+                return Err(error());
+            }
+        }
+        let digit_count = digit_count.map_or(Ok(NUMBER_LENGTH), usize::try_from)?;
+
+        Ok((digit_sum, max_digit, min_digit, digit_count))
+    }
+
+    fn default_public_input(&self) -> StdResult<Self::PublicInput> {
+        Ok((0, Some(0), Some(0), NUMBER_LENGTH))
+    }
+
+    fn supported_options(&self) -> &'static [&'static str] {
+        &["digits"]
+    }
+
+    fn parse_public_input_with_options(
+        &self,
+        public_input: &str,
+        options: &CircuitOptions,
+        _witness: Option<&Self::Witness>,
+    ) -> StdResult<Self::PublicInput> {
+        let (digit_sum, max_digit, min_digit, digit_count) =
+            self.parse_public_input(public_input)?;
+
+        Ok((
+            digit_sum,
+            max_digit,
+            min_digit,
+            options.digits.unwrap_or(digit_count),
+        ))
+    }
+
+    fn public_inputs(
+        &self,
+        (digit_sum, max_digit, min_digit, _): &Self::PublicInput,
+    ) -> StdResult<Vec<Fp>> {
+        Ok([*digit_sum]
+            .into_iter()
+            .chain(*max_digit)
+            .chain(*min_digit)
+            .map(Fp::from)
+            .collect())
+    }
+
+    fn circuit(
+        &self,
+        witness: Self::Witness,
+        (_, max_digit, min_digit, digit_count): &Self::PublicInput,
+    ) -> StdResult<Self::Circuit> {
+        MinMaxDigitCircuit::new(
+            witness,
+            *digit_count,
+            max_digit.is_some(),
+            min_digit.is_some(),
+        )
+    }
+
+    fn circuit_without_witness(
+        &self,
+        (_, max_digit, min_digit, digit_count): &Self::PublicInput,
+    ) -> StdResult<Self::Circuit> {
+        MinMaxDigitCircuit::new_without_witness(
+            *digit_count,
+            max_digit.is_some(),
+            min_digit.is_some(),
+        )
+    }
+}
+
+/// The digit sum and modulo circuit family
+pub struct ModuloFamily;
+
+impl CircuitDescriptor for ModuloFamily {
+    fn name(&self) -> &'static str {
+        "modulo"
+    }
+
+    fn description(&self) -> &'static str {
+        "Sum of the digits and residue modulo a public modulus of a secret number"
+    }
+
+    fn witness_format(&self) -> &'static str {
+        "NUMBER"
+    }
+
+    fn public_input_format(&self) -> &'static str {
+        "SUM,MODULUS,RESIDUE"
+    }
+}
+
+impl CircuitFamily for ModuloFamily {
+    type Witness = u64;
+    type PublicInput = [u64; 3];
+    type Circuit = ModuloCircuit<Fp>;
+
+    fn parse_witness(&self, witness: &str) -> StdResult<Self::Witness> {
+        parse_numbers::<1>(witness, self.witness_format()).map(|[number]| number)
+    }
+
+    fn parse_public_input(&self, public_input: &str) -> StdResult<Self::PublicInput> {
+        let [digit_sum, modulus, residue] =
+            parse_numbers(public_input, self.public_input_format())?;
+        if !(1..=MODULUS_UPPER_BOUND).contains(&modulus) || residue >= modulus {
+            return Err(anyhow!(
+                "Invalid modulus {modulus} or residue {residue}, expected a modulus between 1 and {MODULUS_UPPER_BOUND} and a residue less than the modulus"
+            ));
+        }
+
+        Ok([digit_sum, modulus, residue])
+    }
+
+    fn default_public_input(&self) -> StdResult<Self::PublicInput> {
+        Ok([0, 1, 0])
+    }
+
+    fn supported_options(&self) -> &'static [&'static str] {
+        &["modulus", "residue"]
+    }
+
+    fn parse_public_input_with_options(
+        &self,
+        public_input: &str,
+        options: &CircuitOptions,
+        _witness: Option<&Self::Witness>,
+    ) -> StdResult<Self::PublicInput> {
+        match (options.modulus, options.residue) {
+            (None, None) => self.parse_public_input(public_input),
+            (Some(modulus), Some(residue)) => {
+                let [digit_sum] = parse_numbers(public_input, "SUM")?;
+
+                self.parse_public_input(&format!("{digit_sum},{modulus},{residue}"))
+            }
+            _ => Err(anyhow!(
+                "The modulus and the residue must be given together"
+            )),
+        }
+    }
+
+    fn public_inputs(&self, public_input: &Self::PublicInput) -> StdResult<Vec<Fp>> {
+        let [digit_sum, modulus, residue] = *public_input;
+
+        Ok(ModuloPublicInput::new(digit_sum, modulus, residue).into())
+    }
+
+    fn circuit(
+        &self,
+        witness: Self::Witness,
+        public_input: &Self::PublicInput,
+    ) -> StdResult<Self::Circuit> {
+        let [_, modulus, _] = *public_input;
+
+        ModuloCircuit::new(witness, modulus)
+    }
+
+    fn circuit_without_witness(
+        &self,
+        _public_input: &Self::PublicInput,
+    ) -> StdResult<Self::Circuit> {
+        Ok(ModuloCircuit::default())
+    }
+}
+
+/// The digit sum circuit family with a nullifier of the secret number for a public domain tag
+///
+/// The nullifier is derived from the secret number by the prover, and disclosed to the verifier which records it to
+/// reject a secret number used twice.
+pub struct NullifierFamily;
+
+impl CircuitDescriptor for NullifierFamily {
+    fn name(&self) -> &'static str {
+        "nullifier"
+    }
+
+    fn description(&self) -> &'static str {
+        "Sum of the digits and nullifier for a public domain tag of a secret number"
+    }
+
+    fn witness_format(&self) -> &'static str {
+        "NUMBER"
+    }
+
+    fn public_input_format(&self) -> &'static str {
+        "SUM,DOMAIN[,NULLIFIER]"
+    }
+}
+
+impl CircuitFamily for NullifierFamily {
+    type Witness = DecimalNumber;
+    type PublicInput = (u64, u64, Option<Nullifier>);
+    type Circuit = NullifierCircuit<Fp>;
+
+    fn parse_witness(&self, witness: &str) -> StdResult<Self::Witness> {
+        witness.trim().parse()
+    }
+
+    fn parse_public_input(&self, public_input: &str) -> StdResult<Self::PublicInput> {
+        let (numbers, nullifier) = match public_input.rsplitn(3, ',').collect::<Vec<_>>()[..] {
+            [nullifier, domain, digit_sum] => {
+                (format!("{digit_sum},{domain}"), Some(nullifier.trim()))
+            }
+            _ => (public_input.to_string(), None),
+        };
+        let [digit_sum, domain] = parse_numbers(&numbers, self.public_input_format())?;
+        let nullifier = nullifier.map(str::parse).transpose()?;
+
+        Ok((digit_sum, domain, nullifier))
+    }
+
+    fn default_public_input(&self) -> StdResult<Self::PublicInput> {
+        Ok((0, 0, None))
+    }
+
+    fn supported_options(&self) -> &'static [&'static str] {
+        &["nullifier-domain", "nullifier"]
+    }
+
+    fn parse_public_input_with_options(
+        &self,
+        public_input: &str,
+        options: &CircuitOptions,
+        _witness: Option<&Self::Witness>,
+    ) -> StdResult<Self::PublicInput> {
+        let (digit_sum, domain, nullifier) = match options.nullifier_domain {
+            Some(domain) => {
+                let [digit_sum] = parse_numbers(public_input, "SUM")?;
+
+                (digit_sum, domain, None)
+            }
+            None => self.parse_public_input(public_input)?,
+        };
+
+        Ok((digit_sum, domain, options.nullifier.or(nullifier)))
+    }
+
+    fn complete_public_input(
+        &self,
+        witness: &Self::Witness,
+        (digit_sum, domain, nullifier): &mut Self::PublicInput,
+    ) -> StdResult<Option<String>> {
+        let witness_nullifier = Nullifier::new(witness.clone(), *domain)?;
+        if nullifier.is_some_and(|nullifier| nullifier != witness_nullifier) {
+            return Err(anyhow!(
+                "The nullifier is not the nullifier of the witness for the domain {domain}"
+            ));
+        }
+        *nullifier = Some(witness_nullifier);
+
+        Ok(Some(format!("{digit_sum},{domain},{witness_nullifier}")))
+    }
+
+    fn nullifier(&self, (_, _, nullifier): &Self::PublicInput) -> Option<Nullifier> {
+        *nullifier
+    }
+
+    fn public_inputs(
+        &self,
+        (digit_sum, domain, nullifier): &Self::PublicInput,
+    ) -> StdResult<Vec<Fp>> {
+        let nullifier = nullifier
+            .ok_or_else(|| anyhow!("Missing nullifier, expected {}", self.public_input_format()))?;
+
+        Ok(NullifierPublicInput::new(*digit_sum, *domain, nullifier).into())
+    }
+
+    fn circuit(
+        &self,
+        witness: Self::Witness,
+        _public_input: &Self::PublicInput,
+    ) -> StdResult<Self::Circuit> {
+        NullifierCircuit::new(witness)
+    }
+
+    fn circuit_without_witness(
+        &self,
+        _public_input: &Self::PublicInput,
+    ) -> StdResult<Self::Circuit> {
+        Ok(NullifierCircuit::default())
+    }
+}
+
+/// The digit occurrences circuit family, for the occurrence counts of some digits and whether the digits are distinct
+pub struct OccurrencesFamily;
+
+impl CircuitDescriptor for OccurrencesFamily {
+    fn name(&self) -> &'static str {
+        "occurrences"
+    }
+
+    fn description(&self) -> &'static str {
+        "Occurrence counts of some digits and whether the digits are all distinct in a secret number"
+    }
+
+    fn witness_format(&self) -> &'static str {
+        "NUMBER"
+    }
+
+    fn public_input_format(&self) -> &'static str {
+        "[DIGIT=COUNT,...][,DISTINCT]"
+    }
+}
+
+impl CircuitFamily for OccurrencesFamily {
+    type Witness = u64;
+    type PublicInput = OccurrencePublicInput;
+    type Circuit = OccurrenceCircuit<Fp>;
+
+    fn parse_witness(&self, witness: &str) -> StdResult<Self::Witness> {
+        parse_numbers::<1>(witness, self.witness_format()).map(|[number]| number)
+    }
+
+    fn parse_public_input(&self, public_input: &str) -> StdResult<Self::PublicInput> {
+        let mut occurrences = vec![];
+        let mut distinct = None;
+        for value in public_input.split(',').map(str::trim) {
+            if value.is_empty() {
+                continue;
+            }
+            if distinct.is_some() {
+                return Err(anyhow!(
+                    "Invalid input '{public_input}', expected {}",
+                    self.public_input_format()
+                ));
+            }
+            match value.parse() {
+                Ok(value) => distinct = Some(value),
+                Err(_) => occurrences.push(value.parse()?),
+            }
+        }
+
+        Ok(OccurrencePublicInput::new(occurrences, distinct))
+    }
+
+    fn default_public_input(&self) -> StdResult<Self::PublicInput> {
+        Ok(OccurrencePublicInput::new(vec![], None))
+    }
+
+    fn public_inputs(&self, public_input: &Self::PublicInput) -> StdResult<Vec<Fp>> {
+        Ok(public_input.clone().into())
+    }
+
+    fn circuit(
+        &self,
+        witness: Self::Witness,
+        public_input: &Self::PublicInput,
+    ) -> StdResult<Self::Circuit> {
+        OccurrenceCircuit::new(
+            witness,
+            public_input.counted_digits(),
+            public_input.has_distinct(),
+        )
+    }
+
+    fn circuit_without_witness(
+        &self,
+        public_input: &Self::PublicInput,
+    ) -> StdResult<Self::Circuit> {
+        OccurrenceCircuit::new_without_witness(
+            public_input.counted_digits(),
+            public_input.has_distinct(),
+        )
+    }
+}
+
+/// The palindrome circuit family, for the DIGITS least significant digits of a secret number, which may reveal the
+/// digit sum
+///
+/// The most significant of the checked digits must be non-zero unless leading zeros are allowed.
+pub struct PalindromeFamily;
+
+impl CircuitDescriptor for PalindromeFamily {
+    fn name(&self) -> &'static str {
+        "palindrome"
+    }
+
+    fn description(&self) -> &'static str {
+        "Secret number of DIGITS digits which read the same forwards and backwards"
+    }
+
+    fn witness_format(&self) -> &'static str {
+        "NUMBER"
+    }
+
+    fn public_input_format(&self) -> &'static str {
+        "[DIGITS][,SUM][,ALLOW_LEADING_ZEROS]"
+    }
+}
+
+impl CircuitFamily for PalindromeFamily {
+    type Witness = u64;
+    type PublicInput = (usize, Option<u64>, bool);
+    type Circuit = PalindromeCircuit<Fp>;
+
+    fn parse_witness(&self, witness: &str) -> StdResult<Self::Witness> {
+        parse_numbers::<1>(witness, self.witness_format()).map(|[number]| number)
+    }
+
+    fn parse_public_input(&self, public_input: &str) -> StdResult<Self::PublicInput> {
+        let error = || {
+            anyhow!(
+                "Invalid input '{public_input}', expected {}",
+                self.public_input_format()
+            )
+        };
+        let (numbers, allow_leading_zeros) =
+            match public_input.rsplitn(3, ',').collect::<Vec<_>>()[..] {
+                [allow_leading_zeros, digit_sum, digit_count] => (
+                    format!("{digit_count},{digit_sum}"),
+                    match allow_leading_zeros.trim() {
+                        "" => false,
+                        value => value.parse().map_err(|_| error())?,
+                    },
+                ),
+                _ => (public_input.to_string(), false),
+            };
+        let [digit_count, digit_sum] =
+            parse_optional_numbers(&numbers, self.public_input_format())?;
+        let digit_count = digit_count.map_or(Ok(NUMBER_LENGTH), usize::try_from)?;
+
+        Ok((digit_count, digit_sum, allow_leading_zeros))
+    }
+
+    fn default_public_input(&self) -> StdResult<Self::PublicInput> {
+        Ok((NUMBER_LENGTH, None, false))
+    }
+
+    fn public_inputs(&self, (_, digit_sum, _): &Self::PublicInput) -> StdResult<Vec<Fp>> {
+        Ok(digit_sum.iter().copied().map(Fp::from).collect())
+    }
+
+    fn circuit(
+        &self,
+        witness: Self::Witness,
+        (digit_count, digit_sum, allow_leading_zeros): &Self::PublicInput,
+    ) -> StdResult<Self::Circuit> {
+        PalindromeCircuit::new(
+            witness,
+            *digit_count,
+            *allow_leading_zeros,
+            digit_sum.is_some(),
+        )
+    }
+
+    fn circuit_without_witness(
+        &self,
+        (digit_count, digit_sum, allow_leading_zeros): &Self::PublicInput,
+    ) -> StdResult<Self::Circuit> {
+        PalindromeCircuit::new_without_witness(
+            *digit_count,
+            *allow_leading_zeros,
+            digit_sum.is_some(),
+        )
+    }
+}
+
+/// The digit product circuit family
+pub struct ProductFamily;
+
+impl CircuitDescriptor for ProductFamily {
+    fn name(&self) -> &'static str {
+        "product"
+    }
+
+    fn description(&self) -> &'static str {
+        "Product of the digits of a secret number of at most DIGITS digits, without its leading zeros"
+    }
+
+    fn witness_format(&self) -> &'static str {
+        "NUMBER"
+    }
+
+    fn public_input_format(&self) -> &'static str {
+        "PRODUCT[,DIGITS]"
+    }
+}
+
+impl CircuitFamily for ProductFamily {
+    type Witness = u64;
+    type PublicInput = (u64, usize);
+    type Circuit = DigitProductCircuit<Fp>;
+
+    fn parse_witness(&self, witness: &str) -> StdResult<Self::Witness> {
+        parse_numbers::<1>(witness, self.witness_format()).map(|[number]| number)
+    }
+
+    fn parse_public_input(&self, public_input: &str) -> StdResult<Self::PublicInput> {
+        let [product, digit_count] =
+            parse_optional_numbers(public_input, self.public_input_format())?;
+        let product = product.ok_or_else(|| {
+            anyhow!(
+                "Invalid input '{public_input}', expected {}",
+                self.public_input_format()
+            )
+        })?;
+        let digit_count = digit_count.map_or(Ok(NUMBER_LENGTH), usize::try_from)?;
+
+        Ok((product, digit_count))
+    }
+
+    fn default_public_input(&self) -> StdResult<Self::PublicInput> {
+        Ok((0, NUMBER_LENGTH))
+    }
+
+    fn supported_options(&self) -> &'static [&'static str] {
+        &["digits"]
+    }
+
+    fn parse_public_input_with_options(
+        &self,
+        public_input: &str,
+        options: &CircuitOptions,
+        _witness: Option<&Self::Witness>,
+    ) -> StdResult<Self::PublicInput> {
+        let (product, digit_count) = self.parse_public_input(public_input)?;
+
+        Ok((product, options.digits.unwrap_or(digit_count)))
+    }
+
+    fn public_inputs(&self, (product, _): &Self::PublicInput) -> StdResult<Vec<Fp>> {
+        Ok(vec![Fp::from(*product)])
+    }
+
+    fn circuit(
+        &self,
+        witness: Self::Witness,
+        (_, digit_count): &Self::PublicInput,
+    ) -> StdResult<Self::Circuit> {
+        DigitProductCircuit::new(witness, *digit_count)
+    }
+
+    fn circuit_without_witness(
+        &self,
+        (_, digit_count): &Self::PublicInput,
+    ) -> StdResult<Self::Circuit> {
+        DigitProductCircuit::new_without_witness(*digit_count)
+    }
+}
+
+/// The sudoku circuit family, for a secret solution of a public sudoku puzzle
+///
+/// The grids are given in the text format of [SudokuGrid].
+pub struct SudokuFamily;
+
+impl CircuitDescriptor for SudokuFamily {
+    fn name(&self) -> &'static str {
+        "sudoku"
+    }
+
+    fn description(&self) -> &'static str {
+        "Secret solution of a public sudoku puzzle"
+    }
+
+    fn witness_format(&self) -> &'static str {
+        "GRID"
+    }
+
+    fn public_input_format(&self) -> &'static str {
+        "GRID"
+    }
+}
+
+impl CircuitFamily for SudokuFamily {
+    type Witness = SudokuGrid;
+    type PublicInput = SudokuPublicInput;
+    type Circuit = SudokuCircuit<Fp>;
+
+    fn parse_witness(&self, witness: &str) -> StdResult<Self::Witness> {
+        witness.parse()
+    }
+
+    fn parse_public_input(&self, public_input: &str) -> StdResult<Self::PublicInput> {
+        Ok(SudokuPublicInput::new(public_input.parse()?))
+    }
+
+    fn default_public_input(&self) -> StdResult<Self::PublicInput> {
+        self.parse_public_input(&".........\n".repeat(9))
+    }
+
+    fn public_inputs(&self, public_input: &Self::PublicInput) -> StdResult<Vec<Fp>> {
+        Ok(public_input.clone().into())
+    }
+
+    fn circuit(
+        &self,
+        witness: Self::Witness,
+        _public_input: &Self::PublicInput,
+    ) -> StdResult<Self::Circuit> {
+        Ok(SudokuCircuit::new(&witness))
+    }
+
+    fn circuit_without_witness(
+        &self,
+        _public_input: &Self::PublicInput,
+    ) -> StdResult<Self::Circuit> {
+        Ok(SudokuCircuit::default())
+    }
+}
+
+/// The statement circuit family, for statements parsed from the statement language
+///
+/// The public input is the statement followed by the values of its public parameters as `$NAME=VALUE` clauses,
+/// e.g. `digits=8; sum == $p; max == $max; $p=29; $max=7`.
+pub struct StatementFamily;
+
+impl CircuitDescriptor for StatementFamily {
+    fn name(&self) -> &'static str {
+        "statement"
+    }
+
+    fn description(&self) -> &'static str {
+        "Statement about the digits of a secret number"
+    }
+
+    fn witness_format(&self) -> &'static str {
+        "NUMBER"
+    }
+
+    fn public_input_format(&self) -> &'static str {
+        "STATEMENT[;$NAME=VALUE...]"
+    }
+}
+
+impl CircuitFamily for StatementFamily {
+    type Witness = DecimalNumber;
+    type PublicInput = (Statement, Vec<StatementParameter>);
+    type Circuit = StatementCircuit<Fp>;
+
+    fn parse_witness(&self, witness: &str) -> StdResult<Self::Witness> {
+        witness.trim().parse()
+    }
+
+    fn parse_public_input(&self, public_input: &str) -> StdResult<Self::PublicInput> {
+        let (parameters, clauses): (Vec<_>, Vec<_>) = public_input
+            .split(';')
+            .partition(|clause| clause.trim().starts_with('$'));
+        let parameters = parameters
+            .into_iter()
+            .map(str::parse)
+            .collect::<StdResult<_>>()?;
+
+        Ok((clauses.join(";").parse()?, parameters))
+    }
+
+    fn default_public_input(&self) -> StdResult<Self::PublicInput> {
+        self.parse_public_input("sum == $p")
+    }
+
+    fn supported_options(&self) -> &'static [&'static str] {
+        &["statement", "param"]
+    }
+
+    fn parse_public_input_with_options(
+        &self,
+        public_input: &str,
+        options: &CircuitOptions,
+        _witness: Option<&Self::Witness>,
+    ) -> StdResult<Self::PublicInput> {
+        let (statement, mut parameters) = match &options.statement {
+            // The public input then only binds the `$p` parameter of the statement, if any
+            Some(statement) => match public_input.trim() {
+                "" => (statement.clone(), vec![]),
+                value => {
+                    let [value] = parse_numbers(value, "a value of the $p parameter")?;
+
+                    (statement.clone(), vec![StatementParameter::new("p", value)])
+                }
+            },
+            None => self.parse_public_input(public_input)?,
+        };
+        parameters.extend(options.params.iter().cloned());
+
+        Ok((statement, parameters))
+    }
+
+    fn public_inputs(&self, (statement, parameters): &Self::PublicInput) -> StdResult<Vec<Fp>> {
+        Ok(StatementPublicInput::new(statement, parameters)?.into())
+    }
+
+    fn circuit(
+        &self,
+        witness: Self::Witness,
+        (statement, parameters): &Self::PublicInput,
+    ) -> StdResult<Self::Circuit> {
+        if let Some((constraint, term, _)) = statement
+            .evaluate(&witness, parameters)?
+            .into_iter()
+            .find(|(_, _, holds)| *holds == Some(false))
+        {
+            return Err(anyhow!(
+                "The witness does not satisfy the constraint '{constraint}' of the statement: {} = {term}",
+                constraint.term
+            ));
+        }
+
+        StatementCircuit::new(statement.clone(), witness)
+    }
+
+    fn circuit_without_witness(
+        &self,
+        (statement, _): &Self::PublicInput,
+    ) -> StdResult<Self::Circuit> {
+        Ok(StatementCircuit::new_without_witness(statement.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MerkleTree;
+
+    const POSTAL_CODE_AUTOMATON: &str = r#"
+        # Postal codes made of 5 digits which do not start with 00
+        start s0
+        accept s5
+        s0 0 zero
+        s0 1-9 s1
+        zero 1-9 s2
+        s1 0-9 s2
+        s2 0-9 s3
+        s3 0-9 s4
+        s4 0-9 s5
+    "#;
+
+    const KAKURO_PUZZLE: &str = r#"
+        X     4\    19\   X     X
+        \3    .     .     X     X
+        \11   .     .     8\    11\
+        X     \24   .     .     .
+        X     X     \4    .     .
+    "#;
+
+    const KAKURO_SOLUTION: &str = r#"
+        X     4\    19\   X     X
+        \3    1     2     X     X
+        \11   3     8     8\    11\
+        X     \24   9     7     8
+        X     X     \4    1     3
+    "#;
+
+    const SUDOKU_PUZZLE: &str = r#"
+        53. .7. ...
+        6.. 195 ...
+        .98 ... .6.
+        8.. .6. ..3
+        4.. 8.3 ..1
+        7.. .2. ..6
+        .6. ... 28.
+        ... 419 ..5
+        ... .8. .79
+    "#;
+
+    const SUDOKU_SOLUTION: &str = r#"
+        534 678 912
+        672 195 348
+        198 342 567
+        859 761 423
+        426 853 791
+        713 924 856
+        961 537 284
+        287 419 635
+        345 286 179
+    "#;
+
+    #[test]
+    fn registry_names_are_unique() {
+        for (i, circuit) in CIRCUIT_REGISTRY.iter().enumerate() {
+            assert_eq!(
+                i,
+                CIRCUIT_REGISTRY
+                    .iter()
+                    .position(|other| other.name() == circuit.name())
+                    .unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn registry_finds_circuit_by_name() {
+        assert_eq!("modulo", find_circuit("modulo").unwrap().name());
+        assert!(find_circuit("unknown").is_err());
+    }
+
+    #[test]
+    fn registered_circuits_have_default_shape() {
+        for circuit in CIRCUIT_REGISTRY {
+            circuit
+                .size_parameter(None)
+                .unwrap_or_else(|e| panic!("circuit {} should have a shape: {e}", circuit.name()));
+        }
+    }
+
+    #[test]
+    fn registered_circuits_check_valid_inputs() {
+        let merkle_tree = MerkleTree::build(&[11111111, 12345678, 42]).unwrap();
+        let merkle_witness = format!("12345678 {}", merkle_tree.path(12345678).unwrap());
+        let merkle_root = merkle_tree.root();
+        for (name, witness, public_input) in [
+            ("digitsum", "12345678", "36"),
+            ("digitsum", "123456789012345678901234567890", "135,30"),
+            ("digitsum", "12340000", "10,0=1,1=2"),
+            ("digitsum-exact", "12345", "15,5"),
+            ("age", "20000229", "20261018"),
+            ("age", "20000229", "20261018,26"),
+            ("aggregate", "123,4567,89", "45,3"),
+            ("aggregate", "123\n4567\n123456789", "73,3,9"),
+            ("anagram", "12345678", "81726354"),
+            ("armstrong", "9474", "4"),
+            ("armstrong", "153", "3,9"),
+            ("automaton", "75001", POSTAL_CODE_AUTOMATON),
+            (
+                "automaton",
+                "75001",
+                &format!("{POSTAL_CODE_AUTOMATON}length 5"),
+            ),
+            ("digital-root", "12345678", "9"),
+            ("digital-root", "19999999", "1,3"),
+            ("equal-digit-sums", "1729,991", ""),
+            ("equal-digit-sums", "1729,991", "19"),
+            ("harshad", "10000008", "9"),
+            ("harshad", "1729", ""),
+            ("kakuro", KAKURO_SOLUTION, KAKURO_PUZZLE),
+            ("merkle", &merkle_witness, "36"),
+            ("merkle", &merkle_witness, &format!("36,{merkle_root}")),
+            ("min-max", "31257425", "29,max=7,min=1"),
+            ("min-max", "31252425", "24,max=5"),
+            ("min-max", "31257425", "29,min=1"),
+            ("min-max", "999", "27,min=9,max=9,digits=3"),
+            ("modulo", "12345678", "36,7,2"),
+            ("nullifier", "12345678", "36,7"),
+            ("occurrences", "12775678", "7=3,9=0,false"),
+            ("occurrences", "12345678", "true"),
+            ("palindrome", "1234321", "7,16"),
+            ("palindrome", "12344321", ""),
+            ("palindrome", "110", "4,,true"),
+            ("product", "12345678", "40320"),
+            ("product", "1234", "24,4"),
+            ("product", "1234", "0"),
+            (
+                "statement",
+                "31257425",
+                "sum == $p; max == $max; $p=29; $max=7",
+            ),
+            ("sudoku", SUDOKU_SOLUTION, SUDOKU_PUZZLE),
+        ] {
+            let circuit = find_circuit(name).unwrap();
+
+            circuit
+                .check(witness, public_input, &CircuitOptions::default())
+                .unwrap_or_else(|e| panic!("circuit {name} should be satisfied: {e}"));
+            assert!(circuit
+                .check(witness, "1,2,3,4", &CircuitOptions::default())
+                .is_err());
+        }
+    }
+
+    #[test]
+    fn registered_circuit_fails_check_with_invalid_public_input() {
+        let circuit = find_circuit("digitsum").unwrap();
+
+        assert!(circuit
+            .check("12345678", "35", &CircuitOptions::default())
+            .is_err());
+        assert!(circuit
+            .check("not a number", "36", &CircuitOptions::default())
+            .is_err());
+        assert!(circuit
+            .check("12340000", "10,0=1,1=3", &CircuitOptions::default())
+            .is_err());
+        assert!(find_circuit("digitsum-exact")
+            .unwrap()
+            .check("01234", "10,5", &CircuitOptions::default())
+            .is_err());
+        assert!(find_circuit("age")
+            .unwrap()
+            .check("20090229", "20261018", &CircuitOptions::default())
+            .is_err());
+        for public_input in [
+            "45,2",
+            "45,0",
+            "45,3,0",
+            "45,3,77",
+            "45,18446744073709551615",
+        ] {
+            assert!(find_circuit("aggregate")
+                .unwrap()
+                .check("123,4567,89", public_input, &CircuitOptions::default())
+                .is_err());
+        }
+        assert!(circuit
+            .check("12345678", "36,0", &CircuitOptions::default())
+            .is_err());
+        assert!(find_circuit("anagram")
+            .unwrap()
+            .check("12345678", "81726355", &CircuitOptions::default())
+            .is_err());
+        assert!(find_circuit("armstrong")
+            .unwrap()
+            .check("9475", "4", &CircuitOptions::default())
+            .is_err());
+        assert!(find_circuit("automaton")
+            .unwrap()
+            .check("00123", POSTAL_CODE_AUTOMATON, &CircuitOptions::default())
+            .is_err());
+        assert!(find_circuit("automaton")
+            .unwrap()
+            .check(
+                "75001",
+                &format!("{POSTAL_CODE_AUTOMATON}length 6"),
+                &CircuitOptions::default()
+            )
+            .is_err());
+        assert!(find_circuit("digital-root")
+            .unwrap()
+            .check("19999999", "1,2", &CircuitOptions::default())
+            .is_err());
+        assert!(find_circuit("equal-digit-sums")
+            .unwrap()
+            .check("1729,992", "", &CircuitOptions::default())
+            .is_err());
+        assert!(find_circuit("harshad")
+            .unwrap()
+            .check("1730", "", &CircuitOptions::default())
+            .is_err());
+        assert!(find_circuit("kakuro")
+            .unwrap()
+            .check(
+                KAKURO_SOLUTION,
+                &KAKURO_PUZZLE.replace("\\4 ", "\\5 "),
+                &CircuitOptions::default()
+            )
+            .is_err());
+        let merkle_tree = MerkleTree::build(&[11111111, 12345678, 42]).unwrap();
+        let merkle_witness = format!("12345678 {}", merkle_tree.path(12345678).unwrap());
+        let other_merkle_root = MerkleTree::build(&[11111111, 12345678]).unwrap().root();
+        assert!(find_circuit("merkle")
+            .unwrap()
+            .check(
+                &merkle_witness,
+                &format!("36,{other_merkle_root}"),
+                &CircuitOptions::default()
+            )
+            .is_err());
+        assert!(find_circuit("merkle")
+            .unwrap()
+            .check(
+                &merkle_witness.replacen("12345678", "12345679", 1),
+                &format!("37,{}", merkle_tree.root()),
+                &CircuitOptions::default()
+            )
+            .is_err());
+        assert!(find_circuit("min-max")
+            .unwrap()
+            .check("31257425", "29", &CircuitOptions::default())
+            .is_err());
+        for (witness, public_input) in [
+            ("31257425", "29,7,1"),
+            ("31257425", "29,max=7,max=7"),
+            ("31257425", "29,mid=1"),
+            ("999", "27,min=0,digits=3"),
+            ("999", "27,min=9,digits=2"),
+        ] {
+            assert!(find_circuit("min-max")
+                .unwrap()
+                .check(witness, public_input, &CircuitOptions::default())
+                .is_err());
+        }
+        assert!(find_circuit("modulo")
+            .unwrap()
+            .check("12345678", "36,7,3", &CircuitOptions::default())
+            .is_err());
+        assert!(find_circuit("modulo")
+            .unwrap()
+            .check("12345678", "36,0,0", &CircuitOptions::default())
+            .is_err());
+        assert!(find_circuit("modulo")
+            .unwrap()
+            .check("12345678", "36,7,9", &CircuitOptions::default())
+            .is_err());
+        assert!(find_circuit("occurrences")
+            .unwrap()
+            .check("12775678", "7=2", &CircuitOptions::default())
+            .is_err());
+        assert!(find_circuit("palindrome")
+            .unwrap()
+            .check("110", "4", &CircuitOptions::default())
+            .is_err());
+        assert!(find_circuit("sudoku")
+            .unwrap()
+            .check(
+                SUDOKU_SOLUTION,
+                &SUDOKU_PUZZLE.replacen("53.", "54.", 1),
+                &CircuitOptions::default()
+            )
+            .is_err());
+        assert!(find_circuit("statement")
+            .unwrap()
+            .check("31257425", "sum == $p; $p=28", &CircuitOptions::default())
+            .is_err());
+    }
+
+    #[test]
+    fn registered_circuits_check_valid_inputs_with_options() {
+        let statement = "sum == $p; max == $max".parse::<Statement>().unwrap();
+        for (witness, public_input, options) in [
+            (
+                "12345678",
+                "36",
+                CircuitOptions {
+                    modulus: Some(7),
+                    residue: Some(2),
+                    ..CircuitOptions::default()
+                },
+            ),
+            (
+                "12340000",
+                "10",
+                CircuitOptions {
+                    reveal: vec![0, 1],
+                    ..CircuitOptions::default()
+                },
+            ),
+            (
+                "12340000",
+                "10",
+                CircuitOptions {
+                    revealed: vec!["0=1".parse().unwrap(), "1=2".parse().unwrap()],
+                    ..CircuitOptions::default()
+                },
+            ),
+            (
+                "12345",
+                "15",
+                CircuitOptions {
+                    digits: Some(5),
+                    non_zero_leading_digit: true,
+                    ..CircuitOptions::default()
+                },
+            ),
+            (
+                "12345678",
+                "36",
+                CircuitOptions {
+                    nullifier_domain: Some(7),
+                    ..CircuitOptions::default()
+                },
+            ),
+            (
+                "31257425",
+                "29",
+                CircuitOptions {
+                    statement: Some(statement.clone()),
+                    params: vec![StatementParameter::new("max", 7)],
+                    ..CircuitOptions::default()
+                },
+            ),
+        ] {
+            let name = options.circuit_name();
+
+            find_circuit(name)
+                .unwrap()
+                .check(witness, public_input, &options)
+                .unwrap_or_else(|e| panic!("circuit {name} should be satisfied: {e}"));
+        }
+    }
+
+    #[test]
+    fn registered_circuit_fails_check_with_invalid_options() {
+        let modulo_options = CircuitOptions {
+            modulus: Some(7),
+            residue: Some(3),
+            ..CircuitOptions::default()
+        };
+        let reveal_options = CircuitOptions {
+            reveal: vec![8],
+            ..CircuitOptions::default()
+        };
+        let revealed_options = CircuitOptions {
+            revealed: vec!["0=2".parse().unwrap()],
+            ..CircuitOptions::default()
+        };
+
+        assert_eq!("modulo", modulo_options.circuit_name());
+        assert!(find_circuit("modulo")
+            .unwrap()
+            .check("12345678", "36", &modulo_options)
+            .is_err());
+        assert!(find_circuit("digitsum")
+            .unwrap()
+            .check("12345678", "36", &modulo_options)
+            .is_err());
+        assert!(find_circuit("digitsum")
+            .unwrap()
+            .check("12340000", "10", &reveal_options)
+            .is_err());
+        assert!(find_circuit("digitsum")
+            .unwrap()
+            .check("12340000", "10", &revealed_options)
+            .is_err());
+        assert!(find_circuit("digitsum")
+            .unwrap()
+            .nullifier("10", &reveal_options)
+            .is_err());
+    }
+
+    #[test]
+    fn nullifier_circuit_discloses_the_nullifier_of_the_witness() {
+        let circuit = find_circuit("nullifier").unwrap();
+        let nullifier = Nullifier::new(12345678_u64, 7).unwrap();
+
+        assert_eq!(
+            Some(nullifier),
+            circuit
+                .nullifier(&format!("36,7,{nullifier}"), &CircuitOptions::default())
+                .unwrap()
+        );
+        assert!(circuit
+            .check(
+                "12345678",
+                &format!("36,8,{nullifier}"),
+                &CircuitOptions::default()
+            )
+            .is_err());
+        assert_eq!(
+            None,
+            find_circuit("digitsum")
+                .unwrap()
+                .nullifier("36", &CircuitOptions::default())
+                .unwrap()
+        );
+    }
+}
@@ -0,0 +1,575 @@
+//! The statement language, which describes a claim about the digits of a secret number.
+//!
+//! A statement is a list of clauses separated by semicolons, e.g. `digits=8; sum == $p; d[0] != 0; n % 3 == 0`:
+//! - `digits=N` sets the count of digits of the secret number, including its leading zeros (defaults to 8).
+//! - `TERM == VALUE` constrains a term to be equal to a constant or to a public parameter `$name`.
+//! - `TERM != 0` constrains a term to be non-zero.
+//!
+//! The available terms are the digit sum `sum`, the maximum digit `max`, the minimum digit `min`, the digit
+//! `d[i]` at position `i` (starting at `0` for the most significant digit) and the residue `n % m` of the secret
+//! number by a modulus `m`. The public parameters are the public inputs of the circuit, in the order of their first
+//! occurrence in the statement.
+
+use std::{fmt, str::FromStr};
+
+use anyhow::anyhow;
+use halo2_proofs::pasta::Fp;
+
+use crate::{
+    field_decimal_capacity, poseidon_hash, DecimalNumber, StdError, StdResult, MODULUS_UPPER_BOUND,
+    NUMBER_LENGTH,
+};
+
+/// The count of bytes of the statement text packed in a field element when it is hashed
+const STATEMENT_HASH_CHUNK_SIZE: usize = 7;
+
+/// A term of a statement, i.e. a value computed from the digits of the secret number
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StatementTerm {
+    /// The sum of the digits
+    Sum,
+
+    /// The maximum digit
+    Max,
+
+    /// The minimum digit
+    Min,
+
+    /// The digit at a position, starting at `0` for the most significant digit
+    Digit(usize),
+
+    /// The residue of the number by a modulus
+    Residue(u64),
+}
+
+impl StatementTerm {
+    /// Evaluates the term on the digits of a number
+    pub fn evaluate(&self, digits: &[u64]) -> u64 {
+        match self {
+            Self::Sum => digits.iter().sum(),
+            Self::Max => digits.iter().copied().max().unwrap_or_default(),
+            Self::Min => digits.iter().copied().min().unwrap_or_default(),
+            Self::Digit(position) => digits[*position],
+            Self::Residue(modulus) => digits
+                .iter()
+                .fold(0, |residue, digit| (residue * 10 + digit) % modulus),
+        }
+    }
+}
+
+impl fmt::Display for StatementTerm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Sum => write!(f, "sum"),
+            Self::Max => write!(f, "max"),
+            Self::Min => write!(f, "min"),
+            Self::Digit(position) => write!(f, "d[{position}]"),
+            Self::Residue(modulus) => write!(f, "n % {modulus}"),
+        }
+    }
+}
+
+impl FromStr for StatementTerm {
+    type Err = StdError;
+
+    fn from_str(term: &str) -> Result<Self, Self::Err> {
+        let compact = term.split_whitespace().collect::<String>();
+        let invalid = || anyhow!("Invalid term '{}'", term.trim());
+
+        match compact.as_str() {
+            "sum" => Ok(Self::Sum),
+            "max" => Ok(Self::Max),
+            "min" => Ok(Self::Min),
+            _ => {
+                if let Some(position) = compact
+                    .strip_prefix("d[")
+                    .and_then(|rest| rest.strip_suffix(']'))
+                {
+                    position.parse().map(Self::Digit).map_err(|_| invalid())
+                } else if let Some(modulus) = compact.strip_prefix("n%") {
+                    modulus.parse().map(Self::Residue).map_err(|_| invalid())
+                } else {
+                    Err(invalid())
+                }
+            }
+        }
+    }
+}
+
+/// The comparison operator of a statement constraint
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StatementOperator {
+    /// The term is equal to the value
+    Equal,
+
+    /// The term is not equal to the value
+    NotEqual,
+}
+
+impl fmt::Display for StatementOperator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Equal => write!(f, "=="),
+            Self::NotEqual => write!(f, "!="),
+        }
+    }
+}
+
+/// The value a term is compared to
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StatementValue {
+    /// A constant value, which is part of the circuit
+    Constant(u64),
+
+    /// A public parameter, which is a public input of the circuit
+    Public(String),
+}
+
+impl fmt::Display for StatementValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Constant(value) => write!(f, "{value}"),
+            Self::Public(name) => write!(f, "${name}"),
+        }
+    }
+}
+
+impl FromStr for StatementValue {
+    type Err = StdError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let value = value.trim();
+        match value.strip_prefix('$') {
+            Some(name) if is_parameter_name(name) => Ok(Self::Public(name.to_string())),
+            Some(_) => Err(anyhow!("Invalid public parameter '{value}'")),
+            None => value
+                .parse()
+                .map(Self::Constant)
+                .map_err(|_| anyhow!("Invalid value '{value}'")),
+        }
+    }
+}
+
+/// Checks that a public parameter name is made of alphanumeric characters and underscores
+fn is_parameter_name(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// A constraint of a statement, i.e. the comparison of a term with a value
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StatementConstraint {
+    /// The compared term
+    pub term: StatementTerm,
+
+    /// The comparison operator
+    pub operator: StatementOperator,
+
+    /// The value the term is compared to
+    pub value: StatementValue,
+}
+
+impl fmt::Display for StatementConstraint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} {}", self.term, self.operator, self.value)
+    }
+}
+
+impl FromStr for StatementConstraint {
+    type Err = StdError;
+
+    fn from_str(constraint: &str) -> Result<Self, Self::Err> {
+        let (term, operator, value) = if let Some((term, value)) = constraint.split_once("==") {
+            (term, StatementOperator::Equal, value)
+        } else if let Some((term, value)) = constraint.split_once("!=") {
+            (term, StatementOperator::NotEqual, value)
+        } else {
+            return Err(anyhow!(
+                "Invalid constraint '{}', expected TERM == VALUE or TERM != 0",
+                constraint.trim()
+            ));
+        };
+        let value = value.parse()?;
+        if operator == StatementOperator::NotEqual && value != StatementValue::Constant(0) {
+            return Err(anyhow!(
+                "Invalid constraint '{}', only TERM != 0 is supported",
+                constraint.trim()
+            ));
+        }
+
+        Ok(Self {
+            term: term.parse()?,
+            operator,
+            value,
+        })
+    }
+}
+
+/// A statement about the digits of a secret number, parsed from the statement language
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Statement {
+    /// The count of digits of the secret number, including its leading zeros
+    pub digits: usize,
+
+    /// The constraints on the digits of the secret number
+    pub constraints: Vec<StatementConstraint>,
+}
+
+impl Statement {
+    /// The names of the public parameters, in the order of their public input rows
+    pub fn public_parameters(&self) -> Vec<String> {
+        let mut names: Vec<String> = vec![];
+        for constraint in &self.constraints {
+            if let StatementValue::Public(name) = &constraint.value {
+                if !names.contains(name) {
+                    names.push(name.clone());
+                }
+            }
+        }
+
+        names
+    }
+
+    /// The public input row of a public parameter
+    pub fn public_parameter_row(&self, name: &str) -> Option<usize> {
+        self.public_parameters()
+            .iter()
+            .position(|parameter| parameter == name)
+    }
+
+    /// Hashes the normalized text of the statement into a field element
+    ///
+    /// The text is packed in chunks of bytes which are absorbed by the Poseidon hash, starting from its length.
+    pub fn hash(&self) -> Fp {
+        let text = self.to_string();
+
+        text.as_bytes().chunks(STATEMENT_HASH_CHUNK_SIZE).fold(
+            Fp::from(text.len() as u64),
+            |hash, chunk| {
+                let chunk = chunk
+                    .iter()
+                    .fold(0_u64, |value, byte| (value << 8) | *byte as u64);
+                poseidon_hash([hash, Fp::from(chunk)])
+            },
+        )
+    }
+
+    /// Checks the constraints on a secret number with the values of the public parameters
+    ///
+    /// Returns the constraints along with the value of their term and whether they hold, which is unknown when the
+    /// value of their public parameter is missing.
+    pub fn evaluate(
+        &self,
+        number: &DecimalNumber,
+        parameters: &[StatementParameter],
+    ) -> StdResult<Vec<(StatementConstraint, u64, Option<bool>)>> {
+        let digits = number.padded_digits(self.digits)?;
+
+        Ok(self
+            .constraints
+            .iter()
+            .map(|constraint| {
+                let term = constraint.term.evaluate(&digits);
+                let value = match &constraint.value {
+                    StatementValue::Constant(value) => Some(*value),
+                    StatementValue::Public(name) => parameters
+                        .iter()
+                        .find(|parameter| &parameter.name == name)
+                        .map(|parameter| parameter.value),
+                };
+                let holds = value.map(|value| match constraint.operator {
+                    StatementOperator::Equal => term == value,
+                    StatementOperator::NotEqual => term != value,
+                });
+
+                (constraint.clone(), term, holds)
+            })
+            .collect())
+    }
+
+    /// Checks that the terms of the constraints are supported for the count of digits
+    fn validate(&self) -> StdResult<()> {
+        let capacity = field_decimal_capacity::<Fp>();
+        if !(1..=capacity).contains(&self.digits) {
+            return Err(anyhow!(
+                "Invalid count of digits {}, expected a value between 1 and {capacity}",
+                self.digits
+            ));
+        }
+        if self.constraints.is_empty() {
+            return Err(anyhow!("Expected at least one constraint in the statement"));
+        }
+        for constraint in &self.constraints {
+            match constraint.term {
+                StatementTerm::Digit(position) if position >= self.digits => {
+                    return Err(anyhow!(
+                        "Invalid term '{}', expected a position below {}",
+                        constraint.term,
+                        self.digits
+                    ));
+                }
+                StatementTerm::Residue(modulus) => {
+                    if !(1..=MODULUS_UPPER_BOUND).contains(&modulus) {
+                        return Err(anyhow!(
+                            "Invalid modulus {modulus}, expected a value between 1 and {MODULUS_UPPER_BOUND}"
+                        ));
+                    }
+                    if self.digits > NUMBER_LENGTH {
+                        return Err(anyhow!(
+                            "Invalid term '{}', expected at most {NUMBER_LENGTH} digits",
+                            constraint.term
+                        ));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for Statement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "digits={}", self.digits)?;
+        for constraint in &self.constraints {
+            write!(f, "; {constraint}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for Statement {
+    type Err = StdError;
+
+    fn from_str(statement: &str) -> Result<Self, Self::Err> {
+        let mut digits = None;
+        let mut constraints = vec![];
+        for clause in statement
+            .split(';')
+            .filter(|clause| !clause.trim().is_empty())
+        {
+            match clause.split_once('=') {
+                Some((keyword, count)) if keyword.trim() == "digits" => {
+                    if digits.is_some() {
+                        return Err(anyhow!("Duplicate count of digits '{}'", clause.trim()));
+                    }
+                    digits = Some(
+                        count
+                            .trim()
+                            .parse()
+                            .map_err(|_| anyhow!("Invalid count of digits '{}'", count.trim()))?,
+                    );
+                }
+                _ => constraints.push(clause.parse()?),
+            }
+        }
+        let statement = Self {
+            digits: digits.unwrap_or(NUMBER_LENGTH),
+            constraints,
+        };
+        statement.validate()?;
+
+        Ok(statement)
+    }
+}
+
+/// The value of a public parameter of a statement, parsed from the `NAME=VALUE` format
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StatementParameter {
+    /// The name of the public parameter, without its `$` prefix
+    pub name: String,
+
+    /// The value of the public parameter
+    pub value: u64,
+}
+
+impl StatementParameter {
+    /// Creates a new public parameter value
+    pub fn new(name: &str, value: u64) -> Self {
+        Self {
+            name: name.to_string(),
+            value,
+        }
+    }
+}
+
+impl FromStr for StatementParameter {
+    type Err = StdError;
+
+    fn from_str(parameter: &str) -> Result<Self, Self::Err> {
+        let (name, value) = parameter
+            .split_once('=')
+            .ok_or_else(|| anyhow!("Invalid parameter '{parameter}', expected NAME=VALUE"))?;
+        let name = name.trim();
+        let name = name.strip_prefix('$').unwrap_or(name);
+        if !is_parameter_name(name) {
+            return Err(anyhow!("Invalid parameter name '{name}'"));
+        }
+        let value = value
+            .trim()
+            .parse()
+            .map_err(|_| anyhow!("Invalid parameter value '{}'", value.trim()))?;
+
+        Ok(Self::new(name, value))
+    }
+}
+
+/// The public input for the statement circuit
+#[derive(Clone, Debug)]
+pub struct StatementPublicInput {
+    values: Vec<u64>,
+}
+
+impl StatementPublicInput {
+    /// Creates a new public input from the values of the public parameters of a statement
+    pub fn new(statement: &Statement, parameters: &[StatementParameter]) -> StdResult<Self> {
+        let names = statement.public_parameters();
+        if let Some(parameter) = parameters
+            .iter()
+            .find(|parameter| !names.contains(&parameter.name))
+        {
+            return Err(anyhow!(
+                "Unknown public parameter ${} in statement '{statement}'",
+                parameter.name
+            ));
+        }
+        let values = names
+            .iter()
+            .map(|name| {
+                parameters
+                    .iter()
+                    .find(|parameter| &parameter.name == name)
+                    .map(|parameter| parameter.value)
+                    .ok_or_else(|| anyhow!("Missing value of public parameter ${name}"))
+            })
+            .collect::<StdResult<Vec<_>>>()?;
+
+        Ok(Self { values })
+    }
+}
+
+impl From<StatementPublicInput> for Vec<Fp> {
+    fn from(other: StatementPublicInput) -> Vec<Fp> {
+        other.values.into_iter().map(Fp::from).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn statement_should_parse_and_normalize_valid_text() {
+        let statement: Statement = "digits=8;sum==$p;  d[ 0 ]!=0 ; n%3 == 0;".parse().unwrap();
+
+        assert_eq!(8, statement.digits);
+        assert_eq!(
+            vec![
+                StatementConstraint {
+                    term: StatementTerm::Sum,
+                    operator: StatementOperator::Equal,
+                    value: StatementValue::Public("p".to_string()),
+                },
+                StatementConstraint {
+                    term: StatementTerm::Digit(0),
+                    operator: StatementOperator::NotEqual,
+                    value: StatementValue::Constant(0),
+                },
+                StatementConstraint {
+                    term: StatementTerm::Residue(3),
+                    operator: StatementOperator::Equal,
+                    value: StatementValue::Constant(0),
+                },
+            ],
+            statement.constraints
+        );
+        assert_eq!(
+            "digits=8; sum == $p; d[0] != 0; n % 3 == 0",
+            statement.to_string()
+        );
+        assert_eq!(statement, statement.to_string().parse().unwrap());
+    }
+
+    #[test]
+    fn statement_should_fail_to_parse_invalid_text() {
+        for statement in [
+            "",
+            "digits=8",
+            "digits=0; sum == 1",
+            "digits=8; digits=9; sum == 1",
+            "product == 1",
+            "sum < 1",
+            "sum != 1",
+            "sum == $",
+            "sum == -1",
+            "digits=4; d[4] == 1",
+            "n % 0 == 0",
+            "digits=9; n % 3 == 0",
+        ] {
+            assert!(
+                statement.parse::<Statement>().is_err(),
+                "statement '{statement}' should be invalid"
+            );
+        }
+    }
+
+    #[test]
+    fn statement_hash_depends_on_normalized_text() {
+        let statement: Statement = "sum == $p; d[0] != 0".parse().unwrap();
+
+        assert_eq!(
+            statement.hash(),
+            "digits=8;sum==$p;d[0]!=0"
+                .parse::<Statement>()
+                .unwrap()
+                .hash()
+        );
+        assert_ne!(
+            statement.hash(),
+            "sum == $q; d[0] != 0".parse::<Statement>().unwrap().hash()
+        );
+    }
+
+    #[test]
+    fn statement_public_input_should_follow_parameters_order() {
+        let statement: Statement = "max == $m; sum == $p; min == $m".parse().unwrap();
+        let parameters = vec![StatementParameter::new("p", 29), "$m=7".parse().unwrap()];
+
+        assert_eq!(vec!["m", "p"], statement.public_parameters());
+        let public_input: Vec<Fp> = StatementPublicInput::new(&statement, &parameters)
+            .unwrap()
+            .into();
+        assert_eq!(vec![Fp::from(7), Fp::from(29)], public_input);
+
+        assert!(StatementPublicInput::new(&statement, &parameters[..1]).is_err());
+        assert!(StatementPublicInput::new(
+            &statement,
+            &[parameters.clone(), vec![StatementParameter::new("q", 1)]].concat()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn statement_should_evaluate_constraints_on_number() {
+        let statement: Statement = "sum == $p; d[0] != 0; n % 3 == 0; max == 7"
+            .parse()
+            .unwrap();
+        let evaluation = statement
+            .evaluate(&DecimalNumber::from(31257425_u64), &[])
+            .unwrap()
+            .into_iter()
+            .map(|(_, term, holds)| (term, holds))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![
+                (29, None),
+                (3, Some(true)),
+                (2, Some(false)),
+                (7, Some(true))
+            ],
+            evaluation
+        );
+    }
+}
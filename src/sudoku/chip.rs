@@ -0,0 +1,258 @@
+use std::marker::PhantomData;
+
+use halo2_proofs::{
+    circuit::{AssignedCell, Chip, Layouter, Value},
+    pasta::group::ff::PrimeField,
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, Fixed, Instance, TableColumn},
+    poly::Rotation,
+};
+
+use crate::{SudokuConfig, SudokuInstructions, SUDOKU_CELLS, SUDOKU_SIZE};
+
+/// The chip that implements the sudoku solution instructions
+///
+/// Each digit `d` of the solution is mapped to its weight `10^(d - 1)` with a lookup. A group of `9` cells is a
+/// permutation of the digits from `1` to `9` if and only if the sum of their weights is `111111111`: as each
+/// weight adds one to a single decimal position and there are only `9` weights, the sum has no carry.
+pub struct SudokuChip<F: PrimeField> {
+    config: SudokuConfig,
+    _marker: PhantomData<F>,
+}
+
+impl<F: PrimeField> SudokuChip<F> {
+    /// Creates a new instance of the sudoku chip
+    pub fn construct(config: SudokuConfig) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Configures the sudoku chip
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        advice: [Column<Advice>; 3],
+        instance: Column<Instance>,
+        constant: Column<Fixed>,
+        digit_table: TableColumn,
+    ) -> <Self as Chip<F>>::Config {
+        meta.enable_equality(instance);
+        meta.enable_constant(constant);
+        for column in &advice {
+            meta.enable_equality(*column);
+        }
+        let s_cell = meta.complex_selector();
+        let s_sum = meta.selector();
+        let weight_table = [meta.lookup_table_column(), meta.lookup_table_column()];
+        let one = || Expression::Constant(F::ONE);
+
+        // When s_cell is enabled, the first lookup constrains the digit to {1..10}, and the second lookup
+        // constrains the digit to {0..9} with its weight. When s_cell is disabled, the expressions evaluate
+        // to 0 which is in the tables.
+        meta.lookup(|meta| {
+            let s_cell = meta.query_selector(s_cell);
+            let digit = meta.query_advice(advice[0], Rotation::cur());
+            vec![(s_cell * (digit - one()), digit_table)]
+        });
+
+        meta.lookup(|meta| {
+            let s_cell = meta.query_selector(s_cell);
+            let digit = meta.query_advice(advice[0], Rotation::cur());
+            let weight = meta.query_advice(advice[1], Rotation::cur());
+            vec![
+                (s_cell.clone() * digit, weight_table[0]),
+                (s_cell * weight, weight_table[1]),
+            ]
+        });
+
+        meta.create_gate("given", |meta| {
+            // This gate checks that the cell is equal to the given of the puzzle, unless the given is `0`
+            // Here is the arrangement of the cells of the gate
+            //
+            // | a0     | a1       | a2      | s_cell |
+            // |--------|----------|---------|--------|
+            // | cell0  | weight0  | given0  | s_cell |
+            // | ...    | ...      | ...     | s_cell |
+            // | cell80 | weight80 | given80 | s_cell |
+            //
+            let digit = meta.query_advice(advice[0], Rotation::cur());
+            let given = meta.query_advice(advice[2], Rotation::cur());
+            let s_cell = meta.query_selector(s_cell);
+
+            vec![s_cell * given.clone() * (digit - given)]
+        });
+
+        meta.create_gate("group_sum", |meta| {
+            // This gate implements the sum of the weights of a group of cells
+            // Here is the arrangement of the cells of the gate
+            //
+            // | a0      | a1   | a2   | s_sum |
+            // |---------|------|------|-------|
+            // | weight0 | 0    | sum0 | s_sum |
+            // | weight1 | sum0 | sum1 | s_sum |
+            // | ...     | ...  | ...  | s_sum |
+            // | weight8 | sum7 | sum8 | s_sum |
+            //
+            let weight = meta.query_advice(advice[0], Rotation::cur());
+            let sum_prev = meta.query_advice(advice[1], Rotation::cur());
+            let sum = meta.query_advice(advice[2], Rotation::cur());
+            let s_sum = meta.query_selector(s_sum);
+
+            vec![s_sum * (weight + sum_prev - sum)]
+        });
+
+        SudokuConfig {
+            advice,
+            instance,
+            s_cell,
+            s_sum,
+            weight_table,
+            digit_table,
+        }
+    }
+
+    /// The weight of a digit
+    fn weight(digit: u64) -> u64 {
+        match digit {
+            0 => 0,
+            _ => 10_u64.pow(digit as u32 - 1),
+        }
+    }
+
+    /// The sum of the weights of a group which is a permutation of the digits from `1` to `9`
+    fn group_weight() -> u64 {
+        (1..=SUDOKU_SIZE as u64).map(Self::weight).sum()
+    }
+}
+
+impl<F: PrimeField> Chip<F> for SudokuChip<F> {
+    type Config = SudokuConfig;
+    type Loaded = ();
+
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+/// A number represented in the sudoku chip
+#[derive(Clone, Debug)]
+pub struct SudokuNumber<F: PrimeField>(AssignedCell<F, F>);
+
+impl<F: PrimeField> SudokuInstructions<F> for SudokuChip<F> {
+    type Num = SudokuNumber<F>;
+
+    fn load_table(&self, mut layouter: impl Layouter<F>) -> Result<(), Error> {
+        let config = self.config();
+
+        layouter.assign_table(
+            || "digit weight table",
+            |mut table| {
+                for digit in 0..=SUDOKU_SIZE as u64 {
+                    let weight = Self::weight(digit);
+                    for (column, value) in config.weight_table.into_iter().zip([digit, weight]) {
+                        table.assign_cell(
+                            || format!("digit {digit} weight"),
+                            column,
+                            digit as usize,
+                            || Value::known(F::from(value)),
+                        )?;
+                    }
+                }
+                Ok(())
+            },
+        )
+    }
+
+    fn load_solution(
+        &self,
+        mut layouter: impl Layouter<F>,
+        cells: [Value<F>; SUDOKU_CELLS],
+    ) -> Result<[Self::Num; SUDOKU_CELLS], Error> {
+        let config = self.config();
+
+        let weights = layouter.assign_region(
+            || "solution",
+            |mut region| {
+                cells
+                    .iter()
+                    .enumerate()
+                    .map(|(i, cell)| {
+                        config.s_cell.enable(&mut region, i)?;
+
+                        let digit = region.assign_advice(
+                            || format!("cell {i}"),
+                            config.advice[0],
+                            i,
+                            || *cell,
+                        )?;
+                        region.assign_advice_from_instance(
+                            || format!("given {i}"),
+                            config.instance,
+                            i,
+                            config.advice[2],
+                            i,
+                        )?;
+                        let weight = digit.value().map(|digit| {
+                            let weight = (0..=SUDOKU_SIZE as u64)
+                                .find(|d| F::from(*d) == *digit)
+                                .map(Self::weight)
+                                .unwrap_or_default();
+                            F::from(weight)
+                        });
+                        region
+                            .assign_advice(|| format!("weight {i}"), config.advice[1], i, || weight)
+                            .map(SudokuNumber)
+                    })
+                    .collect::<Result<Vec<_>, Error>>()
+            },
+        )?;
+
+        Ok(weights.try_into().unwrap()) // safe unwrap
+    }
+
+    fn constrain_group(
+        &self,
+        mut layouter: impl Layouter<F>,
+        weights: [&Self::Num; SUDOKU_SIZE],
+    ) -> Result<(), Error> {
+        let config = self.config();
+
+        layouter.assign_region(
+            || "group",
+            |mut region| {
+                let mut previous_value =
+                    region.assign_advice_from_constant(|| "zero", config.advice[1], 0, F::ZERO)?;
+                for (i, weight) in weights.iter().enumerate() {
+                    config.s_sum.enable(&mut region, i)?;
+
+                    let weight = weight.0.copy_advice(
+                        || format!("weight {i}"),
+                        &mut region,
+                        config.advice[0],
+                        i,
+                    )?;
+                    if i > 0 {
+                        previous_value.copy_advice(
+                            || format!("group sum [{}]", i - 1),
+                            &mut region,
+                            config.advice[1],
+                            i,
+                        )?;
+                    }
+                    previous_value = region.assign_advice(
+                        || format!("group sum [{i}]"),
+                        config.advice[2],
+                        i,
+                        || previous_value.value().copied() + weight.value(),
+                    )?;
+                }
+
+                region.constrain_constant(previous_value.cell(), F::from(Self::group_weight()))
+            },
+        )
+    }
+}
@@ -0,0 +1,23 @@
+use halo2_proofs::plonk::{Advice, Column, Instance, Selector, TableColumn};
+
+/// The configuration for the sudoku circuit
+#[derive(Debug, Clone)]
+pub struct SudokuConfig {
+    /// Advice columns of the chip
+    pub advice: [Column<Advice>; 3],
+
+    /// Instance column of the chip
+    pub instance: Column<Instance>,
+
+    /// Cell selector of the chip (digit range, weight lookup and given check)
+    pub s_cell: Selector,
+
+    /// Group sum selector of the chip
+    pub s_sum: Selector,
+
+    /// Table columns for the digit weight lookup: `(digit, 10^(digit - 1))`
+    pub weight_table: [TableColumn; 2],
+
+    /// Table column for the digit range check lookup (shared with the digit sum chip)
+    pub digit_table: TableColumn,
+}
@@ -0,0 +1,182 @@
+use std::str::FromStr;
+
+use anyhow::anyhow;
+
+use crate::{StdError, SUDOKU_BOX_SIZE, SUDOKU_CELLS, SUDOKU_SIZE};
+
+/// A sudoku grid, where the empty cells are represented by `0`
+///
+/// The grid is described with a text format made of one line per row of the grid (empty lines and
+/// comments starting with `#` are ignored). Each row contains `9` cells which are either a digit
+/// from `1` to `9` or an empty cell `.` (whitespaces and `|` separators are ignored).
+///
+/// ```text
+/// # A puzzle
+/// 53. .7. ...
+/// 6.. 195 ...
+/// .98 ... .6.
+/// 8.. .6. ..3
+/// 4.. 8.3 ..1
+/// 7.. .2. ..6
+/// .6. ... 28.
+/// ... 419 ..5
+/// ... .8. .79
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SudokuGrid {
+    cells: [u64; SUDOKU_CELLS],
+}
+
+impl SudokuGrid {
+    /// The cells of the grid, row by row
+    pub fn cells(&self) -> [u64; SUDOKU_CELLS] {
+        self.cells
+    }
+
+    /// The indexes of the cells of each row, column and box of the grid
+    pub fn groups() -> Vec<[usize; SUDOKU_SIZE]> {
+        let rows = (0..SUDOKU_SIZE).map(|row| std::array::from_fn(|i| row * SUDOKU_SIZE + i));
+        let columns =
+            (0..SUDOKU_SIZE).map(|column| std::array::from_fn(|i| i * SUDOKU_SIZE + column));
+        let boxes = (0..SUDOKU_SIZE).map(|index| {
+            let (top, left) = (
+                index / SUDOKU_BOX_SIZE * SUDOKU_BOX_SIZE,
+                index % SUDOKU_BOX_SIZE * SUDOKU_BOX_SIZE,
+            );
+            std::array::from_fn(|i| {
+                (top + i / SUDOKU_BOX_SIZE) * SUDOKU_SIZE + left + i % SUDOKU_BOX_SIZE
+            })
+        });
+
+        rows.chain(columns).chain(boxes).collect()
+    }
+
+    /// Checks if the grid is a solution of a puzzle
+    pub fn solves(&self, puzzle: &SudokuGrid) -> bool {
+        let keeps_givens = puzzle
+            .cells
+            .iter()
+            .zip(self.cells)
+            .all(|(given, cell)| *given == 0 || *given == cell);
+        let has_permutations = Self::groups().into_iter().all(|group| {
+            let mut digits = group.map(|i| self.cells[i]);
+            digits.sort_unstable();
+            digits == std::array::from_fn(|i| i as u64 + 1)
+        });
+
+        keeps_givens && has_permutations
+    }
+}
+
+impl FromStr for SudokuGrid {
+    type Err = StdError;
+
+    fn from_str(description: &str) -> Result<Self, Self::Err> {
+        let mut cells = vec![];
+        for (line_number, line) in description.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or_default(); // safe unwrap
+            let row = line
+                .chars()
+                .filter(|c| !c.is_whitespace() && *c != '|')
+                .map(|c| match c {
+                    '.' => Ok(0),
+                    '1'..='9' => Ok(c as u64 - '0' as u64),
+                    _ => Err(anyhow!("Line {}: invalid cell '{c}'", line_number + 1)),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            match row.len() {
+                0 => {}
+                SUDOKU_SIZE => cells.extend(row),
+                length => {
+                    return Err(anyhow!(
+                        "Line {}: expected {SUDOKU_SIZE} cells, got {length}",
+                        line_number + 1
+                    ))
+                }
+            }
+        }
+        let rows = cells.len() / SUDOKU_SIZE;
+        let cells = cells
+            .try_into()
+            .map_err(|_| anyhow!("Expected {SUDOKU_SIZE} rows, got {rows}"))?;
+
+        Ok(Self { cells })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PUZZLE: &str = r#"
+        # A puzzle
+        53. .7. ...
+        6.. 195 ...
+        .98 ... .6.
+        8.. .6. ..3
+        4.. 8.3 ..1
+        7.. .2. ..6
+        .6. ... 28.
+        ... 419 ..5
+        ... .8. .79
+    "#;
+
+    const SOLUTION: &str = r#"
+        534 678 912
+        672 195 348
+        198 342 567
+        859 761 423
+        426 853 791
+        713 924 856
+        961 537 284
+        287 419 635
+        345 286 179
+    "#;
+
+    #[test]
+    fn sudoku_grid_parses_valid_description() {
+        let puzzle: SudokuGrid = PUZZLE.parse().unwrap();
+
+        assert_eq!([5, 3, 0, 0, 7, 0, 0, 0, 0], puzzle.cells()[..SUDOKU_SIZE]);
+        assert_eq!(9, puzzle.cells()[SUDOKU_CELLS - 1]);
+    }
+
+    #[test]
+    fn sudoku_grid_rejects_invalid_descriptions() {
+        for description in [
+            "",
+            &"123456789\n".repeat(8),
+            &"123456789\n".repeat(10),
+            &"12345678\n".repeat(9),
+            &"12345678x\n".repeat(9),
+            &"123456780\n".repeat(9),
+        ] {
+            assert!(
+                description.parse::<SudokuGrid>().is_err(),
+                "description '{description}' should be invalid"
+            );
+        }
+    }
+
+    #[test]
+    fn sudoku_grid_groups_are_rows_columns_and_boxes() {
+        let groups = SudokuGrid::groups();
+
+        assert_eq!(3 * SUDOKU_SIZE, groups.len());
+        assert_eq!([0, 1, 2, 3, 4, 5, 6, 7, 8], groups[0]);
+        assert_eq!([1, 10, 19, 28, 37, 46, 55, 64, 73], groups[SUDOKU_SIZE + 1]);
+        assert_eq!(
+            [30, 31, 32, 39, 40, 41, 48, 49, 50],
+            groups[2 * SUDOKU_SIZE + 4]
+        );
+    }
+
+    #[test]
+    fn sudoku_grid_checks_solution() {
+        let puzzle: SudokuGrid = PUZZLE.parse().unwrap();
+        let solution: SudokuGrid = SOLUTION.parse().unwrap();
+
+        assert!(solution.solves(&puzzle));
+        assert!(!puzzle.solves(&puzzle));
+    }
+}
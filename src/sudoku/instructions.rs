@@ -0,0 +1,32 @@
+use halo2_proofs::{
+    arithmetic::Field,
+    circuit::{Chip, Layouter, Value},
+    plonk::Error,
+};
+
+use crate::{SUDOKU_CELLS, SUDOKU_SIZE};
+
+/// Traits for the chip that checks a sudoku solution
+pub trait SudokuInstructions<F: Field>: Chip<F> {
+    /// The number type associated to the sudoku instruction
+    type Num;
+
+    /// Loads the digit weight lookup table into the circuit.
+    fn load_table(&self, layouter: impl Layouter<F>) -> Result<(), Error>;
+
+    /// Loads a private solution to the circuit and returns the weights of its cells.
+    ///
+    /// Each cell is constrained to be a digit from `1` to `9`, equal to the given of the puzzle read from the public inputs (if any).
+    fn load_solution(
+        &self,
+        layouter: impl Layouter<F>,
+        cells: [Value<F>; SUDOKU_CELLS],
+    ) -> Result<[Self::Num; SUDOKU_CELLS], Error>;
+
+    /// Constrains a group of cells (a row, a column or a box) to be a permutation of the digits from `1` to `9`.
+    fn constrain_group(
+        &self,
+        layouter: impl Layouter<F>,
+        weights: [&Self::Num; SUDOKU_SIZE],
+    ) -> Result<(), Error>;
+}
@@ -0,0 +1,25 @@
+//! The sudoku chip that provably checks that a secret grid is a solution of a public sudoku puzzle
+//! The module is splitted into four files:
+//! - `grid.rs` contains the sudoku grid and its text format
+//! - `chip.rs` contains the implementation of the chip that checks the solution
+//! - `config.rs` contains the configuration for the sudoku circuit
+//! - `instructions.rs` contains the instructions interface for the sudoku chip
+
+mod chip;
+mod config;
+mod grid;
+mod instructions;
+
+pub use chip::*;
+pub use config::*;
+pub use grid::*;
+pub use instructions::*;
+
+/// The number of cells of a row, a column or a box of a sudoku grid
+pub const SUDOKU_SIZE: usize = 9;
+
+/// The number of cells of a side of a box of a sudoku grid
+pub const SUDOKU_BOX_SIZE: usize = 3;
+
+/// The number of cells of a sudoku grid
+pub const SUDOKU_CELLS: usize = SUDOKU_SIZE * SUDOKU_SIZE;